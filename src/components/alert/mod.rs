@@ -0,0 +1,232 @@
+use std::time::Duration;
+
+use crate::{
+    Element,
+    theme::{
+        alert::AlertStyleClass,
+        badge::BadgeVariant,
+        button::{ButtonStyleClass, ButtonVariant},
+        container::ContainerStyleClass,
+        parts::{Part, PartStyle},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
+    },
+    widgets::countdown::Countdown,
+};
+use iced::{
+    Alignment, Length,
+    border::Radius,
+    widget::{self, column, container, row, text},
+};
+
+/// A Shoelace-style alert component for iced
+///
+/// This component implements the features from Shoelace's alert component:
+/// - Variants mirroring badges (primary, success, neutral, warning, danger)
+/// - Icon slot shown in the variant color
+/// - Closable button
+/// - Auto-dismiss after a duration, with an optional countdown bar
+/// - Countdown pauses while hovered
+///
+/// Alerts are controlled: they are shown for as long as the parent renders them,
+/// and `on_close` is produced when the close button is pressed or the duration runs out.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Alert;
+/// use iced_shoelace::theme::badge::BadgeVariant;
+///
+/// let alert = Alert::new("Your changes have been saved")
+///     .variant(BadgeVariant::Success)
+///     .icon("✓")
+///     .closable(true)
+///     .duration(Duration::from_secs(3))
+///     .on_close(Message::AlertClosed);
+/// ```
+pub struct Alert<'a, Message> {
+    content: Element<'a, Message>,
+    variant: BadgeVariant,
+    icon: Option<Element<'a, Message>>,
+    closable: bool,
+    duration: Option<Duration>,
+    countdown: bool,
+    width: Length,
+    toast: bool,
+    on_close: Option<Message>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<'a, Message> Alert<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    /// Creates a new alert with the given content
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            variant: BadgeVariant::Primary,
+            icon: None,
+            closable: false,
+            duration: None,
+            countdown: false,
+            width: Length::Fill,
+            toast: false,
+            on_close: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the alert variant
+    pub fn variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the icon shown before the content, colored with the variant
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets whether a close button is shown
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Sets how long the alert stays before `on_close` is produced
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets whether a bar counting down the remaining duration is shown
+    pub fn countdown(mut self, countdown: bool) -> Self {
+        self.countdown = countdown;
+        self
+    }
+
+    /// Sets the width of the alert
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the message produced when the alert is closed or its duration runs out
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Raises the alert with a large shadow, as used by the toast stack
+    pub(crate) fn toast(mut self, toast: bool) -> Self {
+        self.toast = toast;
+        self
+    }
+}
+
+impl<'a, Message> From<Alert<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(alert: Alert<'a, Message>) -> Self {
+        let variant = alert.variant;
+        let sizes = alert.sizes;
+        let fonts = alert.fonts;
+
+        // Shoelace draws a 3px top border in the variant color
+        let accent =
+            container(column![])
+                .width(Length::Fill)
+                .height(3)
+                .class(ContainerStyleClass::Badge {
+                    variant,
+                    border_radius: 0.0,
+                    pulse: false,
+                    part: PartStyle::default(),
+                });
+
+        let mut body = row![]
+            .spacing(sizes.spacing.medium)
+            .align_y(Alignment::Center);
+
+        if let Some(icon) = alert.icon {
+            body = body.push(container(icon).class(ContainerStyleClass::AlertIcon { variant }));
+        }
+
+        body = body.push(container(alert.content).width(Length::Fill));
+
+        if alert.closable
+            && let Some(on_close) = alert.on_close.clone()
+        {
+            body = body.push(
+                widget::button(
+                    text("✕")
+                        .size(sizes.font_size.small)
+                        .line_height(1.0)
+                        .font(fonts.sans),
+                )
+                .padding(sizes.spacing.x_small)
+                .class(ButtonStyleClass {
+                    variant: ButtonVariant::Text,
+                    outline: false,
+                    border_radius: Radius::from(sizes.border_radius.medium),
+                    disabled: false,
+                    base: Part::default(),
+                    label: Part::default(),
+                })
+                .on_press(on_close),
+            );
+        }
+
+        // Shoelace pads the message with --sl-spacing-large
+        let body = container(body)
+            .width(Length::Fill)
+            .padding([sizes.spacing.medium, sizes.spacing.large]);
+
+        let panel = container(column![accent, body])
+            .width(alert.width)
+            .clip(true)
+            .class(ContainerStyleClass::Alert { toast: alert.toast });
+
+        match alert.duration {
+            Some(duration) => {
+                let countdown = Countdown::new(panel, duration)
+                    .show_bar(alert.countdown)
+                    .class(AlertStyleClass { variant });
+
+                match alert.on_close {
+                    Some(on_close) => countdown.on_expire(on_close).into(),
+                    None => countdown.into(),
+                }
+            }
+            None => panel.into(),
+        }
+    }
+}
+
+/// Helper function to create an alert
+pub fn alert<'a, Message>(content: impl Into<Element<'a, Message>>) -> Alert<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    Alert::new(content)
+}
@@ -0,0 +1,446 @@
+use std::path::PathBuf;
+
+use crate::{
+    Element,
+    theme::{
+        Theme,
+        container::ContainerStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
+    },
+    widgets::image_fallback::ImageFallback,
+};
+use iced::advanced::image::Bytes;
+use iced::{
+    ContentFit, Padding, Point, Radians, Rectangle, Renderer, Shadow, mouse,
+    widget::{
+        canvas::{self, Canvas, Frame, Geometry, Path, path::Arc},
+        column, container, image, stack, text,
+    },
+};
+
+/// The shape of an avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvatarShape {
+    #[default]
+    Circle,
+    Square,
+    Rounded,
+}
+
+/// The palettes avatar backgrounds are picked from
+const BACKGROUNDS: [ColorVariant; 17] = [
+    ColorVariant::Red,
+    ColorVariant::Orange,
+    ColorVariant::Amber,
+    ColorVariant::Yellow,
+    ColorVariant::Lime,
+    ColorVariant::Green,
+    ColorVariant::Emerald,
+    ColorVariant::Teal,
+    ColorVariant::Cyan,
+    ColorVariant::Sky,
+    ColorVariant::Blue,
+    ColorVariant::Indigo,
+    ColorVariant::Violet,
+    ColorVariant::Purple,
+    ColorVariant::Fuchsia,
+    ColorVariant::Pink,
+    ColorVariant::Rose,
+];
+
+/// A Shoelace-style avatar component for iced
+///
+/// This component implements the features from Shoelace's avatar component:
+/// - Image from a file path or in-memory bytes
+/// - Initials, computed from the name unless set explicitly
+/// - Generic person icon when there is neither an image nor a name
+/// - Circle, square and rounded shapes
+/// - Configurable size
+///
+/// When the image cannot be loaded the avatar falls back to the initials, then to
+/// the icon. The background behind initials is picked from the color palettes by
+/// hashing the name, so the same name always gets the same color.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Avatar, AvatarShape};
+///
+/// let avatar = Avatar::new()
+///     .name("Ada Lovelace")
+///     .image_path("avatars/ada.png")
+///     .shape(AvatarShape::Rounded);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Avatar {
+    name: Option<String>,
+    initials: Option<String>,
+    image: Option<image::Handle>,
+    shape: AvatarShape,
+    size: f32,
+    ring: bool,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl Default for Avatar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Avatar {
+    /// Creates a new avatar showing the generic icon
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            initials: None,
+            image: None,
+            shape: AvatarShape::Circle,
+            size: 36.0,
+            ring: false,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the name the initials and background color are derived from
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the initials, overriding the ones computed from the name
+    pub fn initials(mut self, initials: impl Into<String>) -> Self {
+        self.initials = Some(initials.into());
+        self
+    }
+
+    /// Sets the image to load from a file
+    pub fn image_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.image = Some(image::Handle::from_path(path.into()));
+        self
+    }
+
+    /// Sets the image to decode from encoded bytes (PNG, JPEG, ...)
+    pub fn image_bytes(mut self, bytes: impl Into<Bytes>) -> Self {
+        self.image = Some(image::Handle::from_bytes(bytes));
+        self
+    }
+
+    /// Sets the image from an existing handle
+    pub fn image(mut self, handle: image::Handle) -> Self {
+        self.image = Some(handle);
+        self
+    }
+
+    /// Sets the shape of the avatar
+    /// Default: Circle
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the width and height of the avatar (in pixels)
+    /// Default: 36
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Draws a ring in the page background color, separating overlapping avatars
+    pub(crate) fn ring(mut self, ring: bool) -> Self {
+        self.ring = ring;
+        self
+    }
+
+    /// The initials to show, if any
+    fn get_initials(&self) -> Option<String> {
+        if let Some(initials) = &self.initials {
+            return Some(initials.clone());
+        }
+
+        initials_of(self.name.as_deref()?)
+    }
+
+    /// Gets the border radius for the shape
+    fn get_border_radius(&self) -> f32 {
+        let sizes = self.sizes;
+        match self.shape {
+            AvatarShape::Circle => self.size / 2.0,
+            AvatarShape::Square => 0.0,
+            AvatarShape::Rounded => sizes.border_radius.medium,
+        }
+    }
+}
+
+impl<'a, Message> From<Avatar> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(avatar: Avatar) -> Self {
+        let size = avatar.size;
+        let border_radius = avatar.get_border_radius();
+
+        let (content, background): (Element<'a, Message>, _) = match avatar.get_initials() {
+            Some(initials) => (
+                text(initials)
+                    .size(size * 0.5)
+                    .line_height(1.0)
+                    .font(avatar.fonts.sans)
+                    .into(),
+                background_of(avatar.name.as_deref().unwrap_or_default()),
+            ),
+            None => (
+                Canvas::new(PersonIcon)
+                    .width(size * 0.6)
+                    .height(size * 0.6)
+                    .into(),
+                ColorToken::new(ColorVariant::Neutral, ColorValue::C400),
+            ),
+        };
+
+        let (border_color, border_width) = if avatar.ring {
+            (
+                Some(ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50)),
+                2.0,
+            )
+        } else {
+            (None, 0.0)
+        };
+
+        let class = |background| ContainerStyleClass::Custom {
+            background,
+            text_color: Some(ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50)),
+            border_color,
+            border_width,
+            border_radius,
+            shadow: Shadow::default(),
+            snap: false,
+        };
+
+        let fallback = container(content)
+            .center(size)
+            .class(class(Some(background)));
+
+        let Some(handle) = avatar.image else {
+            return fallback.into();
+        };
+
+        // The ring is drawn over the image so it keeps the avatar's outline
+        let picture = stack![
+            image(handle.clone())
+                .width(size)
+                .height(size)
+                .content_fit(ContentFit::Cover)
+                .border_radius(border_radius),
+            container(column![])
+                .width(size)
+                .height(size)
+                .class(class(None)),
+        ];
+
+        ImageFallback::new(handle, picture, fallback).into()
+    }
+}
+
+/// Helper function to create an avatar
+pub fn avatar() -> Avatar {
+    Avatar::new()
+}
+
+/// A row of overlapping avatars, ending with a "+N" badge for the ones not shown
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Avatar, AvatarGroup};
+///
+/// let group = AvatarGroup::new()
+///     .push(Avatar::new().name("Ada Lovelace"))
+///     .push(Avatar::new().name("Alan Turing"))
+///     .push(Avatar::new().name("Grace Hopper"))
+///     .max(2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AvatarGroup {
+    avatars: Vec<Avatar>,
+    max: Option<usize>,
+    size: Option<f32>,
+}
+
+impl AvatarGroup {
+    /// Creates an empty avatar group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an avatar to the group
+    pub fn push(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    /// Sets how many avatars are shown before the rest are counted in the badge
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the size of every avatar in the group, overriding their own
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl<'a, Message> From<AvatarGroup> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(group: AvatarGroup) -> Self {
+        let shown = group
+            .max
+            .unwrap_or(group.avatars.len())
+            .min(group.avatars.len());
+        let hidden = group.avatars.len() - shown;
+        let size = group
+            .size
+            .or_else(|| group.avatars.first().map(|avatar| avatar.size))
+            .unwrap_or(36.0);
+        let fonts = group.avatars.first().map_or(FONTS, |avatar| avatar.fonts);
+
+        let mut items: Vec<Element<'a, Message>> = group
+            .avatars
+            .into_iter()
+            .take(shown)
+            .map(|avatar| avatar.size(size).ring(true).into())
+            .collect();
+
+        if hidden > 0 {
+            let label = text(format!("+{hidden}"))
+                .size(size * 0.4)
+                .line_height(1.0)
+                .font(fonts.sans);
+
+            items.push(
+                container(label)
+                    .center(size)
+                    .class(ContainerStyleClass::Custom {
+                        background: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C200)),
+                        text_color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
+                        border_color: Some(ColorToken::new(
+                            ColorVariant::NeutralBase,
+                            ColorValue::C50,
+                        )),
+                        border_width: 2.0,
+                        border_radius: size / 2.0,
+                        shadow: Shadow::default(),
+                        snap: false,
+                    })
+                    .into(),
+            );
+        }
+
+        // Each avatar overlaps the previous one by a third of its size; the first
+        // layer reserves the width of the whole group
+        let step = size * 2.0 / 3.0;
+        let width = size + step * items.len().saturating_sub(1) as f32;
+
+        let mut layers = stack![container(column![]).width(width).height(size)];
+
+        for (index, item) in items.into_iter().enumerate() {
+            layers = layers.push(container(item).padding(Padding {
+                left: step * index as f32,
+                ..Padding::ZERO
+            }));
+        }
+
+        layers.into()
+    }
+}
+
+/// Helper function to create an avatar group
+pub fn avatar_group() -> AvatarGroup {
+    AvatarGroup::new()
+}
+
+/// Computes up to two initials from the first and last words of a name
+fn initials_of(name: &str) -> Option<String> {
+    let mut words = name.split_whitespace();
+    let first = words.next()?.chars().next()?;
+
+    let mut initials: String = first.to_uppercase().collect();
+
+    if let Some(last) = words.last().and_then(|word| word.chars().next()) {
+        initials.extend(last.to_uppercase());
+    }
+
+    Some(initials)
+}
+
+/// Picks a background color for a name with a stable FNV-1a hash
+fn background_of(name: &str) -> ColorToken {
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+
+    let variant = BACKGROUNDS[hash as usize % BACKGROUNDS.len()];
+
+    ColorToken::new(variant, ColorValue::C600)
+}
+
+/// Canvas program drawing a generic head and shoulders silhouette
+struct PersonIcon;
+
+impl<Message> canvas::Program<Message, Theme> for PersonIcon {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let color = theme.tokens().neutral_0;
+        let width = bounds.width;
+
+        frame.fill(
+            &Path::circle(Point::new(width / 2.0, width * 0.3), width * 0.22),
+            color,
+        );
+
+        let shoulders = Path::new(|builder| {
+            builder.arc(Arc {
+                center: Point::new(width / 2.0, bounds.height),
+                radius: width * 0.45,
+                start_angle: Radians(std::f32::consts::PI),
+                end_angle: Radians(std::f32::consts::TAU),
+            });
+            builder.close();
+        });
+
+        frame.fill(&shoulders, color);
+
+        vec![frame.into_geometry()]
+    }
+}
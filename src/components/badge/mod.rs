@@ -3,7 +3,9 @@ use crate::{
     theme::{
         badge::BadgeVariant,
         container::ContainerStyleClass,
-        sizes::{BORDER_RADIUS, FONT_SIZE, LINE_HEIGHT, SPACING},
+        parts::{Part, Parts, Status},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
     },
 };
 use iced::{
@@ -18,11 +20,22 @@ use iced_core::Length;
 /// - Multiple variants (primary, success, neutral, warning, danger)
 /// - Pill style (fully rounded corners)
 /// - Pulse animation effect
+/// - Style overrides of its parts, see [`BadgePart`]
 pub struct Badge {
     content: String,
     variant: BadgeVariant,
     pill: bool,
     pulse: bool,
+    parts: Parts<BadgePart>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+/// The parts of a badge that can be restyled, matching Shoelace's CSS parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgePart {
+    /// The badge itself: background, border, shadow and text color
+    Base,
 }
 
 impl Badge {
@@ -33,6 +46,9 @@ impl Badge {
             variant: BadgeVariant::Primary,
             pill: false,
             pulse: false,
+            parts: Parts::new(),
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -54,40 +70,64 @@ impl Badge {
         self
     }
 
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Overrides the style of a part, merged over the theme's style
+    pub fn part(mut self, part: BadgePart, style: impl Into<Part>) -> Self {
+        self.parts.set(part, style.into());
+        self
+    }
+
     /// Gets the appropriate padding for the badge
     /// Following Shoelace spec: small padding for compact appearance
     fn get_padding(&self) -> Padding {
         // Badges in Shoelace use: 0 0.5rem (0px 8px)
-        Padding::from([SPACING.x2_small, SPACING.small])
+        Padding::from([self.sizes.spacing.x2_small, self.sizes.spacing.small])
     }
 
     /// Gets the appropriate border radius
     fn get_border_radius(&self) -> f32 {
         if self.pill {
             // Pill badges should be fully rounded
-            BORDER_RADIUS.x_large * 10.0
+            self.sizes.border_radius.x_large * 10.0
         } else {
             // Standard badges use medium border radius
-            BORDER_RADIUS.medium
+            self.sizes.border_radius.medium
         }
     }
 }
 
 impl<'a, Message: 'a> From<Badge> for Element<'a, Message> {
     fn from(badge: Badge) -> Self {
+        let sizes = badge.sizes;
+        let fonts = badge.fonts;
         let padding = badge.get_padding();
         let border_radius = badge.get_border_radius();
 
         // Badge text styling - small size
         let badge_text = text(badge.content)
-            .size(FONT_SIZE.small)
-            .line_height(LINE_HEIGHT.dense);
+            .size(sizes.font_size.small)
+            .line_height(sizes.line_height.dense)
+            .font(fonts.sans);
 
         // Create style class using ContainerStyleClass
         let style_class = ContainerStyleClass::Badge {
             variant: badge.variant,
             border_radius,
             pulse: badge.pulse,
+            part: badge.parts.get(BadgePart::Base).get(Status::Active),
         };
 
         // Build the container with appropriate styling
@@ -3,8 +3,10 @@ use crate::{
     theme::{
         button::ButtonStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{BORDER_RADIUS, FONT_SIZE, LINE_HEIGHT, SPACING},
+        parts::Part,
+        sizes::{SIZES, Sizes},
         text::TextStyleClass,
+        typography::{FONTS, Fonts},
     },
 };
 use iced::{
@@ -24,6 +26,8 @@ use iced_core::Length;
 pub struct Breadcrumb<Message> {
     items: Vec<BreadcrumbItem<Message>>,
     separator: String,
+    sizes: Sizes,
+    fonts: Fonts,
 }
 
 /// A single item in a breadcrumb trail
@@ -57,6 +61,8 @@ where
         Self {
             items: Vec::new(),
             separator: "/".to_string(),
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -71,6 +77,20 @@ where
         self.separator = separator.into();
         self
     }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
 }
 
 impl<Message> Default for Breadcrumb<Message>
@@ -87,10 +107,14 @@ where
     Message: Clone,
 {
     fn from(breadcrumb: Breadcrumb<Message>) -> Self {
+        let sizes = breadcrumb.sizes;
+        let fonts = breadcrumb.fonts;
         let separator = breadcrumb.separator;
         let items_count = breadcrumb.items.len();
 
-        let mut breadcrumb_row = row([]).spacing(SPACING.x2_small).align_y(Alignment::Center);
+        let mut breadcrumb_row = row([])
+            .spacing(sizes.spacing.x2_small)
+            .align_y(Alignment::Center);
 
         for (idx, item) in breadcrumb.items.into_iter().enumerate() {
             let is_last = idx == items_count - 1;
@@ -103,19 +127,22 @@ where
                 let button_style = ButtonStyleClass {
                     variant: crate::theme::button::ButtonVariant::Text,
                     outline: false,
-                    border_radius: Radius::from(BORDER_RADIUS.medium),
+                    border_radius: Radius::from(sizes.border_radius.medium),
                     disabled: false,
+                    base: Part::default(),
+                    label: Part::default(),
                 };
 
                 let button = iced::widget::button(
                     text(label)
-                        .size(FONT_SIZE.medium)
-                        .line_height(LINE_HEIGHT.normal)
+                        .size(sizes.font_size.medium)
+                        .line_height(sizes.line_height.normal)
+                        .font(fonts.sans)
                         .class(TextStyleClass {
-                            color: Some(ColorToken::new(ColorVariant::Sky, ColorValue::C600)),
+                            color: Some(ColorToken::new(ColorVariant::Primary, ColorValue::C600)),
                         }),
                 )
-                .padding([0., SPACING.x_small])
+                .padding([0., sizes.spacing.x_small])
                 .class(button_style)
                 .on_press(message);
 
@@ -124,8 +151,9 @@ where
                 // Non-clickable item (current page)
                 Element::from(
                     text(label)
-                        .size(FONT_SIZE.medium)
-                        .line_height(LINE_HEIGHT.normal)
+                        .size(sizes.font_size.medium)
+                        .line_height(sizes.line_height.normal)
+                        .font(fonts.sans)
                         .class(TextStyleClass {
                             color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C600)),
                         }),
@@ -137,8 +165,9 @@ where
             // Add separator if not the last item
             if !is_last {
                 let separator_element = text(separator_str)
-                    .size(FONT_SIZE.medium)
-                    .line_height(LINE_HEIGHT.normal)
+                    .size(sizes.font_size.medium)
+                    .line_height(sizes.line_height.normal)
+                    .font(fonts.sans)
                     .class(TextStyleClass {
                         color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C400)),
                     });
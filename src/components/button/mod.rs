@@ -1,15 +1,21 @@
 use crate::{
     Element,
+    components::spinner::Spinner,
     theme::{
         Theme,
         button::{ButtonSize, ButtonStyleClass, ButtonVariant},
-        sizes::{BORDER_RADIUS, FONT_SIZE, LINE_HEIGHT, SPACING},
+        container::ContainerStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        parts::{Part, Parts, Status},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
     },
+    widgets::{hidden::Hidden, status_area::StatusArea},
 };
 use iced::{
-    Alignment, Padding,
+    Alignment, Length, Padding,
     border::Radius,
-    widget::{self, Component, component, text},
+    widget::{self, Component, component, container, stack, text},
 };
 use iced_widget::Row;
 
@@ -25,6 +31,7 @@ use iced_widget::Row;
 /// - Disabled state
 /// - Optional caret icon
 /// - Prefix and suffix icons/content
+/// - Style overrides of its parts, see [`ButtonPart`]
 pub struct Button<Message> {
     label: String,
     variant: ButtonVariant,
@@ -37,6 +44,22 @@ pub struct Button<Message> {
     suffix: Option<String>,
     on_press: Option<Message>,
     custom_border_radius: Option<Radius>,
+    parts: Parts<ButtonPart>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+/// The parts of a button that can be restyled, matching Shoelace's CSS parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonPart {
+    /// The button itself: background, border, shadow and text color
+    Base,
+    /// The prefix content
+    Prefix,
+    /// The label; only the text color applies
+    Label,
+    /// The suffix content
+    Suffix,
 }
 
 impl<Message> Button<Message> {
@@ -54,6 +77,9 @@ impl<Message> Button<Message> {
             suffix: None,
             on_press: None,
             custom_border_radius: None,
+            parts: Parts::new(),
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -117,42 +143,91 @@ impl<Message> Button<Message> {
         self
     }
 
+    /// Overrides the style of a part, merged over the theme's style
+    ///
+    /// Pass a [`PartStyle`](crate::theme::parts::PartStyle), or a [`Part::with`] to
+    /// style each status. Every part is drawn in the button's status.
+    pub fn part(mut self, part: ButtonPart, style: impl Into<Part>) -> Self {
+        self.parts.set(part, style.into());
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
     /// Gets the appropriate padding based on size
     fn get_padding(&self) -> Padding {
+        let sizes = self.sizes;
         // Following Shoelace spec: small (0.5rem, 1rem), medium (0.75rem, 1.5rem), large (1rem, 2rem)
         match self.size {
-            ButtonSize::Small => Padding::from([SPACING.x_small, SPACING.medium]), // 8px, 16px
-            ButtonSize::Medium => Padding::from([SPACING.small, SPACING.x_large]), // 12px, 28px (close to 24px)
-            ButtonSize::Large => Padding::from([SPACING.medium, SPACING.x2_large]), // 16px, 36px (close to 32px)
+            ButtonSize::Small => Padding::from([sizes.spacing.x_small, sizes.spacing.medium]), // 8px, 16px
+            ButtonSize::Medium => Padding::from([sizes.spacing.small, sizes.spacing.x_large]), // 12px, 28px (close to 24px)
+            ButtonSize::Large => Padding::from([sizes.spacing.medium, sizes.spacing.x2_large]), // 16px, 36px (close to 32px)
         }
     }
 
     /// Gets the appropriate border radius
     fn get_border_radius(&self) -> Radius {
+        let sizes = self.sizes;
         // Use custom border radius if set (for button groups)
         if let Some(custom_radius) = self.custom_border_radius {
             return custom_radius;
         }
 
         let radius = if self.pill {
-            BORDER_RADIUS.x_large * 10.0
+            sizes.border_radius.x_large * 10.0
         } else {
             match self.size {
-                ButtonSize::Small => BORDER_RADIUS.small,
-                ButtonSize::Medium => BORDER_RADIUS.medium,
-                ButtonSize::Large => BORDER_RADIUS.large,
+                ButtonSize::Small => sizes.border_radius.small,
+                ButtonSize::Medium => sizes.border_radius.medium,
+                ButtonSize::Large => sizes.border_radius.large,
             }
         };
 
         Radius::from(radius)
     }
 
+    /// Gets the spinner color, matching the label color of the variant
+    fn get_spinner_color(&self) -> ColorToken {
+        if self.disabled {
+            return ColorToken::new(ColorVariant::Neutral, ColorValue::C500);
+        }
+
+        // The semantic color scale of the variant
+        let variant = match self.variant {
+            ButtonVariant::Default | ButtonVariant::Neutral => ColorVariant::Neutral,
+            ButtonVariant::Primary | ButtonVariant::Text => ColorVariant::Primary,
+            ButtonVariant::Success => ColorVariant::Success,
+            ButtonVariant::Warning => ColorVariant::Warning,
+            ButtonVariant::Danger => ColorVariant::Danger,
+        };
+
+        match self.variant {
+            ButtonVariant::Default => ColorToken::new(ColorVariant::Neutral, ColorValue::C700),
+            ButtonVariant::Text => ColorToken::new(variant, ColorValue::C600),
+            _ if self.outline => ColorToken::new(variant, ColorValue::C600),
+            _ => ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50),
+        }
+    }
+
     /// Gets the appropriate font size
     fn get_font_size(&self) -> f32 {
+        let sizes = self.sizes;
         match self.size {
-            ButtonSize::Small => FONT_SIZE.small,
-            ButtonSize::Medium => FONT_SIZE.medium,
-            ButtonSize::Large => FONT_SIZE.large,
+            ButtonSize::Small => sizes.font_size.small,
+            ButtonSize::Medium => sizes.font_size.medium,
+            ButtonSize::Large => sizes.font_size.large,
         }
     }
 }
@@ -160,16 +235,17 @@ impl<Message> Button<Message> {
 #[derive(Debug, Clone)]
 pub enum Event {
     Pressed,
+    StatusChanged(Status),
 }
 
 impl<'a, Message> Component<'a, Message, Theme> for Button<Message>
 where
     Message: Clone,
 {
-    type State = ();
+    type State = Status;
     type Event = Event;
 
-    fn update(&mut self, _state: &mut Self::State, event: Self::Event) -> Option<Message> {
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
         match event {
             Event::Pressed => {
                 if !self.loading && !self.disabled {
@@ -178,21 +254,20 @@ where
                     None
                 }
             }
+            Event::StatusChanged(status) => {
+                *state = status;
+                None
+            }
         }
     }
 
-    fn view(&self, _state: &Self::State) -> Element<'a, Self::Event> {
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
         let padding = self.get_padding();
         let border_radius = self.get_border_radius();
         let font_size = self.get_font_size();
-        let line_height = LINE_HEIGHT.dense;
-
-        // Build label text with optional caret
-        let label_str = if self.loading {
-            "⋯".to_string()
-        } else {
-            self.label.clone()
-        };
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let line_height = sizes.line_height.dense;
 
         let variant = self.variant;
         let outline = self.outline;
@@ -208,11 +283,19 @@ where
             outline,
             border_radius,
             disabled,
+            base: self.parts.get(ButtonPart::Base),
+            label: self.parts.get(ButtonPart::Label),
+        };
+        // The status the base and label are drawn with, for the prefix and suffix
+        let status = if disabled || loading || !has_on_press {
+            Status::Disabled
+        } else {
+            *state
         };
 
         // Build the button content with prefix, label, and suffix
         let mut row_content = Row::new()
-            .spacing(SPACING.x2_small)
+            .spacing(sizes.spacing.x2_small)
             .align_y(Alignment::Center);
 
         // Add prefix if present
@@ -220,27 +303,54 @@ where
             && !prefix_text.is_empty()
         {
             row_content = row_content.push(
-                text(prefix_text.clone())
-                    .size(font_size)
-                    .line_height(line_height),
+                container(
+                    text(prefix_text.clone())
+                        .size(font_size)
+                        .line_height(line_height)
+                        .font(fonts.sans),
+                )
+                .class(ContainerStyleClass::Part(
+                    self.parts.get(ButtonPart::Prefix).get(status),
+                )),
             );
         }
 
         // Add main label with proper typography
-        row_content = row_content.push(
-            text(label_str.clone())
-                .size(font_size)
-                .line_height(line_height),
-        );
+        let label = text(self.label.clone())
+            .size(font_size)
+            .line_height(line_height)
+            .font(fonts.sans);
+
+        // Shoelace hides the label while loading, keeping its width, and centers a spinner over it
+        if loading {
+            row_content = row_content.push(stack![
+                Hidden::new(label),
+                container(
+                    Spinner::new()
+                        .size(font_size)
+                        .track_color(None)
+                        .indicator_color(self.get_spinner_color()),
+                )
+                .center(Length::Fill),
+            ]);
+        } else {
+            row_content = row_content.push(label);
+        }
 
         // Add suffix if present
         if let Some(suffix_text) = &suffix
             && !suffix_text.is_empty()
         {
             row_content = row_content.push(
-                text(suffix_text.clone())
-                    .size(font_size)
-                    .line_height(line_height),
+                container(
+                    text(suffix_text.clone())
+                        .size(font_size)
+                        .line_height(line_height)
+                        .font(fonts.sans),
+                )
+                .class(ContainerStyleClass::Part(
+                    self.parts.get(ButtonPart::Suffix).get(status),
+                )),
             );
         }
 
@@ -256,9 +366,7 @@ where
                 None
             });
 
-        let element: Element<'a, Event> = btn.into();
-
-        element.map(|_message| Event::Pressed)
+        StatusArea::new(btn).on_change(Event::StatusChanged).into()
     }
 }
 
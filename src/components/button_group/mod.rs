@@ -4,8 +4,9 @@ use crate::{
     theme::{
         container::ContainerStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{BORDER_RADIUS, FONT_SIZE, LINE_HEIGHT, SPACING},
+        sizes::{SIZES, Sizes},
         text::TextStyleClass,
+        typography::{FONTS, Fonts},
     },
 };
 use iced::widget::{Row, container, text};
@@ -36,6 +37,8 @@ pub struct ButtonGroup<'a, Message> {
     buttons: Vec<Button<Message>>,
     label: Option<String>,
     spacing: f32,
+    sizes: Sizes,
+    fonts: Fonts,
     _phantom: std::marker::PhantomData<&'a ()>,
 }
 
@@ -46,6 +49,8 @@ impl<'a, Message> ButtonGroup<'a, Message> {
             buttons: Vec::new(),
             label: None,
             spacing: -1.0, // Negative spacing to overlap borders
+            sizes: SIZES,
+            fonts: FONTS,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -56,6 +61,8 @@ impl<'a, Message> ButtonGroup<'a, Message> {
             buttons,
             label: None,
             spacing: -1.0, // Negative spacing to overlap borders
+            sizes: SIZES,
+            fonts: FONTS,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -78,13 +85,29 @@ impl<'a, Message> ButtonGroup<'a, Message> {
         self
     }
 
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
     /// Builds the button group into an Element
     pub fn build(self) -> Element<'a, Message>
     where
         Message: Clone + 'a,
     {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
         let button_count = self.buttons.len();
-        let border_radius = BORDER_RADIUS.medium;
+        let border_radius = sizes.border_radius.medium;
 
         // Create the button row with connected appearance
         let mut button_row = Row::new().spacing(self.spacing);
@@ -137,15 +160,16 @@ impl<'a, Message> ButtonGroup<'a, Message> {
             };
 
             let label: Element<'a, Message> = text(label_text)
-                .size(FONT_SIZE.small)
-                .line_height(LINE_HEIGHT.normal)
+                .size(sizes.font_size.small)
+                .line_height(sizes.line_height.normal)
+                .font(fonts.sans)
                 .class(label_style)
                 .into();
 
             // Wrap label and group in a column with proper spacing
             container(
                 Column::new()
-                    .spacing(SPACING.x2_small)
+                    .spacing(sizes.spacing.x2_small)
                     .push(label)
                     .push(group_container),
             )
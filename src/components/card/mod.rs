@@ -1,6 +1,9 @@
 use crate::{
     Element,
-    theme::{container::ContainerStyleClass, sizes::SPACING},
+    theme::{
+        container::ContainerStyleClass,
+        sizes::{SIZES, Sizes},
+    },
 };
 use iced::{
     Length,
@@ -23,7 +26,8 @@ pub struct Card<'a, Message> {
     image: Option<Element<'a, Message>>,
     width: Length,
     height: Length,
-    padding: f32,
+    padding: Option<f32>,
+    sizes: Sizes,
 }
 
 impl<'a, Message> Card<'a, Message>
@@ -39,7 +43,8 @@ where
             image: None,
             width: Length::Shrink,
             height: Length::Shrink,
-            padding: SPACING.large,
+            padding: None,
+            sizes: SIZES,
         }
     }
 
@@ -73,9 +78,16 @@ where
         self
     }
 
-    /// Sets the padding of the card content (default is SPACING.large)
+    /// Sets the padding of the card content (default is the large spacing)
     pub fn padding(mut self, padding: f32) -> Self {
-        self.padding = padding;
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
         self
     }
 }
@@ -85,6 +97,7 @@ where
     Message: Clone,
 {
     fn from(card: Card<'a, Message>) -> Self {
+        let padding = card.padding.unwrap_or(card.sizes.spacing.large);
         let mut card_column = column([]).spacing(0).width(card.width).height(card.height);
 
         // Add image if present (no padding, full width)
@@ -102,7 +115,7 @@ where
         if let Some(header) = card.header {
             let header_container = container(header)
                 .width(Length::Fill)
-                .padding(card.padding)
+                .padding(padding)
                 .class(ContainerStyleClass::CardHeader);
 
             card_column = card_column.push(header_container);
@@ -112,7 +125,7 @@ where
         // Shoelace applies consistent padding to body content
         let content_container = container(card.content)
             .width(Length::Fill)
-            .padding(card.padding)
+            .padding(padding)
             .class(ContainerStyleClass::CardContent);
 
         card_column = card_column.push(content_container);
@@ -121,7 +134,7 @@ where
         if let Some(footer) = card.footer {
             let footer_container = container(footer)
                 .width(Length::Fill)
-                .padding(card.padding)
+                .padding(padding)
                 .class(ContainerStyleClass::CardFooter);
 
             card_column = card_column.push(footer_container);
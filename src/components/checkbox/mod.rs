@@ -3,12 +3,17 @@ use crate::{
     theme::{
         Theme,
         checkbox::CheckboxStyleClass,
+        container::ContainerStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{FONT_SIZE, LINE_HEIGHT, SPACING, TOGGLE_SIZE},
-        text::TextStyleClass,
+        parts::{Part, PartStyle, Parts, Status},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
     },
 };
-use iced::widget::{Component, checkbox as iced_checkbox, component, text};
+use iced::{
+    Font,
+    widget::{Component, checkbox as iced_checkbox, component, container, text},
+};
 use iced_widget::Column;
 
 /// Size variants for checkboxes
@@ -21,27 +26,108 @@ pub enum CheckboxSize {
 }
 
 impl CheckboxSize {
-    fn control_size(&self) -> f32 {
+    fn control_size(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => TOGGLE_SIZE.small,
-            Self::Medium => TOGGLE_SIZE.medium,
-            Self::Large => TOGGLE_SIZE.large,
+            Self::Small => sizes.toggle_size.small,
+            Self::Medium => sizes.toggle_size.medium,
+            Self::Large => sizes.toggle_size.large,
         }
     }
 
-    fn font_size(&self) -> f32 {
+    fn font_size(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => FONT_SIZE.small,
-            Self::Medium => FONT_SIZE.medium,
-            Self::Large => FONT_SIZE.large,
+            Self::Small => sizes.font_size.small,
+            Self::Medium => sizes.font_size.medium,
+            Self::Large => sizes.font_size.large,
         }
     }
 
-    fn spacing(&self) -> f32 {
+    fn spacing(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => SPACING.x2_small,
-            Self::Medium => SPACING.x_small,
-            Self::Large => SPACING.small,
+            Self::Small => sizes.spacing.x2_small,
+            Self::Medium => sizes.spacing.x_small,
+            Self::Large => sizes.spacing.small,
+        }
+    }
+}
+
+/// The state of a checkbox that can be partially checked
+///
+/// Used by "select all" checkboxes, whose state is derived from the
+/// checkboxes they control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    /// Computes the state of a parent checkbox from its children
+    ///
+    /// All checked yields `Checked`, none checked (or no children) yields
+    /// `Unchecked`, and anything in between yields `Indeterminate`.
+    pub fn from_children(children: impl IntoIterator<Item = bool>) -> Self {
+        let (mut checked, mut total) = (0usize, 0usize);
+
+        for child in children {
+            total += 1;
+            if child {
+                checked += 1;
+            }
+        }
+
+        if checked == 0 {
+            Self::Unchecked
+        } else if checked == total {
+            Self::Checked
+        } else {
+            Self::Indeterminate
+        }
+    }
+
+    /// Combines the states of nested groups into the state of their parent
+    ///
+    /// Any indeterminate child makes the parent indeterminate.
+    pub fn from_states(states: impl IntoIterator<Item = CheckState>) -> Self {
+        let (mut checked, mut total) = (0usize, 0usize);
+
+        for state in states {
+            total += 1;
+            match state {
+                Self::Checked => checked += 1,
+                Self::Indeterminate => return Self::Indeterminate,
+                Self::Unchecked => {}
+            }
+        }
+
+        if checked == 0 {
+            Self::Unchecked
+        } else if checked == total {
+            Self::Checked
+        } else {
+            Self::Indeterminate
+        }
+    }
+
+    /// Returns whether the state is fully checked
+    pub fn is_checked(self) -> bool {
+        self == Self::Checked
+    }
+
+    /// Returns whether the state is partially checked
+    pub fn is_indeterminate(self) -> bool {
+        self == Self::Indeterminate
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
         }
     }
 }
@@ -54,6 +140,7 @@ impl CheckboxSize {
 /// - Disabled state
 /// - Help text support
 /// - Custom label
+/// - Style overrides of its parts, see [`CheckboxPart`]
 pub struct Checkbox<Message> {
     label: String,
     checked: bool,
@@ -62,6 +149,22 @@ pub struct Checkbox<Message> {
     disabled: bool,
     help_text: Option<String>,
     on_toggle: Option<Box<dyn Fn(bool) -> Message>>,
+    parts: Parts<CheckboxPart>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+/// The parts of a checkbox that can be restyled, matching Shoelace's CSS parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxPart {
+    /// The box: background, border and check mark color
+    Control,
+    /// The check or indeterminate mark; only the text color applies
+    Icon,
+    /// The label; only the text color applies
+    Label,
+    /// The help text below the checkbox
+    HelpText,
 }
 
 impl<Message> Checkbox<Message> {
@@ -75,6 +178,9 @@ impl<Message> Checkbox<Message> {
             disabled: false,
             help_text: None,
             on_toggle: None,
+            parts: Parts::new(),
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -84,6 +190,11 @@ impl<Message> Checkbox<Message> {
         self
     }
 
+    /// Creates a new checkbox from a tri-state value
+    pub fn with_state(label: impl Into<String>, state: CheckState) -> Self {
+        Self::new(label, state.is_checked()).indeterminate(state.is_indeterminate())
+    }
+
     /// Sets whether the checkbox is disabled
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -110,6 +221,28 @@ impl<Message> Checkbox<Message> {
         self.on_toggle = Some(Box::new(f));
         self
     }
+
+    /// Overrides the style of a part, merged over the theme's style
+    ///
+    /// Only the help text does not see the hovered state.
+    pub fn part(mut self, part: CheckboxPart, style: impl Into<Part>) -> Self {
+        self.parts.set(part, style.into());
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -128,6 +261,10 @@ where
         match event {
             Event::Toggled(new_state) => {
                 if !self.disabled {
+                    // An indeterminate box is drawn as checked, so iced reports `false`
+                    // when it is clicked; Shoelace resolves it to checked instead
+                    let new_state = self.indeterminate || new_state;
+
                     self.checked = new_state;
                     // Clear indeterminate when user toggles
                     self.indeterminate = false;
@@ -140,10 +277,11 @@ where
     }
 
     fn view(&self, _state: &Self::State) -> Element<'a, Self::Event> {
-        let font_size = self.size.font_size();
-        let spacing = self.size.spacing();
-        let line_height = LINE_HEIGHT.dense;
-        let control_size = self.size.control_size();
+        let sizes = self.sizes;
+        let font_size = self.size.font_size(sizes);
+        let spacing = self.size.spacing(sizes);
+        let line_height = sizes.line_height.dense;
+        let control_size = self.size.control_size(sizes);
 
         let label_text = self.label.clone();
         let checked = self.checked;
@@ -156,13 +294,18 @@ where
             is_checked: checked,
             is_indeterminate: indeterminate,
             is_disabled: disabled,
+            control: self.parts.get(CheckboxPart::Control),
+            icon: self.parts.get(CheckboxPart::Icon),
+            label: self.parts.get(CheckboxPart::Label),
         };
 
         // Build the main checkbox control with label
-        let checkbox_control = iced_checkbox(label_text, checked)
+        // Indeterminate boxes are drawn checked with a dash instead of the check mark
+        let mut checkbox_control = iced_checkbox(label_text, checked || indeterminate)
             .size(control_size)
             .text_size(font_size)
             .text_line_height(line_height)
+            .font(self.fonts.sans)
             .class(style_class)
             .on_toggle_maybe(if !disabled {
                 Some(Event::Toggled)
@@ -170,16 +313,33 @@ where
                 None
             });
 
+        if indeterminate {
+            checkbox_control = checkbox_control.icon(iced_checkbox::Icon {
+                font: Font::DEFAULT,
+                code_point: '\u{2212}',
+                size: Some(control_size.into()),
+                line_height: text::LineHeight::Relative(1.0),
+                shaping: text::Shaping::Basic,
+            });
+        }
+
         // Build the complete control with optional help text
         let mut content = Column::new().spacing(spacing);
         content = content.push(checkbox_control);
 
         // Add help text if present
         if let Some(help) = help_text {
-            let help_style = text(help).size(FONT_SIZE.small).class(TextStyleClass {
-                color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C500)),
-            });
-            content = content.push(help_style);
+            let help_style = PartStyle::new()
+                .text_color(ColorToken::new(ColorVariant::Neutral, ColorValue::C500))
+                .merge(
+                    self.parts
+                        .get(CheckboxPart::HelpText)
+                        .get(Status::of(disabled)),
+                );
+            content = content.push(
+                container(text(help).size(sizes.font_size.small).font(self.fonts.sans))
+                    .class(ContainerStyleClass::Part(help_style)),
+            );
         }
 
         content.into()
@@ -202,3 +362,27 @@ where
 {
     Checkbox::new(label, checked)
 }
+
+/// Helper function to create a "select all" checkbox for a group of children
+///
+/// The checkbox is checked when every child is checked, unchecked when none
+/// are, and indeterminate otherwise. Toggling it reports the value that should
+/// be applied to every child, so an indeterminate parent selects all.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::checkbox::select_all;
+///
+/// let parent = select_all("Select all", state.rows.iter().map(|row| row.selected))
+///     .on_toggle(Message::SelectAll);
+/// ```
+pub fn select_all<Message>(
+    label: impl Into<String>,
+    children: impl IntoIterator<Item = bool>,
+) -> Checkbox<Message>
+where
+    Message: Clone,
+{
+    Checkbox::with_state(label, CheckState::from_children(children))
+}
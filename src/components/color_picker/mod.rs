@@ -0,0 +1,937 @@
+use crate::{
+    Element,
+    components::{button::Button, input::InputSize},
+    theme::{
+        Theme,
+        button::ButtonSize,
+        color,
+        container::ContainerStyleClass,
+        input::InputStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant, hsl},
+        parts::Part,
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        tokens::Tokens,
+        typography::{FONTS, Fonts},
+    },
+    widgets::popup::{Align, Popup, Side},
+};
+use iced::{
+    Alignment, Color, Length, Point, Rectangle, Renderer, Size, mouse,
+    widget::{
+        Component,
+        canvas::{self, Canvas, Frame, Geometry, Path, Stroke, gradient::Linear},
+        column, component, container, mouse_area, row, text, text_input,
+    },
+};
+use iced_widget::{Column, Row};
+use std::cell::Cell;
+
+pub use crate::theme::color::Format;
+
+/// Width of the picker panel, matching Shoelace's --grid-width
+const PANEL_WIDTH: f32 = 280.0;
+/// Height of the saturation/value grid, matching Shoelace's --grid-height
+const GRID_HEIGHT: f32 = 200.0;
+/// Diameter of the grid and slider handles
+const HANDLE_SIZE: f32 = 16.0;
+/// Height of the hue and opacity tracks
+const TRACK_HEIGHT: f32 = 12.0;
+/// Diameter of the preview swatch and the dropdown trigger
+const PREVIEW_SIZE: f32 = 32.0;
+/// Number of preset swatches per row
+const SWATCHES_PER_ROW: usize = 9;
+
+/// A Shoelace-style color picker component for iced
+///
+/// This component implements the features from Shoelace's color picker component:
+/// - Saturation/value grid for the current hue
+/// - Hue slider and optional opacity slider
+/// - Text field accepting hex, RGB or HSL notation, with validation
+/// - Button cycling the notation the text field shows
+/// - Row of preset swatches, defaulting to the `Tokens` color scales
+/// - Dropdown mode (a swatch trigger opening the picker) or inline mode
+/// - Label and disabled state
+///
+/// The picker keeps the hue it was dragged to, so moving the value to black or the
+/// saturation to zero does not lose it.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{ColorPicker, token_swatches};
+///
+/// let picker = ColorPicker::new(state.accent)
+///     .label("Accent")
+///     .opacity(true)
+///     .on_change(Message::AccentChanged);
+///
+/// // The swatches follow the theme the picker is drawn in; pick a darker shade
+/// let themed = ColorPicker::new(state.accent)
+///     .inline(true)
+///     .swatches(token_swatches(theme.tokens(), ColorValue::C700))
+///     .on_change(Message::AccentChanged);
+/// ```
+pub struct ColorPicker<Message> {
+    value: Color,
+    label: Option<String>,
+    format: Format,
+    opacity: bool,
+    inline: bool,
+    swatches: Vec<Preset>,
+    disabled: bool,
+    on_change: Option<Box<dyn Fn(Color) -> Message>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<Message> ColorPicker<Message> {
+    /// Creates a new color picker showing `value`
+    pub fn new(value: Color) -> Self {
+        Self {
+            value,
+            label: None,
+            format: Format::Hex,
+            opacity: false,
+            inline: false,
+            swatches: SCALES
+                .iter()
+                .map(|&variant| Preset::Token(ColorToken::new(variant, ColorValue::C500)))
+                .collect(),
+            disabled: false,
+            on_change: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the label shown above the picker
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the notation the text field starts in
+    /// Default: Hex
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether the opacity slider is shown; without it colors are kept opaque
+    /// Default: false
+    pub fn opacity(mut self, opacity: bool) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Sets whether the picker is shown inline instead of in a dropdown
+    /// Default: false
+    pub fn inline(mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Sets the preset swatches, or an empty list to hide the swatch row
+    /// Default: the 500 shade of every color scale of the theme the picker is drawn in
+    pub fn swatches(mut self, swatches: impl IntoIterator<Item = Color>) -> Self {
+        self.swatches = swatches.into_iter().map(Preset::Color).collect();
+        self
+    }
+
+    /// Sets whether the picker is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the callback invoked with the new color when it changes
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Color) -> Message,
+    {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+/// The color scales offered as preset swatches, in Shoelace's order
+const SCALES: [ColorVariant; 18] = [
+    ColorVariant::Red,
+    ColorVariant::Orange,
+    ColorVariant::Amber,
+    ColorVariant::Yellow,
+    ColorVariant::Lime,
+    ColorVariant::Green,
+    ColorVariant::Emerald,
+    ColorVariant::Teal,
+    ColorVariant::Cyan,
+    ColorVariant::Sky,
+    ColorVariant::Blue,
+    ColorVariant::Indigo,
+    ColorVariant::Violet,
+    ColorVariant::Purple,
+    ColorVariant::Fuchsia,
+    ColorVariant::Pink,
+    ColorVariant::Rose,
+    ColorVariant::Gray,
+];
+
+/// Returns one shade of every color scale of `tokens`, for use as preset swatches
+pub fn token_swatches(tokens: Tokens, shade: ColorValue) -> Vec<Color> {
+    SCALES
+        .iter()
+        .map(|&variant| variant.get_color(tokens, shade))
+        .collect()
+}
+
+/// A preset swatch
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Preset {
+    /// A fixed color
+    Color(Color),
+    /// A shade of a color scale, looked up in the theme the swatch is drawn in
+    Token(ColorToken),
+}
+
+/// A color in the HSV model the grid and sliders edit
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsva {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+    alpha: f32,
+}
+
+impl Hsva {
+    fn from_color(color: Color) -> Self {
+        let (hue, saturation, value) = color::to_hsv(color);
+
+        Self {
+            hue,
+            saturation,
+            value,
+            alpha: color.a,
+        }
+    }
+
+    fn color(self) -> Color {
+        Color {
+            a: self.alpha,
+            ..color::hsv(self.hue, self.saturation, self.value)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Toggled,
+    Closed,
+    SaturationValue(f32, f32),
+    Hue(f32),
+    Alpha(f32),
+    Swatch(Color),
+    Input(String),
+    FormatCycled,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ColorPickerState {
+    open: bool,
+    format: Option<Format>,
+    /// Text typed into the field, kept until the color is changed another way
+    draft: Option<String>,
+    /// The last color produced by the picker, with the HSV values it came from
+    last: Option<(Color, Hsva)>,
+}
+
+impl ColorPickerState {
+    /// The HSV values for `value`, reusing the last ones so grays keep their hue
+    fn hsva(&self, value: Color) -> Hsva {
+        match self.last {
+            Some((color, hsva)) if color == value => hsva,
+            _ => Hsva::from_color(value),
+        }
+    }
+}
+
+impl<'a, Message> Component<'a, Message, Theme> for ColorPicker<Message>
+where
+    Message: Clone + 'a,
+{
+    type State = ColorPickerState;
+    type Event = Event;
+
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
+        if self.disabled {
+            return None;
+        }
+
+        let mut hsva = state.hsva(self.value);
+
+        match event {
+            Event::Toggled => {
+                state.open = !state.open;
+                return None;
+            }
+            Event::Closed => {
+                state.open = false;
+                return None;
+            }
+            Event::FormatCycled => {
+                state.format = Some(match state.format.unwrap_or(self.format) {
+                    Format::Hex => Format::Rgb,
+                    Format::Rgb => Format::Hsl,
+                    Format::Hsl => Format::Hex,
+                });
+                state.draft = None;
+                return None;
+            }
+            Event::SaturationValue(saturation, value) => {
+                hsva.saturation = saturation;
+                hsva.value = value;
+                state.draft = None;
+            }
+            Event::Hue(hue) => {
+                hsva.hue = hue;
+                state.draft = None;
+            }
+            Event::Alpha(alpha) => {
+                hsva.alpha = alpha;
+                state.draft = None;
+            }
+            Event::Swatch(swatch) => {
+                hsva = Hsva::from_color(swatch);
+                state.draft = None;
+            }
+            Event::Input(input) => {
+                // Invalid text stays in the field, flagged, without changing the color
+                let parsed = color::parse(&input);
+                state.draft = Some(input);
+                hsva = Hsva::from_color(parsed?);
+            }
+        }
+
+        if !self.opacity {
+            hsva.alpha = 1.0;
+        }
+
+        let color = hsva.color();
+        state.last = Some((color, hsva));
+        self.value = color;
+
+        self.on_change.as_ref().map(|f| f(color))
+    }
+
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let disabled = self.disabled;
+        let hsva = state.hsva(self.value);
+        let format = state.format.unwrap_or(self.format);
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+
+        let mut content = Column::new().spacing(sizes.spacing.x_small);
+
+        if let Some(label) = &self.label {
+            content = content.push(
+                text(label.clone())
+                    .size(sizes.font_size.medium)
+                    .font(fonts.sans)
+                    .class(TextStyleClass {
+                        color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
+                    }),
+            );
+        }
+
+        if !self.inline {
+            let trigger = mouse_area(
+                Canvas::new(Swatch::new(Preset::Color(self.value), PREVIEW_SIZE / 2.0))
+                    .width(PREVIEW_SIZE)
+                    .height(PREVIEW_SIZE),
+            )
+            .on_press(Event::Toggled)
+            .interaction(if disabled {
+                mouse::Interaction::NotAllowed
+            } else {
+                mouse::Interaction::Pointer
+            });
+
+            let panel = self.panel(state, hsva, format);
+
+            return content
+                .push(
+                    Popup::new(trigger, panel)
+                        .open(state.open && !disabled)
+                        .side(Side::Bottom)
+                        .align(Align::Start)
+                        .gap(sizes.spacing.x_small)
+                        .on_close(Event::Closed),
+                )
+                .into();
+        }
+
+        content.push(self.panel(state, hsva, format)).into()
+    }
+}
+
+impl<Message> ColorPicker<Message> {
+    /// Builds the grid, sliders, text field and swatches
+    fn panel<'a>(
+        &self,
+        state: &ColorPickerState,
+        hsva: Hsva,
+        format: Format,
+    ) -> Element<'a, Event> {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let disabled = self.disabled;
+
+        let grid = Canvas::new(Grid { hsva, disabled })
+            .width(Length::Fill)
+            .height(GRID_HEIGHT + HANDLE_SIZE);
+
+        let mut sliders = column![
+            Canvas::new(Channel {
+                kind: ChannelKind::Hue,
+                hsva,
+                disabled,
+            })
+            .width(Length::Fill)
+            .height(HANDLE_SIZE)
+        ]
+        .spacing(sizes.spacing.x_small)
+        .width(Length::Fill);
+
+        if self.opacity {
+            sliders = sliders.push(
+                Canvas::new(Channel {
+                    kind: ChannelKind::Alpha,
+                    hsva,
+                    disabled,
+                })
+                .width(Length::Fill)
+                .height(HANDLE_SIZE),
+            );
+        }
+
+        let preview = Canvas::new(Swatch::new(Preset::Color(self.value), PREVIEW_SIZE / 2.0))
+            .width(PREVIEW_SIZE)
+            .height(PREVIEW_SIZE);
+
+        let controls = row![sliders, preview]
+            .spacing(sizes.spacing.small)
+            .align_y(Alignment::Center);
+
+        let value = state
+            .draft
+            .clone()
+            .unwrap_or_else(|| color::format(self.value, format));
+        let invalid = state
+            .draft
+            .as_deref()
+            .is_some_and(|draft| color::parse(draft).is_none());
+
+        let field = text_input("", &value)
+            .size(sizes.font_size.small)
+            .font(fonts.sans)
+            .class(InputStyleClass {
+                size: InputSize::Small,
+                disabled,
+                filled: false,
+                pill: false,
+                base: Part::default(),
+                input: Part::default(),
+            })
+            .padding([sizes.spacing.x_small, sizes.spacing.small])
+            .on_input_maybe((!disabled).then_some(Event::Input))
+            .width(Length::Fill);
+
+        let format_label = match format {
+            Format::Hex => "HEX",
+            Format::Rgb => "RGB",
+            Format::Hsl => "HSL",
+        };
+
+        let toggle = Button::new(format_label)
+            .size(ButtonSize::Small)
+            .sizes(sizes)
+            .fonts(fonts)
+            .disabled(disabled)
+            .on_press(Event::FormatCycled);
+
+        let mut panel = column![
+            grid,
+            controls,
+            row![field, toggle]
+                .spacing(sizes.spacing.x_small)
+                .align_y(Alignment::Center)
+        ]
+        .spacing(sizes.spacing.small);
+
+        if invalid {
+            panel = panel.push(
+                text("Enter a hex, RGB or HSL color")
+                    .size(sizes.font_size.small)
+                    .font(fonts.sans)
+                    .class(TextStyleClass {
+                        color: Some(ColorToken::new(ColorVariant::Danger, ColorValue::C600)),
+                    }),
+            );
+        }
+
+        if !self.swatches.is_empty() {
+            // Short rows are padded so every swatch has the same width
+            let rows = self.swatches.chunks(SWATCHES_PER_ROW).map(|chunk| {
+                let swatches = chunk
+                    .iter()
+                    .map(|&preset| {
+                        Canvas::new(Swatch {
+                            selectable: true,
+                            ..Swatch::new(preset, sizes.border_radius.small)
+                        })
+                        .width(Length::Fill)
+                        .height(20)
+                        .into()
+                    })
+                    .chain(
+                        (chunk.len()..SWATCHES_PER_ROW)
+                            .map(|_| container(column![]).width(Length::Fill).into()),
+                    );
+
+                Row::with_children(swatches)
+                    .spacing(sizes.spacing.x_small)
+                    .into()
+            });
+
+            panel = panel.push(Column::with_children(rows).spacing(sizes.spacing.x_small));
+        }
+
+        container(panel)
+            .width(PANEL_WIDTH)
+            .padding(sizes.spacing.small)
+            .class(ContainerStyleClass::Card)
+            .into()
+    }
+}
+
+impl<'a, Message> From<ColorPicker<Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(picker: ColorPicker<Message>) -> Self {
+        component(picker)
+    }
+}
+
+/// Helper function to create a color picker
+pub fn color_picker<Message>(value: Color) -> ColorPicker<Message>
+where
+    Message: Clone,
+{
+    ColorPicker::new(value)
+}
+
+/// Tracks a left-button drag that started inside a canvas
+#[derive(Default)]
+struct DragState {
+    dragging: bool,
+}
+
+impl DragState {
+    /// Returns the cursor position while dragging, as fractions of `bounds` shrunk by
+    /// `inset` on every side and clamped to it
+    fn drag(
+        &mut self,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        inset: f32,
+    ) -> Option<(f32, f32)> {
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.dragging = cursor.is_over(bounds);
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. }) if self.dragging => {}
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.dragging = false;
+                return None;
+            }
+            _ => return None,
+        }
+
+        let position = cursor.position().filter(|_| self.dragging)?;
+        let fraction = |offset: f32, length: f32| {
+            ((offset - inset) / (length - 2.0 * inset).max(1.0)).clamp(0.0, 1.0)
+        };
+
+        Some((
+            fraction(position.x - bounds.x, bounds.width),
+            fraction(position.y - bounds.y, bounds.height),
+        ))
+    }
+}
+
+/// Canvas program drawing the saturation (x) / value (y) grid for the current hue
+///
+/// The grid is inset by half a handle so the handle is never clipped at the edges.
+struct Grid {
+    hsva: Hsva,
+    disabled: bool,
+}
+
+impl canvas::Program<Event, Theme> for Grid {
+    type State = DragState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Event>> {
+        if self.disabled {
+            return None;
+        }
+
+        let (x, y) = state.drag(event, bounds, cursor, HANDLE_SIZE / 2.0)?;
+
+        Some(
+            canvas::Action::publish(Event::SaturationValue(x * 100.0, (1.0 - y) * 100.0))
+                .and_capture(),
+        )
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let inset = HANDLE_SIZE / 2.0;
+        let origin = Point::new(inset, inset);
+        let size = Size::new(bounds.width - 2.0 * inset, bounds.height - 2.0 * inset);
+        let area = Path::rounded_rectangle(origin, size, theme.sizes().border_radius.medium.into());
+
+        // White to the pure hue across, then transparent to black down
+        frame.fill(
+            &area,
+            Linear::new(origin, Point::new(origin.x + size.width, origin.y))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, color::hsv(self.hsva.hue, 100.0, 100.0)),
+        );
+        frame.fill(
+            &area,
+            Linear::new(origin, Point::new(origin.x, origin.y + size.height))
+                .add_stop(0.0, Color::TRANSPARENT)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        handle(
+            &mut frame,
+            Point::new(
+                origin.x + size.width * self.hsva.saturation / 100.0,
+                origin.y + size.height * (1.0 - self.hsva.value / 100.0),
+            ),
+            Color {
+                a: 1.0,
+                ..self.hsva.color()
+            },
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if !self.disabled && (state.dragging || cursor.is_over(bounds)) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChannelKind {
+    Hue,
+    Alpha,
+}
+
+/// Canvas program drawing a horizontal hue or opacity slider
+struct Channel {
+    kind: ChannelKind,
+    hsva: Hsva,
+    disabled: bool,
+}
+
+impl canvas::Program<Event, Theme> for Channel {
+    type State = DragState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Event>> {
+        if self.disabled {
+            return None;
+        }
+
+        let (x, _) = state.drag(event, bounds, cursor, HANDLE_SIZE / 2.0)?;
+
+        let event = match self.kind {
+            ChannelKind::Hue => Event::Hue(x * 360.0),
+            ChannelKind::Alpha => Event::Alpha(x),
+        };
+
+        Some(canvas::Action::publish(event).and_capture())
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let inset = HANDLE_SIZE / 2.0;
+        let origin = Point::new(inset, (bounds.height - TRACK_HEIGHT) / 2.0);
+        let size = Size::new(bounds.width - 2.0 * inset, TRACK_HEIGHT);
+        let radius = TRACK_HEIGHT / 2.0;
+        let track = Path::rounded_rectangle(origin, size, radius.into());
+        let end = Point::new(origin.x + size.width, origin.y);
+
+        let opaque = Color {
+            a: 1.0,
+            ..self.hsva.color()
+        };
+
+        let (fraction, handle_color) = match self.kind {
+            ChannelKind::Hue => {
+                let gradient = (0..=6).fold(Linear::new(origin, end), |gradient, step| {
+                    gradient.add_stop(step as f32 / 6.0, hsl(step as f32 * 60.0, 100.0, 50.0))
+                });
+                frame.fill(&track, gradient);
+
+                (
+                    self.hsva.hue / 360.0,
+                    color::hsv(self.hsva.hue, 100.0, 100.0),
+                )
+            }
+            ChannelKind::Alpha => {
+                checkerboard(&mut frame, origin, size, radius);
+                frame.fill(
+                    &track,
+                    Linear::new(origin, end)
+                        .add_stop(0.0, Color { a: 0.0, ..opaque })
+                        .add_stop(1.0, opaque),
+                );
+
+                (self.hsva.alpha, self.hsva.color())
+            }
+        };
+
+        handle(
+            &mut frame,
+            Point::new(
+                origin.x + size.width * fraction.clamp(0.0, 1.0),
+                bounds.height / 2.0,
+            ),
+            handle_color,
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if self.disabled {
+            mouse::Interaction::default()
+        } else if state.dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Canvas program drawing a color over a checkerboard, so opacity is visible
+struct Swatch {
+    color: Preset,
+    radius: f32,
+    /// Whether pressing the swatch selects its color
+    selectable: bool,
+}
+
+impl Swatch {
+    fn new(color: Preset, radius: f32) -> Self {
+        Self {
+            color,
+            radius,
+            selectable: false,
+        }
+    }
+}
+
+impl canvas::Program<Event, Theme> for Swatch {
+    /// The color last drawn, which is the one a press selects, since token colors
+    /// are only known once the theme is
+    type State = Cell<Option<Color>>;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Event>> {
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.selectable && cursor.is_over(bounds) =>
+            {
+                let color = state.get()?;
+
+                Some(canvas::Action::publish(Event::Swatch(color)).and_capture())
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let color = match self.color {
+            Preset::Color(color) => color,
+            Preset::Token(token) => token.get_color(theme.tokens()),
+        };
+        state.set(Some(color));
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let radius = self.radius.min(bounds.width.min(bounds.height) / 2.0);
+        let area = Path::rounded_rectangle(Point::ORIGIN, bounds.size(), radius.into());
+
+        if color.a < 1.0 {
+            checkerboard(&mut frame, Point::ORIGIN, bounds.size(), radius);
+        }
+
+        frame.fill(&area, color);
+
+        // Shoelace outlines swatches with a faint inset border
+        frame.stroke(
+            &Path::rounded_rectangle(
+                Point::new(0.5, 0.5),
+                Size::new(bounds.width - 1.0, bounds.height - 1.0),
+                (radius - 0.5).max(0.0).into(),
+            ),
+            Stroke::default()
+                .with_color(Color::from_rgba(0.0, 0.0, 0.0, 0.2))
+                .with_width(1.0),
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if self.selectable && cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Draws a handle: the color ringed in white, with a faint shadow
+fn handle(frame: &mut Frame, center: Point, color: Color) {
+    let radius = HANDLE_SIZE / 2.0;
+
+    frame.fill(
+        &Path::circle(center, radius),
+        Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+    );
+    frame.fill(&Path::circle(center, radius - 1.0), Color::WHITE);
+    frame.fill(&Path::circle(center, radius - 3.0), color);
+}
+
+/// Fills a rounded rectangle with a light and dark checkerboard
+///
+/// Dark squares crossing the rounded corners are skipped, so nothing is drawn
+/// outside the rectangle.
+fn checkerboard(frame: &mut Frame, origin: Point, size: Size, radius: f32) {
+    const SQUARE: f32 = 4.0;
+
+    frame.fill(
+        &Path::rounded_rectangle(origin, size, radius.into()),
+        Color::WHITE,
+    );
+
+    let inside = |x: f32, y: f32| {
+        let nearest_x = x.clamp(radius, size.width - radius);
+        let nearest_y = y.clamp(radius, size.height - radius);
+
+        (x - nearest_x).powi(2) + (y - nearest_y).powi(2) <= radius * radius
+    };
+
+    let dark = hsl(0.0, 0.0, 80.0);
+    let columns = (size.width / SQUARE).floor() as usize;
+    let rows = (size.height / SQUARE).floor() as usize;
+
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let (x, y) = (column as f32 * SQUARE, row as f32 * SQUARE);
+
+            if inside(x, y)
+                && inside(x + SQUARE, y)
+                && inside(x, y + SQUARE)
+                && inside(x + SQUARE, y + SQUARE)
+            {
+                frame.fill_rectangle(
+                    Point::new(origin.x + x, origin.y + y),
+                    Size::new(SQUARE, SQUARE),
+                    dark,
+                );
+            }
+        }
+    }
+}
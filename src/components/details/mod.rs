@@ -0,0 +1,298 @@
+use std::rc::Rc;
+
+use crate::{
+    Element,
+    theme::{
+        container::ContainerStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+    widgets::collapse::Collapse,
+};
+use iced::{
+    Alignment, Length, Padding, Shadow,
+    widget::{column, container, mouse_area, row, text},
+};
+
+/// A Shoelace-style details component for iced
+///
+/// This component implements the features from Shoelace's details component:
+/// - Summary row with an expand/collapse chevron
+/// - Body that animates its height when opened or closed
+/// - Controlled open state with a toggle callback
+/// - Disabled state
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Details;
+///
+/// let details = Details::new("Toggle me", text("Lorem ipsum dolor sit amet"))
+///     .open(state.open)
+///     .on_toggle(Message::DetailsToggled);
+/// ```
+pub struct Details<'a, Message> {
+    summary: Element<'a, Message>,
+    content: Element<'a, Message>,
+    open: bool,
+    disabled: bool,
+    width: Length,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<'a, Message> Details<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    /// Creates a new, closed details with the given summary and content
+    pub fn new(
+        summary: impl Into<Element<'a, Message>>,
+        content: impl Into<Element<'a, Message>>,
+    ) -> Self {
+        Self {
+            summary: summary.into(),
+            content: content.into(),
+            open: false,
+            disabled: false,
+            width: Length::Fill,
+            on_toggle: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets whether the details are open
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Sets whether the details are disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the width of the details
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the callback invoked with the requested open state when the summary is pressed
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+impl<'a, Message> From<Details<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(details: Details<'a, Message>) -> Self {
+        let sizes = details.sizes;
+        let fonts = details.fonts;
+        let chevron_color = if details.disabled {
+            ColorToken::new(ColorVariant::Neutral, ColorValue::C400)
+        } else {
+            ColorToken::new(ColorVariant::Neutral, ColorValue::C600)
+        };
+
+        let chevron = text(if details.open { "▾" } else { "▸" })
+            .size(sizes.font_size.large)
+            .line_height(sizes.line_height.dense)
+            .font(fonts.sans)
+            .class(TextStyleClass {
+                color: Some(chevron_color),
+            });
+
+        // Shoelace pads the summary with --sl-spacing-medium on all sides
+        let header = container(
+            row![container(details.summary).width(Length::Fill), chevron]
+                .spacing(sizes.spacing.small)
+                .align_y(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .padding(sizes.spacing.medium)
+        .class(ContainerStyleClass::Custom {
+            background: None,
+            text_color: details
+                .disabled
+                .then(|| ColorToken::new(ColorVariant::Neutral, ColorValue::C400)),
+            border_color: None,
+            border_width: 0.0,
+            border_radius: 0.0,
+            shadow: Shadow::default(),
+            snap: true,
+        });
+
+        let header: Element<'a, Message> = match (&details.on_toggle, details.disabled) {
+            (Some(on_toggle), false) => mouse_area(header)
+                .on_press(on_toggle(!details.open))
+                .interaction(iced::mouse::Interaction::Pointer)
+                .into(),
+            _ => header.into(),
+        };
+
+        // The body sits flush under the summary, so it has no top padding
+        let body = container(details.content)
+            .width(Length::Fill)
+            .padding(Padding {
+                top: 0.0,
+                right: sizes.spacing.medium,
+                bottom: sizes.spacing.medium,
+                left: sizes.spacing.medium,
+            });
+
+        container(column![header, Collapse::new(body, details.open)])
+            .width(details.width)
+            .class(ContainerStyleClass::Card)
+            .into()
+    }
+}
+
+/// A group of [`Details`] where at most one item is open at a time
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Accordion, Details};
+///
+/// let faq = Accordion::new(state.open_question)
+///     .push(Details::new("What is it?", text("A component library")))
+///     .push(Details::new("Is it free?", text("Yes")))
+///     .on_change(Message::QuestionToggled);
+/// ```
+pub struct Accordion<'a, Message> {
+    items: Vec<Details<'a, Message>>,
+    open: Option<usize>,
+    spacing: Option<f32>,
+    width: Length,
+    on_change: Option<Rc<dyn Fn(Option<usize>) -> Message + 'a>>,
+    sizes: Sizes,
+}
+
+impl<'a, Message> Accordion<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    /// Creates a new accordion with the item at `open` expanded
+    pub fn new(open: Option<usize>) -> Self {
+        Self {
+            items: Vec::new(),
+            open,
+            spacing: None,
+            width: Length::Fill,
+            on_change: None,
+            sizes: SIZES,
+        }
+    }
+
+    /// Adds an item; its own open state and toggle callback are replaced by the accordion's
+    pub fn push(mut self, details: Details<'a, Message>) -> Self {
+        self.items.push(details);
+        self
+    }
+
+    /// Sets the spacing between items
+    /// Default: the x-small spacing
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Sets the width of the accordion
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the callback invoked with the index of the newly opened item, or `None` when it closes
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Option<usize>) -> Message,
+    {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+}
+
+impl<'a, Message> From<Accordion<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(accordion: Accordion<'a, Message>) -> Self {
+        let open = accordion.open;
+        let on_change = accordion.on_change;
+
+        let items = accordion
+            .items
+            .into_iter()
+            .enumerate()
+            .map(|(index, details)| {
+                let mut details = details.open(open == Some(index));
+
+                details.on_toggle = on_change.clone().map(|on_change| {
+                    Box::new(move |opened: bool| on_change(opened.then_some(index)))
+                        as Box<dyn Fn(bool) -> Message + 'a>
+                });
+
+                Element::from(details)
+            });
+
+        column(items)
+            .spacing(accordion.spacing.unwrap_or(accordion.sizes.spacing.x_small))
+            .width(accordion.width)
+            .into()
+    }
+}
+
+/// Helper function to create details
+pub fn details<'a, Message>(
+    summary: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+) -> Details<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    Details::new(summary, content)
+}
+
+/// Helper function to create an accordion
+pub fn accordion<'a, Message>(open: Option<usize>) -> Accordion<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    Accordion::new(open)
+}
@@ -1,6 +1,10 @@
 use crate::{
     Element,
-    theme::{container::ContainerStyleClass, rule::RuleStyleClass, sizes::SPACING},
+    theme::{
+        container::ContainerStyleClass,
+        rule::RuleStyleClass,
+        sizes::{SIZES, Sizes},
+    },
 };
 use iced::{
     Color, Length, Padding, Shadow,
@@ -38,7 +42,8 @@ pub struct Divider {
     vertical: bool,
     width: f32,
     color: Option<Color>,
-    spacing: f32,
+    spacing: Option<f32>,
+    sizes: Sizes,
 }
 
 impl Divider {
@@ -48,13 +53,14 @@ impl Divider {
     /// - Horizontal orientation
     /// - Width: 1px (matching Shoelace's default)
     /// - Color: neutral-300 (from theme)
-    /// - Spacing: 1rem (matching Shoelace's default)
+    /// - Spacing: the x-small spacing
     pub fn new() -> Self {
         Self {
             vertical: false,
             width: 1.0,
             color: None,
-            spacing: SPACING.x_small, // 1rem / 16px
+            spacing: None,
+            sizes: SIZES,
         }
     }
 
@@ -88,9 +94,16 @@ impl Divider {
     /// Sets the spacing around the divider
     ///
     /// In Shoelace, this corresponds to the `--spacing` CSS custom property.
-    /// Default is 1rem (16px).
+    /// Default is the x-small spacing.
     pub fn spacing(mut self, spacing: f32) -> Self {
-        self.spacing = spacing;
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
         self
     }
 }
@@ -118,6 +131,8 @@ pub fn divider() -> Divider {
 
 impl<'a, Message: 'a> From<Divider> for Element<'a, Message> {
     fn from(divider: Divider) -> Self {
+        let spacing = divider.spacing.unwrap_or(divider.sizes.spacing.x_small);
+
         // Use iced's built-in Rule widget for the divider line
         // Note: for vertical rules, the parameter is width; for horizontal rules, it's height
         let rule_widget = if divider.vertical {
@@ -136,9 +151,9 @@ impl<'a, Message: 'a> From<Divider> for Element<'a, Message> {
 
         // Wrap in a container to add spacing with transparent background (matching Shoelace)
         let padding = if divider.vertical {
-            Padding::from([0.0, divider.spacing])
+            Padding::from([0.0, spacing])
         } else {
-            Padding::from([divider.spacing, 0.0])
+            Padding::from([spacing, 0.0])
         };
 
         let transparent_style = ContainerStyleClass::Custom {
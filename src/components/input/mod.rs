@@ -2,14 +2,16 @@ use crate::{
     Element,
     theme::{
         Theme,
+        container::ContainerStyleClass,
         input::InputStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{FONT_SIZE, INPUT_HEIGHT, LINE_HEIGHT, SPACING},
-        text::TextStyleClass,
+        parts::{Part, PartStyle, Parts, Status},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
     },
 };
 use iced::Length;
-use iced::widget::{Component, component, text, text_input};
+use iced::widget::{Component, component, container, text, text_input};
 use iced_widget::Column;
 
 /// Size variants for input
@@ -23,27 +25,27 @@ pub enum InputSize {
 
 impl InputSize {
     #[allow(dead_code)]
-    fn height(&self) -> f32 {
+    fn height(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => INPUT_HEIGHT.small,
-            Self::Medium => INPUT_HEIGHT.medium,
-            Self::Large => INPUT_HEIGHT.large,
+            Self::Small => sizes.input_height.small,
+            Self::Medium => sizes.input_height.medium,
+            Self::Large => sizes.input_height.large,
         }
     }
 
-    fn font_size(&self) -> f32 {
+    fn font_size(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => FONT_SIZE.small,
-            Self::Medium => FONT_SIZE.medium,
-            Self::Large => FONT_SIZE.large,
+            Self::Small => sizes.font_size.small,
+            Self::Medium => sizes.font_size.medium,
+            Self::Large => sizes.font_size.large,
         }
     }
 
-    fn spacing(&self) -> f32 {
+    fn spacing(&self, sizes: Sizes) -> f32 {
         match self {
-            Self::Small => SPACING.x2_small,
-            Self::Medium => SPACING.x_small,
-            Self::Large => SPACING.small,
+            Self::Small => sizes.spacing.x2_small,
+            Self::Medium => sizes.spacing.x_small,
+            Self::Large => sizes.spacing.small,
         }
     }
 }
@@ -74,6 +76,7 @@ pub enum InputType {
 /// - Label support
 /// - Placeholder text
 /// - Required field marking
+/// - Style overrides of its parts, see [`InputPart`]
 pub struct Input<Message> {
     label: Option<String>,
     value: String,
@@ -87,6 +90,22 @@ pub struct Input<Message> {
     help_text: Option<String>,
     required: bool,
     on_input: Option<Box<dyn Fn(String) -> Message>>,
+    parts: Parts<InputPart>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+/// The parts of an input that can be restyled, matching Shoelace's CSS parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputPart {
+    /// The label above the input
+    Label,
+    /// The field: background, border and value color
+    Base,
+    /// The entered value; only the text color applies
+    Input,
+    /// The help text below the input
+    HelpText,
 }
 
 impl<Message> Input<Message> {
@@ -105,6 +124,9 @@ impl<Message> Input<Message> {
             help_text: None,
             required: false,
             on_input: None,
+            parts: Parts::new(),
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -176,6 +198,28 @@ impl<Message> Input<Message> {
         self.required = required;
         self
     }
+
+    /// Overrides the style of a part, merged over the theme's style
+    ///
+    /// Only the base and input see the hovered and focused states.
+    pub fn part(mut self, part: InputPart, style: impl Into<Part>) -> Self {
+        self.parts.set(part, style.into());
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -204,9 +248,11 @@ where
     }
 
     fn view(&self, _state: &Self::State) -> Element<'a, Self::Event> {
-        let font_size = self.size.font_size();
-        let spacing = self.size.spacing();
-        let line_height = LINE_HEIGHT.dense;
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let font_size = self.size.font_size(sizes);
+        let spacing = self.size.spacing(sizes);
+        let line_height = sizes.line_height.dense;
 
         let value = self.value.clone();
         let placeholder = self.placeholder.clone();
@@ -225,7 +271,10 @@ where
             disabled,
             filled,
             pill,
+            base: self.parts.get(InputPart::Base),
+            input: self.parts.get(InputPart::Input),
         };
+        let status = Status::of(disabled);
 
         // Build the text input control
         let is_password = matches!(input_type, InputType::Password);
@@ -233,12 +282,13 @@ where
         let text_input_control = text_input(&placeholder, &value)
             .size(font_size)
             .line_height(line_height)
+            .font(fonts.sans)
             .class(style_class)
             .secure(is_password)
             .padding(if pill {
-                [SPACING.x_small, SPACING.medium]
+                [sizes.spacing.x_small, sizes.spacing.medium]
             } else {
-                [SPACING.x_small, SPACING.small]
+                [sizes.spacing.x_small, sizes.spacing.small]
             })
             .on_input_maybe(if !disabled && !readonly {
                 Some(Event::InputChanged)
@@ -257,10 +307,13 @@ where
             } else {
                 label
             };
-            let label_style = text(label_str).size(font_size).class(TextStyleClass {
-                color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
-            });
-            content = content.push(label_style);
+            let label_style = PartStyle::new()
+                .text_color(ColorToken::new(ColorVariant::Neutral, ColorValue::C700))
+                .merge(self.parts.get(InputPart::Label).get(status));
+            content = content.push(
+                container(text(label_str).size(font_size).font(fonts.sans))
+                    .class(ContainerStyleClass::Part(label_style)),
+            );
         }
 
         // Add the input control
@@ -268,10 +321,13 @@ where
 
         // Add help text if present
         if let Some(help) = help_text {
-            let help_style = text(help).size(FONT_SIZE.small).class(TextStyleClass {
-                color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C500)),
-            });
-            content = content.push(help_style);
+            let help_style = PartStyle::new()
+                .text_color(ColorToken::new(ColorVariant::Neutral, ColorValue::C500))
+                .merge(self.parts.get(InputPart::HelpText).get(status));
+            content = content.push(
+                container(text(help).size(sizes.font_size.small).font(fonts.sans))
+                    .class(ContainerStyleClass::Part(help_style)),
+            );
         }
 
         content.into()
@@ -0,0 +1,322 @@
+//! Simulated interactions with the components that handle their own events
+//!
+//! Each test lays out a component with [`iced_test`]'s simulator, drives it with
+//! clicks, hovers and key presses, and checks the messages it emits.
+use iced::{
+    Event, Point,
+    keyboard::key::Named,
+    mouse,
+    widget::{button, text},
+};
+use iced_test::{Simulator, simulator};
+
+use crate::{
+    Element,
+    components::{Checkbox, Input, MenuItem, MenuItemType, button::Button, hovered},
+    theme::{Theme, parts::Status},
+    widgets::{
+        popup::Popup,
+        range::{Range, Value},
+        status_area::StatusArea,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Pressed,
+    Input(String),
+    Toggled(bool),
+    Selected,
+    Closed,
+    Range(Value),
+    Status(Status),
+}
+
+fn simulate<'a>(element: impl Into<Element<'a, Message>>) -> Simulator<'a, Message, Theme> {
+    simulator(element.into())
+}
+
+fn move_to(simulator: &mut Simulator<'_, Message, Theme>, x: f32, y: f32) {
+    let position = Point::new(x, y);
+
+    simulator.point_at(position);
+    simulator.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+}
+
+/// Clicks at a position, for widgets without text to find
+fn click_at(simulator: &mut Simulator<'_, Message, Theme>, x: f32, y: f32) {
+    move_to(simulator, x, y);
+    simulator.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]);
+}
+
+fn messages(simulator: Simulator<'_, Message, Theme>) -> Vec<Message> {
+    simulator.into_messages().collect()
+}
+
+#[test]
+fn button_emits_on_press() {
+    let mut simulator = simulate(Button::new("Save").on_press(Message::Pressed));
+
+    simulator.click("Save").expect("the label is drawn");
+
+    assert_eq!(messages(simulator), [Message::Pressed]);
+}
+
+#[test]
+fn disabled_button_ignores_clicks() {
+    let mut simulator = simulate(
+        Button::new("Save")
+            .disabled(true)
+            .on_press(Message::Pressed),
+    );
+
+    simulator.click("Save").expect("the label is drawn");
+
+    assert!(messages(simulator).is_empty());
+}
+
+#[test]
+fn loading_button_ignores_clicks() {
+    // The label is hidden behind the spinner, so click the button itself
+    let mut simulator = simulate(Button::new("Save").loading(true).on_press(Message::Pressed));
+
+    click_at(&mut simulator, 8.0, 8.0);
+
+    assert!(messages(simulator).is_empty());
+}
+
+#[test]
+fn button_without_on_press_emits_nothing() {
+    let mut simulator = simulate(Button::<Message>::new("Save"));
+
+    simulator.click("Save").expect("the label is drawn");
+
+    assert!(messages(simulator).is_empty());
+}
+
+#[test]
+fn input_emits_every_edit() {
+    let mut simulator = simulate(Input::new("Name").on_input(Message::Input));
+
+    click_at(&mut simulator, 10.0, 10.0);
+    simulator.typewrite("abc");
+    simulator.tap_key(Named::Backspace);
+
+    assert_eq!(
+        messages(simulator),
+        [
+            Message::Input("a".into()),
+            Message::Input("ab".into()),
+            Message::Input("abc".into()),
+            Message::Input("ab".into()),
+        ]
+    );
+}
+
+#[test]
+fn disabled_and_readonly_inputs_ignore_typing() {
+    for input in [
+        Input::new("Name").disabled(true),
+        Input::new("Name").readonly(true),
+    ] {
+        let mut simulator = simulate(input.on_input(Message::Input));
+
+        click_at(&mut simulator, 10.0, 10.0);
+        simulator.typewrite("abc");
+
+        assert!(messages(simulator).is_empty());
+    }
+}
+
+#[test]
+fn checkbox_toggles() {
+    let cases = [
+        (Checkbox::new("Accept", false), [Message::Toggled(true)]),
+        (Checkbox::new("Accept", true), [Message::Toggled(false)]),
+        // Shoelace resolves an indeterminate checkbox to checked
+        (
+            Checkbox::new("Accept", false).indeterminate(true),
+            [Message::Toggled(true)],
+        ),
+    ];
+
+    for (checkbox, expected) in cases {
+        let mut simulator = simulate(checkbox.on_toggle(Message::Toggled));
+
+        click_at(&mut simulator, 6.0, 6.0);
+
+        assert_eq!(messages(simulator), expected);
+    }
+}
+
+#[test]
+fn disabled_checkbox_ignores_clicks() {
+    let mut simulator = simulate(
+        Checkbox::new("Accept", false)
+            .disabled(true)
+            .on_toggle(Message::Toggled),
+    );
+
+    click_at(&mut simulator, 6.0, 6.0);
+
+    assert!(messages(simulator).is_empty());
+}
+
+#[test]
+fn menu_item_emits_on_select() {
+    let mut simulator = simulate(MenuItem::new("Open").on_select(Message::Selected));
+
+    simulator.click("Open").expect("the label is drawn");
+
+    assert_eq!(messages(simulator), [Message::Selected]);
+}
+
+#[test]
+fn checkbox_menu_item_selects_on_every_click() {
+    let mut simulator = simulate(
+        MenuItem::new("Autosave")
+            .item_type(MenuItemType::Checkbox)
+            .on_select(Message::Selected),
+    );
+
+    simulator.click("Autosave").expect("the label is drawn");
+    simulator.click("Autosave").expect("the label is drawn");
+
+    assert_eq!(messages(simulator), [Message::Selected, Message::Selected]);
+}
+
+#[test]
+fn disabled_and_loading_menu_items_ignore_clicks() {
+    for item in [
+        MenuItem::new("Open").disabled(true),
+        MenuItem::new("Open").loading(true),
+    ] {
+        let mut simulator = simulate(item.on_select(Message::Selected));
+
+        simulator.click("Open").expect("the label is drawn");
+
+        assert!(messages(simulator).is_empty());
+    }
+}
+
+#[test]
+fn hovered_follows_the_cursor() {
+    let mut simulator = simulate(hovered(|is_hovered| {
+        text(if is_hovered { "Hovered" } else { "Idle" })
+    }));
+
+    assert!(simulator.find("Idle").is_ok());
+
+    move_to(&mut simulator, 5.0, 5.0);
+    assert!(simulator.find("Hovered").is_ok());
+
+    move_to(&mut simulator, 500.0, 500.0);
+    assert!(simulator.find("Idle").is_ok());
+}
+
+#[test]
+fn hovered_forwards_messages() {
+    let mut simulator = simulate(hovered(|_| button(text("Go")).on_press(Message::Pressed)));
+
+    simulator.click("Go").expect("the label is drawn");
+
+    assert_eq!(messages(simulator), [Message::Pressed]);
+}
+
+#[test]
+fn status_area_sees_presses_captured_by_its_content() {
+    let mut simulator = simulate(
+        StatusArea::new(button(text("Go")).on_press(Message::Pressed)).on_change(Message::Status),
+    );
+
+    move_to(&mut simulator, 5.0, 5.0);
+    simulator.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]);
+
+    assert_eq!(
+        messages(simulator),
+        [
+            Message::Status(Status::Hovered),
+            Message::Status(Status::Pressed),
+            Message::Pressed,
+            Message::Status(Status::Hovered),
+        ]
+    );
+}
+
+/// An open popup below a "Anchor" button, holding an "Inside" button
+fn open_popup<'a>() -> Popup<'a, Message> {
+    Popup::new(
+        button(text("Anchor")).on_press(Message::Selected),
+        button(text("Inside")).on_press(Message::Pressed),
+    )
+    .open(true)
+    .on_close(Message::Closed)
+}
+
+#[test]
+fn closed_popup_hides_its_content() {
+    let mut simulator = simulate(Popup::new(text("Anchor"), text("Inside")));
+
+    assert!(simulator.find("Anchor").is_ok());
+    assert!(simulator.find("Inside").is_err());
+}
+
+#[test]
+fn popup_forwards_clicks_to_its_content() {
+    let mut simulator = simulate(open_popup());
+
+    simulator.click("Inside").expect("the popup is open");
+
+    assert_eq!(messages(simulator), [Message::Pressed]);
+}
+
+#[test]
+fn clicking_the_anchor_does_not_close_the_popup() {
+    let mut simulator = simulate(open_popup());
+
+    simulator.click("Anchor").expect("the label is drawn");
+
+    assert_eq!(messages(simulator), [Message::Selected]);
+}
+
+#[test]
+fn popup_closes_on_outside_click() {
+    let mut simulator = simulate(open_popup());
+
+    click_at(&mut simulator, 500.0, 500.0);
+
+    assert_eq!(messages(simulator), [Message::Closed]);
+}
+
+#[test]
+fn popup_closes_on_escape() {
+    let mut simulator = simulate(open_popup());
+
+    simulator.tap_key(Named::Escape);
+
+    assert_eq!(messages(simulator), [Message::Closed]);
+}
+
+#[test]
+fn stacked_range_thumbs_split_towards_the_drag() {
+    // A 100px track starting at x = 7, one pixel per unit
+    let mut simulator =
+        simulate(Range::new(0.0..=100.0, Value::Interval(0.0, 0.0), Message::Range).width(114));
+
+    move_to(&mut simulator, 7.0, 7.0);
+    simulator.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+    move_to(&mut simulator, 57.0, 7.0);
+
+    assert_eq!(
+        messages(simulator).last(),
+        Some(&Message::Range(Value::Interval(0.0, 50.0)))
+    );
+}
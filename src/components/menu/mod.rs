@@ -3,7 +3,8 @@ use crate::{
     theme::{
         container::ContainerStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{BORDER_RADIUS, SPACING},
+        parts::{Part, PartStyle, Parts, Status},
+        sizes::{SIZES, Sizes},
     },
 };
 use iced::{
@@ -18,6 +19,7 @@ use iced::{
 /// - Proper styling with border, shadow, and padding
 /// - Configurable width
 /// - Supports dividers for grouping
+/// - Style overrides of its parts, see [`MenuPart`]
 ///
 /// ## Example
 ///
@@ -43,8 +45,17 @@ pub struct Menu<'a, Message> {
     children: Vec<Element<'a, Message>>,
     width: Length,
     height: Length,
-    padding: f32,
-    spacing: f32,
+    padding: Option<f32>,
+    spacing: Option<f32>,
+    parts: Parts<MenuPart>,
+    sizes: Sizes,
+}
+
+/// The parts of a menu that can be restyled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuPart {
+    /// The panel around the items: background, border, shadow and text color
+    Base,
 }
 
 impl<'a, Message> Menu<'a, Message>
@@ -64,8 +75,10 @@ where
             children: Vec::new(),
             width: Length::Shrink,
             height: Length::Shrink,
-            padding: SPACING.x_small,
-            spacing: SPACING.x3_small,
+            padding: None,
+            spacing: None,
+            parts: Parts::new(),
+            sizes: SIZES,
         }
     }
 
@@ -96,17 +109,30 @@ where
 
     /// Sets the padding around the menu content
     ///
-    /// Default is SPACING.x_small (4px).
+    /// Default is the x-small spacing.
     pub fn padding(mut self, padding: f32) -> Self {
-        self.padding = padding;
+        self.padding = Some(padding);
         self
     }
 
     /// Sets the spacing between menu items
     ///
-    /// Default is SPACING.x3_small (1px).
+    /// Default is the 3x-small spacing.
     pub fn spacing(mut self, spacing: f32) -> Self {
-        self.spacing = spacing;
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Overrides the style of a part, merged over the theme's style
+    pub fn part(mut self, part: MenuPart, style: impl Into<Part>) -> Self {
+        self.parts.set(part, style.into());
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
         self
     }
 }
@@ -125,9 +151,11 @@ where
     Message: Clone,
 {
     fn from(menu: Menu<'a, Message>) -> Self {
+        let sizes = menu.sizes;
+
         // Create a column with all the menu children
         let mut menu_column = column([])
-            .spacing(menu.spacing)
+            .spacing(menu.spacing.unwrap_or(sizes.spacing.x3_small))
             .width(Length::Fill)
             .height(Length::Shrink);
 
@@ -138,26 +166,25 @@ where
 
         // Create custom menu styling using ColorToken for theme-aware colors
         // Following Shoelace design: neutral-0 background, neutral-700 text, neutral-200 border
-        let menu_style = ContainerStyleClass::Custom {
-            background: Some(ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50)),
-            text_color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
-            border_color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C200)),
-            border_width: 1.0,
-            border_radius: BORDER_RADIUS.medium,
-            shadow: Shadow {
+        let menu_style = PartStyle::new()
+            .background(ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50))
+            .text_color(ColorToken::new(ColorVariant::Neutral, ColorValue::C700))
+            .border_color(ColorToken::new(ColorVariant::Neutral, ColorValue::C200))
+            .border_width(1.0)
+            .border_radius(sizes.border_radius.medium)
+            .shadow(Shadow {
                 color: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
                 offset: iced::Vector::new(0.0, 1.0),
                 blur_radius: 3.0,
-            },
-            snap: false,
-        };
+            })
+            .merge(menu.parts.get(MenuPart::Base).get(Status::Active));
 
         // Wrap in a container with menu styling
         container(menu_column)
-            .padding(menu.padding)
+            .padding(menu.padding.unwrap_or(sizes.spacing.x_small))
             .width(menu.width)
             .height(menu.height)
-            .class(menu_style)
+            .class(ContainerStyleClass::Part(menu_style))
             .into()
     }
 }
@@ -1,16 +1,19 @@
 use crate::{
     Element,
+    components::spinner::Spinner,
     theme::{
         Theme,
         container::ContainerStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{BORDER_RADIUS, FONT_SIZE, LINE_HEIGHT, SPACING},
+        sizes::{SIZES, Sizes},
         text::TextStyleClass,
+        typography::{FONTS, Fonts},
     },
+    widgets::hidden::Hidden,
 };
 use iced::{
     Alignment, Padding, Shadow,
-    widget::{Component, component, container, text},
+    widget::{Component, component, container, stack, text},
 };
 use iced_core::Length;
 use iced_widget::Row;
@@ -65,6 +68,8 @@ pub struct MenuItem<Message> {
     prefix: Option<String>,
     suffix: Option<String>,
     on_select: Option<Message>,
+    sizes: Sizes,
+    fonts: Fonts,
 }
 
 impl<Message> MenuItem<Message> {
@@ -86,6 +91,8 @@ impl<Message> MenuItem<Message> {
             prefix: None,
             suffix: None,
             on_select: None,
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -139,11 +146,25 @@ impl<Message> MenuItem<Message> {
         self
     }
 
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
     /// Gets the appropriate padding for the menu item
     /// Following Shoelace spec: var(--sl-spacing-2x-small) var(--sl-spacing-small)
     fn get_padding(&self) -> Padding {
         // Menu items in Shoelace use: 0.125rem 0.75rem (approximately 2px 12px)
-        Padding::from([SPACING.x2_small, SPACING.small])
+        Padding::from([self.sizes.spacing.x2_small, self.sizes.spacing.small])
     }
 }
 
@@ -181,23 +202,18 @@ where
     }
 
     fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
         let padding = self.get_padding();
-        let font_size = FONT_SIZE.medium;
-        let line_height = LINE_HEIGHT.dense;
-
-        // Build label text
-        let label_str = if self.loading {
-            "⋯".to_string()
-        } else {
-            self.label.clone()
-        };
+        let font_size = sizes.font_size.medium;
+        let line_height = sizes.line_height.dense;
 
         let is_hovered = state.is_hovered;
         let disabled = self.disabled;
 
         // Build the menu item content with optional prefix, checkmark, label, and suffix
         let mut row_content = Row::new()
-            .spacing(SPACING.small)
+            .spacing(sizes.spacing.small)
             .align_y(Alignment::Center);
 
         // Add checkmark for checkbox items when checked
@@ -207,6 +223,7 @@ where
                 text(check_text)
                     .size(font_size)
                     .line_height(line_height)
+                    .font(fonts.sans)
                     .class(TextStyleClass {
                         color: Some(ColorToken::new(ColorVariant::Blue, ColorValue::C600)),
                     }),
@@ -221,6 +238,7 @@ where
                 text(prefix_text.clone())
                     .size(font_size)
                     .line_height(line_height)
+                    .font(fonts.sans)
                     .class(TextStyleClass {
                         color: if disabled {
                             Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C400))
@@ -232,18 +250,27 @@ where
         }
 
         // Add main label
-        row_content = row_content.push(
-            text(label_str)
-                .size(font_size)
-                .line_height(line_height)
-                .class(TextStyleClass {
-                    color: if disabled {
-                        Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C400))
-                    } else {
-                        Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700))
-                    },
-                }),
-        );
+        let label = text(self.label.clone())
+            .size(font_size)
+            .line_height(line_height)
+            .font(fonts.sans)
+            .class(TextStyleClass {
+                color: if disabled {
+                    Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C400))
+                } else {
+                    Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700))
+                },
+            });
+
+        // While loading, keep the label's width and center a spinner over it
+        if self.loading {
+            row_content = row_content.push(stack![
+                Hidden::new(label),
+                container(Spinner::new().size(font_size).track_color(None)).center(Length::Fill),
+            ]);
+        } else {
+            row_content = row_content.push(label);
+        }
 
         // Add suffix if present
         if let Some(suffix_text) = &self.suffix
@@ -253,6 +280,7 @@ where
                 text(suffix_text.clone())
                     .size(font_size)
                     .line_height(line_height)
+                    .font(fonts.sans)
                     .class(TextStyleClass {
                         color: if disabled {
                             Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C400))
@@ -282,7 +310,7 @@ where
             text_color: None,
             border_color: None,
             border_width: 0.0,
-            border_radius: BORDER_RADIUS.small,
+            border_radius: sizes.border_radius.small,
             shadow: Shadow::default(),
             snap: false,
         };
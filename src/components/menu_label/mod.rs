@@ -3,8 +3,9 @@ use crate::{
     theme::{
         container::ContainerStyleClass,
         pallete::{ColorToken, ColorValue, ColorVariant},
-        sizes::{FONT_SIZE, LINE_HEIGHT, SPACING},
+        sizes::{SIZES, Sizes},
         text::TextStyleClass,
+        typography::{FONTS, Fonts},
     },
 };
 use iced::{
@@ -36,6 +37,8 @@ use iced_core::Length;
 pub struct MenuLabel {
     content: String,
     uppercase: bool,
+    sizes: Sizes,
+    fonts: Fonts,
 }
 
 impl MenuLabel {
@@ -50,6 +53,8 @@ impl MenuLabel {
         Self {
             content: content.into(),
             uppercase: false,
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -62,12 +67,26 @@ impl MenuLabel {
         self
     }
 
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
     /// Gets the appropriate padding for the menu label
     /// Following Shoelace spec: minimal vertical, small horizontal padding
     fn get_padding(&self) -> Padding {
         // Menu labels in Shoelace use: var(--sl-spacing-2x-small) var(--sl-spacing-small)
         // Which is approximately 0.125rem 0.5rem (2px 8px)
-        Padding::from([SPACING.x2_small, SPACING.small])
+        Padding::from([self.sizes.spacing.x2_small, self.sizes.spacing.small])
     }
 }
 
@@ -94,6 +113,7 @@ pub fn menu_label(content: impl Into<String>) -> MenuLabel {
 
 impl<'a, Message: 'a> From<MenuLabel> for Element<'a, Message> {
     fn from(label: MenuLabel) -> Self {
+        let sizes = label.sizes;
         let padding = label.get_padding();
 
         // Transform content to uppercase if requested
@@ -105,12 +125,12 @@ impl<'a, Message: 'a> From<MenuLabel> for Element<'a, Message> {
 
         // Menu label text styling - x-small size (following Shoelace spec), semibold weight
         let label_text = text(content)
-            .size(FONT_SIZE.x_small)
+            .size(sizes.font_size.x_small)
             .font(Font {
                 weight: iced::font::Weight::Semibold,
-                ..Default::default()
+                ..label.fonts.sans
             })
-            .line_height(LINE_HEIGHT.dense)
+            .line_height(sizes.line_height.dense)
             // Use neutral-600 for muted appearance
             .class(TextStyleClass {
                 color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C600)),
@@ -1,38 +1,79 @@
 use crate::theme::Theme;
 
+pub mod alert;
+pub mod avatar;
 pub mod badge;
 pub mod breadcrumb;
 pub mod button;
 pub mod button_group;
 pub mod card;
 pub mod checkbox;
+pub mod color_picker;
+pub mod details;
 pub mod divider;
 pub mod dropdown;
 pub mod hovered;
 pub mod input;
+#[cfg(test)]
+mod interactions;
 pub mod menu;
 pub mod menu_item;
 pub mod menu_label;
 pub mod popup;
+pub mod progress_bar;
+pub mod progress_ring;
+pub mod range;
+pub mod rating;
 pub mod scrollable;
+pub mod skeleton;
+#[cfg(test)]
+mod snapshots;
+pub mod spinner;
+pub mod split_panel;
+pub mod tab_group;
+pub mod tag;
+pub mod toast;
 pub mod tooltip;
+pub mod tree;
+pub mod typography;
 
 pub type Element<'a, Message> = iced::Element<'a, Message, Theme>;
 
 pub type ElementFn<'a, Arg, Message> = Box<dyn Fn(Arg) -> Element<'a, Message> + 'a>;
 
-pub use badge::Badge;
+pub use alert::{Alert, alert};
+pub use avatar::{Avatar, AvatarGroup, AvatarShape, avatar, avatar_group};
+pub use badge::{Badge, BadgePart};
 pub use breadcrumb::{Breadcrumb, BreadcrumbItem};
 pub use button_group::{ButtonGroup, button_group, button_group_with};
 pub use card::Card;
-pub use checkbox::{Checkbox, CheckboxSize, checkbox};
+pub use checkbox::{CheckState, Checkbox, CheckboxPart, CheckboxSize, checkbox, select_all};
+pub use color_picker::{ColorPicker, Format as ColorFormat, color_picker, token_swatches};
+pub use details::{Accordion, Details, accordion, details};
 pub use divider::{Divider, divider};
 pub use dropdown::{Dropdown, dropdown};
 pub use hovered::{Hovered, hovered};
-pub use input::{Input, InputSize, InputType, input};
-pub use menu::{Menu, menu, menu_with};
+pub use input::{Input, InputPart, InputSize, InputType, input};
+pub use menu::{Menu, MenuPart, menu, menu_with};
 pub use menu_item::{MenuItem, MenuItemType, menu_item};
 pub use menu_label::{MenuLabel, menu_label};
 pub use popup::{Placement as PopupPlacement, Popup, popup};
+pub use progress_bar::{ProgressBar, progress_bar};
+pub use progress_ring::{LabelPosition as ProgressLabelPosition, ProgressRing, progress_ring};
+pub use range::{Range, range};
+pub use rating::{Rating, rating};
 pub use scrollable::{Direction, scrollable, scrollable_with};
-pub use tooltip::{Placement as TooltipPlacement, Tooltip, tooltip};
+pub use skeleton::{Skeleton, SkeletonEffect, SkeletonShape};
+pub use spinner::{Spinner, spinner};
+pub use split_panel::{
+    Orientation as SplitOrientation, Pane as SplitPane, Position as SplitPosition, SplitPanel,
+    split_panel,
+};
+pub use tab_group::{
+    Activation as TabActivation, Placement as TabPlacement, Tab, TabGroup, TabPanel, tab_group,
+};
+pub use tag::{Tag, TagSize, tag};
+pub use toast::{Toast, ToastId, ToastPosition, Toasts, toast_stack};
+pub use tooltip::{Placement as TooltipPlacement, Tooltip, Trigger as TooltipTrigger, tooltip};
+pub use tree::{Tree, TreeEvent, TreeNode, TreeSelection, TreeState, tree};
+pub use typography::{Heading, Text, heading};
@@ -0,0 +1,286 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    Element,
+    components::progress_ring::LabelPosition,
+    theme::{
+        Theme,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+};
+use iced::{
+    Alignment, Length, Point, Rectangle, Renderer, Size, mouse,
+    widget::{
+        canvas::{self, Canvas, Frame, Geometry, Path},
+        container, row, stack, text,
+    },
+    window,
+};
+
+/// A Shoelace-style progress bar component for iced
+///
+/// This component implements the features from Shoelace's progress bar component:
+/// - Determinate value from 0 to 100
+/// - Indeterminate mode with a sliding indicator
+/// - Optional label inside or beside the bar
+/// - Configurable height
+/// - Theme-aware track, indicator and label colors via `ColorToken`
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::ProgressBar;
+///
+/// let bar = ProgressBar::new(state.progress).label(format!("{:.0}%", state.progress));
+/// let busy = ProgressBar::new(0.0).indeterminate(true);
+/// ```
+pub struct ProgressBar {
+    value: f32,
+    indeterminate: bool,
+    width: Length,
+    height: f32,
+    track_color: ColorToken,
+    indicator_color: ColorToken,
+    label_color: ColorToken,
+    label: Option<String>,
+    label_position: LabelPosition,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl ProgressBar {
+    /// Creates a new progress bar showing `value` percent
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            indeterminate: false,
+            width: Length::Fill,
+            height: 16.0,
+            track_color: ColorToken::new(ColorVariant::Neutral, ColorValue::C200),
+            indicator_color: ColorToken::new(ColorVariant::Primary, ColorValue::C600),
+            label_color: ColorToken::new(ColorVariant::NeutralBase, ColorValue::C50),
+            label: None,
+            label_position: LabelPosition::Inside,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets whether the bar shows an animated indeterminate state for work of unknown length
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Sets the width of the bar
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the bar (in pixels)
+    /// Default: 16
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the color of the track
+    /// Default: neutral-200
+    pub fn track_color(mut self, color: ColorToken) -> Self {
+        self.track_color = color;
+        self
+    }
+
+    /// Sets the color of the indicator
+    /// Default: primary-600
+    pub fn indicator_color(mut self, color: ColorToken) -> Self {
+        self.indicator_color = color;
+        self
+    }
+
+    /// Sets the color of a label drawn inside the bar
+    /// Default: neutral-0
+    pub fn label_color(mut self, color: ColorToken) -> Self {
+        self.label_color = color;
+        self
+    }
+
+    /// Sets the label
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets where the label is placed
+    /// Default: Inside
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+impl<'a, Message> From<ProgressBar> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(bar: ProgressBar) -> Self {
+        let sizes = bar.sizes;
+        let fonts = bar.fonts;
+        let indicator = Canvas::new(Bar {
+            progress: (!bar.indeterminate).then(|| bar.value.clamp(0.0, 100.0) / 100.0),
+            track_color: bar.track_color,
+            indicator_color: bar.indicator_color,
+        })
+        .width(Length::Fill)
+        .height(bar.height);
+
+        let Some(label) = bar.label else {
+            return container(indicator).width(bar.width).into();
+        };
+
+        match bar.label_position {
+            LabelPosition::Inside => {
+                // Shoelace centers the label over the indicator
+                let label = text(label)
+                    .size(sizes.font_size.x_small)
+                    .line_height(1.0)
+                    .font(fonts.sans)
+                    .class(TextStyleClass {
+                        color: Some(bar.label_color),
+                    });
+
+                stack![indicator, container(label).center(Length::Fill)]
+                    .width(bar.width)
+                    .height(bar.height)
+                    .into()
+            }
+            LabelPosition::Beside => row![
+                indicator,
+                text(label).size(sizes.font_size.medium).font(fonts.sans)
+            ]
+            .spacing(sizes.spacing.small)
+            .align_y(Alignment::Center)
+            .width(bar.width)
+            .into(),
+        }
+    }
+}
+
+/// Helper function to create a progress bar
+pub fn progress_bar(value: f32) -> ProgressBar {
+    ProgressBar::new(value)
+}
+
+/// Canvas program drawing a rounded track and indicator
+///
+/// With no `progress` an indicator half the width of the track slides across it,
+/// as in Shoelace's indeterminate animation.
+struct Bar {
+    progress: Option<f32>,
+    track_color: ColorToken,
+    indicator_color: ColorToken,
+}
+
+/// How long one pass of the indeterminate indicator takes
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(2500);
+
+struct BarState {
+    started: Instant,
+    now: Instant,
+}
+
+impl Default for BarState {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self { started: now, now }
+    }
+}
+
+impl<Message> canvas::Program<Message, Theme> for Bar {
+    type State = BarState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Message>> {
+        if self.progress.is_none()
+            && let iced::Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            state.now = *now;
+            return Some(canvas::Action::request_redraw());
+        }
+
+        None
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let tokens = theme.tokens();
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let width = bounds.width;
+        let height = bounds.height;
+        let radius = height / 2.0;
+
+        frame.fill(
+            &Path::rounded_rectangle(Point::ORIGIN, bounds.size(), radius.into()),
+            self.track_color.get_color(tokens),
+        );
+
+        let (start, end) = match self.progress {
+            Some(progress) => (0.0, width * progress),
+            None => {
+                let elapsed = state.now.saturating_duration_since(state.started);
+                let t = (elapsed.as_secs_f32() / INDETERMINATE_PERIOD.as_secs_f32()).fract();
+
+                // Slide from fully off the left edge to fully off the right edge
+                let indicator_width = width / 2.0;
+                let x = -indicator_width + t * (width + indicator_width);
+
+                (x.max(0.0), (x + indicator_width).min(width))
+            }
+        };
+
+        if end > start {
+            frame.fill(
+                &Path::rounded_rectangle(
+                    Point::new(start, 0.0),
+                    Size::new(end - start, height),
+                    radius.min((end - start) / 2.0).into(),
+                ),
+                self.indicator_color.get_color(tokens),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
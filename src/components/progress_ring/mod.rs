@@ -0,0 +1,299 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::time::{Duration, Instant};
+
+use crate::{
+    Element,
+    theme::{
+        Theme,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
+    },
+};
+use iced::{
+    Alignment, Point, Radians, Rectangle, Renderer, mouse,
+    widget::{
+        canvas::{self, Canvas, Frame, Geometry, LineCap, Path, Stroke, path::Arc},
+        container, row, stack, text,
+    },
+    window,
+};
+
+/// Where the label of a progress indicator is placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LabelPosition {
+    /// Centered inside the indicator
+    #[default]
+    Inside,
+    /// Next to the indicator
+    Beside,
+}
+
+/// A Shoelace-style progress ring component for iced
+///
+/// This component implements the features from Shoelace's progress ring component:
+/// - Determinate value from 0 to 100
+/// - Indeterminate mode with a rotating arc
+/// - Optional label inside or beside the ring
+/// - Configurable size, track width and indicator width
+/// - Theme-aware track and indicator colors via `ColorToken`
+///
+/// The ring is drawn with a canvas, so it renders with both the wgpu and the
+/// software renderer.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::ProgressRing;
+///
+/// let ring = ProgressRing::new(state.progress).label(format!("{:.0}%", state.progress));
+/// let busy = ProgressRing::new(0.0).indeterminate(true).size(32.0);
+/// ```
+pub struct ProgressRing {
+    value: f32,
+    indeterminate: bool,
+    size: f32,
+    track_width: f32,
+    indicator_width: f32,
+    track_color: ColorToken,
+    indicator_color: ColorToken,
+    label: Option<String>,
+    label_position: LabelPosition,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl ProgressRing {
+    /// Creates a new progress ring showing `value` percent
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            indeterminate: false,
+            size: 128.0,
+            track_width: 4.0,
+            indicator_width: 4.0,
+            track_color: ColorToken::new(ColorVariant::Neutral, ColorValue::C200),
+            indicator_color: ColorToken::new(ColorVariant::Primary, ColorValue::C600),
+            label: None,
+            label_position: LabelPosition::Inside,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets whether the ring shows an animated indeterminate state for work of unknown length
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Sets the diameter of the ring (in pixels)
+    /// Default: 128
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the track (in pixels)
+    /// Default: 4
+    pub fn track_width(mut self, width: f32) -> Self {
+        self.track_width = width;
+        self
+    }
+
+    /// Sets the width of the indicator (in pixels)
+    /// Default: 4
+    pub fn indicator_width(mut self, width: f32) -> Self {
+        self.indicator_width = width;
+        self
+    }
+
+    /// Sets the color of the track
+    /// Default: neutral-200
+    pub fn track_color(mut self, color: ColorToken) -> Self {
+        self.track_color = color;
+        self
+    }
+
+    /// Sets the color of the indicator
+    /// Default: primary-600
+    pub fn indicator_color(mut self, color: ColorToken) -> Self {
+        self.indicator_color = color;
+        self
+    }
+
+    /// Sets the label
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets where the label is placed
+    /// Default: Inside
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+impl<'a, Message> From<ProgressRing> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(ring: ProgressRing) -> Self {
+        let sizes = ring.sizes;
+        let fonts = ring.fonts;
+        let arc = Canvas::new(Ring {
+            progress: (!ring.indeterminate).then(|| ring.value.clamp(0.0, 100.0) / 100.0),
+            track_width: ring.track_width,
+            indicator_width: ring.indicator_width,
+            track_color: Some(ring.track_color),
+            indicator_color: ring.indicator_color,
+            period: Duration::from_secs(2),
+        })
+        .width(ring.size)
+        .height(ring.size);
+
+        let Some(label) = ring.label else {
+            return arc.into();
+        };
+
+        let label = text(label).size(sizes.font_size.medium).font(fonts.sans);
+
+        match ring.label_position {
+            LabelPosition::Inside => stack![arc, container(label).center(ring.size),].into(),
+            LabelPosition::Beside => row![arc, label]
+                .spacing(sizes.spacing.small)
+                .align_y(Alignment::Center)
+                .into(),
+        }
+    }
+}
+
+/// Helper function to create a progress ring
+pub fn progress_ring(value: f32) -> ProgressRing {
+    ProgressRing::new(value)
+}
+
+/// Canvas program drawing a circular track and a progress arc
+///
+/// With no `progress` the arc is a quarter circle that rotates once per `period`.
+/// Redraws are only requested from `RedrawRequested` events, so the animation stops
+/// as soon as the canvas leaves the layout.
+pub(crate) struct Ring {
+    pub progress: Option<f32>,
+    pub track_width: f32,
+    pub indicator_width: f32,
+    pub track_color: Option<ColorToken>,
+    pub indicator_color: ColorToken,
+    pub period: Duration,
+}
+
+pub(crate) struct RingState {
+    started: Instant,
+    now: Instant,
+}
+
+impl Default for RingState {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self { started: now, now }
+    }
+}
+
+impl<Message> canvas::Program<Message, Theme> for Ring {
+    type State = RingState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Message>> {
+        if self.progress.is_none()
+            && let iced::Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            state.now = *now;
+            return Some(canvas::Action::request_redraw());
+        }
+
+        None
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let tokens = theme.tokens();
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let radius =
+            (bounds.width.min(bounds.height) - self.track_width.max(self.indicator_width)) / 2.0;
+
+        if radius <= 0.0 {
+            return vec![frame.into_geometry()];
+        }
+
+        if let Some(track_color) = self.track_color {
+            frame.stroke(
+                &Path::circle(center, radius),
+                Stroke::default()
+                    .with_color(track_color.get_color(tokens))
+                    .with_width(self.track_width),
+            );
+        }
+
+        // Angles start at 12 o'clock and grow clockwise
+        let (start, sweep) = match self.progress {
+            Some(progress) => (-FRAC_PI_2, progress * TAU),
+            None => {
+                let elapsed = state.now.saturating_duration_since(state.started);
+                let turns = elapsed.as_secs_f32() / self.period.as_secs_f32();
+
+                (-FRAC_PI_2 + turns.fract() * TAU, TAU / 4.0)
+            }
+        };
+
+        if sweep > 0.0 {
+            let arc = Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start),
+                    end_angle: Radians(start + sweep),
+                });
+            });
+
+            frame.stroke(
+                &arc,
+                Stroke::default()
+                    .with_color(self.indicator_color.get_color(tokens))
+                    .with_width(self.indicator_width)
+                    .with_line_cap(LineCap::Round),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
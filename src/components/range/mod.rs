@@ -0,0 +1,382 @@
+use crate::{
+    Element,
+    components::tooltip::{Placement, Trigger, tooltip},
+    theme::{
+        Theme,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        range::RangeStyleClass,
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+    widgets::range::{self, Value},
+};
+use iced::{
+    Alignment, Length, Padding,
+    widget::{Component, Stack, column, component, container, row, text},
+};
+use iced_widget::{Column, text::Wrapping};
+
+/// The resolution of tick label positions, in parts of the track width
+const TICK_PORTIONS: f32 = 1000.0;
+
+/// A Shoelace-style range component for iced
+///
+/// This component implements the features from Shoelace's range component:
+/// - Min, max and step
+/// - Value tooltip shown while dragging (top or bottom placement)
+/// - Optional tick marks with labels
+/// - Custom value formatter for the tooltip and tick labels
+/// - Dual-thumb mode for selecting an interval
+/// - Disabled state
+/// - Label and help text
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Range;
+///
+/// // Simple volume slider
+/// let volume = Range::new(0.0, 100.0, 50.0)
+///     .on_change(Message::VolumeChanged);
+///
+/// // Frequency band with ticks and a custom formatter
+/// let band = Range::interval(20.0, 20_000.0, (200.0, 2_000.0))
+///     .step(10.0)
+///     .ticks(5_000.0)
+///     .formatter(|value| format!("{value} Hz"))
+///     .on_interval_change(Message::BandChanged);
+/// ```
+pub struct Range<Message> {
+    min: f32,
+    max: f32,
+    value: Value,
+    step: f32,
+    ticks: Option<f32>,
+    tick_labels: bool,
+    tooltip: Option<Placement>,
+    formatter: Box<dyn Fn(f32) -> String>,
+    label: Option<String>,
+    help_text: Option<String>,
+    disabled: bool,
+    width: Length,
+    on_change: Option<Box<dyn Fn(f32) -> Message>>,
+    on_interval_change: Option<Box<dyn Fn(f32, f32) -> Message>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<Message> Range<Message> {
+    /// Creates a new single-thumb range between `min` and `max`
+    pub fn new(min: f32, max: f32, value: f32) -> Self {
+        Self::with_value(min, max, Value::Single(value))
+    }
+
+    /// Creates a new dual-thumb range selecting the interval `(lower, upper)`
+    pub fn interval(min: f32, max: f32, (lower, upper): (f32, f32)) -> Self {
+        Self::with_value(min, max, Value::Interval(lower, upper))
+    }
+
+    fn with_value(min: f32, max: f32, value: Value) -> Self {
+        Self {
+            min,
+            max,
+            value,
+            step: 1.0,
+            ticks: None,
+            tick_labels: true,
+            tooltip: Some(Placement::Top),
+            formatter: Box::new(|value| value.to_string()),
+            label: None,
+            help_text: None,
+            disabled: false,
+            width: Length::Fill,
+            on_change: None,
+            on_interval_change: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the step the value snaps to
+    /// Default: 1.0
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Draws tick marks every `interval` units, starting from `min`
+    pub fn ticks(mut self, interval: f32) -> Self {
+        self.ticks = Some(interval);
+        self
+    }
+
+    /// Sets whether tick marks are labelled with their formatted value
+    /// Default: true
+    pub fn tick_labels(mut self, tick_labels: bool) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
+    /// Sets where the value tooltip is shown while dragging, or `None` to hide it
+    /// Default: Top
+    pub fn tooltip(mut self, placement: Option<Placement>) -> Self {
+        self.tooltip = placement;
+        self
+    }
+
+    /// Sets the function used to format values in the tooltip and tick labels
+    pub fn formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: 'static + Fn(f32) -> String,
+    {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Sets the label text shown above the range
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the help text shown below the range
+    pub fn help_text(mut self, help_text: impl Into<String>) -> Self {
+        self.help_text = Some(help_text.into());
+        self
+    }
+
+    /// Sets whether the range is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the width of the range
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the callback invoked when the value of a single-thumb range changes
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback invoked when the interval of a dual-thumb range changes
+    pub fn on_interval_change<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(f32, f32) -> Message,
+    {
+        self.on_interval_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Computes the tick positions from the tick interval
+    fn tick_values(&self) -> Vec<f32> {
+        let Some(interval) = self.ticks.filter(|interval| *interval > 0.0) else {
+            return Vec::new();
+        };
+
+        // An empty range would put every tick at a NaN fraction of the track
+        if self.max <= self.min {
+            return Vec::new();
+        }
+
+        // The epsilon keeps a tick at max when the division drifts just below a whole number
+        let count = ((self.max - self.min) / interval + 1e-4).floor() as usize;
+
+        (0..=count)
+            .map(|index| self.min + index as f32 * interval)
+            .collect()
+    }
+
+    /// Formats the current value for the tooltip
+    fn tooltip_text(&self) -> String {
+        match self.value {
+            Value::Single(value) => (self.formatter)(value),
+            Value::Interval(lower, upper) => {
+                format!("{} – {}", (self.formatter)(lower), (self.formatter)(upper))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Changed(Value),
+    Released,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RangeState {
+    dragging: bool,
+}
+
+impl<'a, Message> Component<'a, Message, Theme> for Range<Message>
+where
+    Message: Clone + 'a,
+{
+    type State = RangeState;
+    type Event = Event;
+
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
+        match event {
+            Event::Changed(value) => {
+                if self.disabled {
+                    return None;
+                }
+
+                state.dragging = true;
+                self.value = value;
+
+                match value {
+                    Value::Single(value) => self.on_change.as_ref().map(|f| f(value)),
+                    Value::Interval(lower, upper) => {
+                        self.on_interval_change.as_ref().map(|f| f(lower, upper))
+                    }
+                }
+            }
+            Event::Released => {
+                state.dragging = false;
+                None
+            }
+        }
+    }
+
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let ticks = self.tick_values();
+        let thumb_size = sizes.toggle_size.medium;
+
+        // Shoelace uses a 6px track with a 14px thumb
+        let slider = range::Range::new(self.min..=self.max, self.value, Event::Changed)
+            .step(self.step)
+            .ticks(ticks.clone())
+            .thumb_size(thumb_size)
+            .track_height(sizes.spacing.x_small + sizes.spacing.x2_small)
+            .disabled(self.disabled)
+            .on_release(Event::Released)
+            .class(RangeStyleClass::Default);
+
+        let slider: Element<'a, Event> = match self.tooltip {
+            Some(placement) => tooltip(self.tooltip_text(), slider)
+                .fonts(fonts)
+                .placement(placement)
+                .trigger(Trigger::Manual)
+                .open(state.dragging && !self.disabled)
+                .into(),
+            None => slider.into(),
+        };
+
+        let mut content = Column::new()
+            .spacing(sizes.spacing.x_small)
+            .width(self.width);
+
+        if let Some(label) = &self.label {
+            content = content.push(
+                text(label.clone())
+                    .size(sizes.font_size.medium)
+                    .font(fonts.sans)
+                    .class(TextStyleClass {
+                        color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
+                    }),
+            );
+        }
+
+        content = content.push(slider);
+
+        // Tick labels sit at the same fraction of the track as their ticks. Each one
+        // is centered in a cell that extends equally to both sides of its tick, and
+        // the labels at the ends of the track are aligned to its edges instead.
+        if self.tick_labels && ticks.len() > 1 {
+            let portion =
+                |fraction: f32| Length::FillPortion((fraction * TICK_PORTIONS).round() as u16);
+
+            let labels = ticks.iter().map(|tick| {
+                let fraction = ((tick - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
+                let half = fraction.min(1.0 - fraction);
+
+                let label = text((self.formatter)(*tick))
+                    .size(sizes.font_size.x_small)
+                    .line_height(sizes.line_height.dense)
+                    .font(fonts.sans)
+                    .wrapping(Wrapping::None)
+                    .class(TextStyleClass {
+                        color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C500)),
+                    });
+
+                if half * TICK_PORTIONS < 1.0 {
+                    let align = if fraction < 0.5 {
+                        Alignment::Start
+                    } else {
+                        Alignment::End
+                    };
+
+                    container(label).width(Length::Fill).align_x(align).into()
+                } else {
+                    row![
+                        container(column![]).width(portion(fraction - half)),
+                        label.width(portion(2.0 * half)).center(),
+                        container(column![]).width(portion(1.0 - fraction - half)),
+                    ]
+                    .into()
+                }
+            });
+
+            content = content.push(
+                container(Stack::with_children(labels).width(Length::Fill))
+                    .padding(Padding::from([0.0, thumb_size / 2.0])),
+            );
+        }
+
+        if let Some(help) = &self.help_text {
+            content = content.push(
+                text(help.clone())
+                    .size(sizes.font_size.small)
+                    .font(fonts.sans)
+                    .class(TextStyleClass {
+                        color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C500)),
+                    }),
+            );
+        }
+
+        content.into()
+    }
+}
+
+impl<'a, Message> From<Range<Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(range: Range<Message>) -> Self {
+        component(range)
+    }
+}
+
+/// Helper function to create a single-thumb range
+pub fn range<Message>(min: f32, max: f32, value: f32) -> Range<Message>
+where
+    Message: Clone,
+{
+    Range::new(min, max, value)
+}
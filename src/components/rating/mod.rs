@@ -0,0 +1,344 @@
+use crate::{
+    Element,
+    theme::{
+        Theme,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+    widgets::keyboard_area::KeyboardArea,
+};
+use iced::{
+    Length, Point,
+    keyboard::{Key, key::Named},
+    widget::{Component, component, container, mouse_area, stack, text},
+};
+use iced_widget::{Row, text::Wrapping};
+
+/// A Shoelace-style rating component for iced
+///
+/// This component implements the features from Shoelace's rating component:
+/// - Configurable max value (number of symbols)
+/// - Fractional precision (e.g. 0.5 for half stars)
+/// - Hover preview of the value under the cursor
+/// - Readonly and disabled states
+/// - Custom symbols per value
+/// - Keyboard support (arrows, Home and End) once focused
+/// - Theme-aware active and inactive colors via `ColorToken`
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Rating;
+///
+/// // Five stars with half-star precision
+/// let rating = Rating::new(3.5)
+///     .precision(0.5)
+///     .on_change(Message::RatingChanged);
+///
+/// // Custom symbols per value
+/// let mood = Rating::new(2.0)
+///     .symbol(|value| ["😞", "😐", "🙂", "😀", "😍"][value - 1].to_string())
+///     .on_change(Message::MoodChanged);
+/// ```
+pub struct Rating<Message> {
+    value: f32,
+    max: usize,
+    precision: f32,
+    size: Option<f32>,
+    readonly: bool,
+    disabled: bool,
+    active_color: ColorToken,
+    inactive_color: ColorToken,
+    symbol: Box<dyn Fn(usize) -> String>,
+    on_change: Option<Box<dyn Fn(f32) -> Message>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<Message> Rating<Message> {
+    /// Creates a new rating with the given value
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            max: 5,
+            precision: 1.0,
+            size: None,
+            readonly: false,
+            disabled: false,
+            active_color: ColorToken::new(ColorVariant::Amber, ColorValue::C400),
+            inactive_color: ColorToken::new(ColorVariant::Neutral, ColorValue::C300),
+            symbol: Box::new(|_| "★".to_string()),
+            on_change: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the highest rating (number of symbols)
+    /// Default: 5
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the precision the value snaps to, e.g. 0.5 for half symbols
+    /// Default: 1.0
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the size of each symbol (in pixels)
+    /// Default: the x-large font size
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets whether the rating is readonly (displayed but not editable)
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Sets whether the rating is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the color of selected symbols
+    /// Default: amber-400
+    pub fn active_color(mut self, color: ColorToken) -> Self {
+        self.active_color = color;
+        self
+    }
+
+    /// Sets the color of unselected symbols
+    /// Default: neutral-300
+    pub fn inactive_color(mut self, color: ColorToken) -> Self {
+        self.inactive_color = color;
+        self
+    }
+
+    /// Sets the function that renders the symbol for each value (1-based)
+    pub fn symbol<F>(mut self, symbol: F) -> Self
+    where
+        F: 'static + Fn(usize) -> String,
+    {
+        self.symbol = Box::new(symbol);
+        self
+    }
+
+    /// Sets the callback invoked when the user picks a new value
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// The size of each symbol
+    fn symbol_size(&self) -> f32 {
+        self.size.unwrap_or(self.sizes.font_size.x_large)
+    }
+
+    /// Whether the rating reacts to pointer and keyboard input
+    fn is_interactive(&self) -> bool {
+        !self.readonly && !self.disabled
+    }
+
+    /// Rounds a value up to the configured precision, as Shoelace does
+    fn round_to_precision(&self, value: f32) -> f32 {
+        if self.precision > 0.0 {
+            (value / self.precision).ceil() * self.precision
+        } else {
+            value
+        }
+    }
+
+    /// Clamps and publishes a new value
+    fn change(&mut self, value: f32) -> Option<Message> {
+        let value = value.clamp(0.0, self.max as f32);
+
+        if value == self.value {
+            return None;
+        }
+
+        self.value = value;
+        self.on_change.as_ref().map(|f| f(value))
+    }
+
+    /// Resolves the text colors for the active and inactive layers
+    fn colors(&self) -> (ColorToken, ColorToken) {
+        if self.disabled {
+            (
+                ColorToken::new(ColorVariant::Neutral, ColorValue::C400),
+                ColorToken::new(ColorVariant::Neutral, ColorValue::C200),
+            )
+        } else {
+            (self.active_color, self.inactive_color)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Hovered(usize, Point),
+    Exited,
+    Pressed,
+    KeyPressed(Key),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RatingState {
+    hover_value: Option<f32>,
+}
+
+impl<'a, Message> Component<'a, Message, Theme> for Rating<Message>
+where
+    Message: Clone + 'a,
+{
+    type State = RatingState;
+    type Event = Event;
+
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
+        if !self.is_interactive() {
+            state.hover_value = None;
+            return None;
+        }
+
+        match event {
+            Event::Hovered(index, position) => {
+                let fraction = (position.x / self.symbol_size()).clamp(0.0, 1.0);
+                let value = self.round_to_precision(index as f32 + fraction);
+                // Not `clamp`, which panics when the precision exceeds max or is NaN
+                state.hover_value = Some(value.max(self.precision).min(self.max as f32));
+                None
+            }
+            Event::Exited => {
+                state.hover_value = None;
+                None
+            }
+            Event::Pressed => {
+                let hovered = state.hover_value?;
+
+                // Clicking the current value clears the rating, as in Shoelace
+                let value = if hovered == self.value { 0.0 } else { hovered };
+                state.hover_value = None;
+                self.change(value)
+            }
+            Event::KeyPressed(key) => {
+                let value = match key {
+                    Key::Named(Named::ArrowRight | Named::ArrowUp) => self.value + self.precision,
+                    Key::Named(Named::ArrowLeft | Named::ArrowDown) => self.value - self.precision,
+                    Key::Named(Named::Home) => 0.0,
+                    Key::Named(Named::End) => self.max as f32,
+                    _ => return None,
+                };
+
+                self.change(value)
+            }
+        }
+    }
+
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let displayed = state.hover_value.unwrap_or(self.value);
+        let (active_color, inactive_color) = self.colors();
+        let interactive = self.is_interactive();
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let size = self.symbol_size();
+
+        let symbols = (0..self.max).map(|index| {
+            let symbol = (self.symbol)(index + 1);
+            let fill = (displayed - index as f32).clamp(0.0, 1.0);
+
+            let glyph = |color: ColorToken| {
+                text(symbol.clone())
+                    .size(size)
+                    .line_height(1.0)
+                    .font(fonts.sans)
+                    .width(Length::Fixed(size))
+                    .center()
+                    .wrapping(Wrapping::None)
+                    .class(TextStyleClass { color: Some(color) })
+            };
+
+            // The active symbol is drawn over the inactive one and clipped to the fill ratio
+            let cell = stack![
+                glyph(inactive_color),
+                container(glyph(active_color))
+                    .width(Length::Fixed(size * fill))
+                    .clip(true),
+            ];
+
+            let cell = mouse_area(cell);
+
+            let cell = if interactive {
+                cell.on_move(move |position| Event::Hovered(index, position))
+                    .on_press(Event::Pressed)
+                    .interaction(iced::mouse::Interaction::Pointer)
+            } else {
+                cell
+            };
+
+            cell.into()
+        });
+
+        let row = mouse_area(Row::with_children(symbols).spacing(sizes.spacing.x2_small))
+            .on_exit(Event::Exited);
+
+        if interactive {
+            KeyboardArea::new(row)
+                .on_key_press(|key, _modifiers| match key {
+                    Key::Named(
+                        Named::ArrowLeft
+                        | Named::ArrowRight
+                        | Named::ArrowUp
+                        | Named::ArrowDown
+                        | Named::Home
+                        | Named::End,
+                    ) => Some(Event::KeyPressed(key)),
+                    _ => None,
+                })
+                .into()
+        } else {
+            row.into()
+        }
+    }
+}
+
+impl<'a, Message> From<Rating<Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(rating: Rating<Message>) -> Self {
+        component(rating)
+    }
+}
+
+/// Helper function to create a rating
+pub fn rating<Message>(value: f32) -> Rating<Message>
+where
+    Message: Clone,
+{
+    Rating::new(value)
+}
@@ -5,7 +5,7 @@ use iced::widget::{
 
 use crate::{
     components::Element,
-    theme::{Theme, sizes::SPACING},
+    theme::{Theme, sizes::Sizes},
 };
 
 pub enum Direction {
@@ -16,15 +16,17 @@ pub enum Direction {
 
 pub fn scrollable<'a, Message>(
     base: impl Into<Element<'a, Message>>,
+    sizes: Sizes,
 ) -> Scrollable<'a, Message, Theme> {
-    scrollable_with(base, Direction::Vertical)
+    scrollable_with(base, Direction::Vertical, sizes)
 }
 
 pub fn scrollable_with<'a, Message>(
     base: impl Into<Element<'a, Message>>,
     direction: Direction,
+    sizes: Sizes,
 ) -> Scrollable<'a, Message, Theme> {
-    let scrollbar_width = SPACING.small; // 12.0
+    let scrollbar_width = sizes.spacing.small; // 12.0
 
     let scrollbar: Scrollbar = Scrollbar::default()
         .spacing(0)
@@ -0,0 +1,311 @@
+use std::f32::consts::TAU;
+use std::time::{Duration, Instant};
+
+use crate::{
+    Element,
+    components::{card::Card, menu::Menu},
+    theme::{
+        Theme,
+        sizes::{SIZES, Sizes},
+    },
+};
+use iced::{
+    Alignment, Length, Padding, Point, Rectangle, Renderer, mouse,
+    widget::{
+        canvas::{self, Canvas, Frame, Geometry, Gradient, Path, gradient::Linear},
+        column, container, row,
+    },
+    window,
+};
+
+/// The shape of a skeleton placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkeletonShape {
+    /// A pill-shaped line the height of body text
+    #[default]
+    Text,
+    /// A rectangle with rounded corners
+    Rectangle,
+    /// A circle fitting the placeholder bounds
+    Circle,
+}
+
+/// The animation of a skeleton placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkeletonEffect {
+    #[default]
+    None,
+    /// Fades in and out
+    Pulse,
+    /// A lighter band sweeps across the placeholder
+    Sheen,
+}
+
+/// A Shoelace-style skeleton component for iced
+///
+/// This component implements the features from Shoelace's skeleton component:
+/// - Text line, rectangle and circle shapes
+/// - Pulse and sheen effects
+/// - Colors from the neutral scale of the active theme
+///
+/// Effects are animated through window redraws and stop as soon as the skeleton
+/// leaves the layout. [`Skeleton::card`] and [`Skeleton::menu_items`] build
+/// placeholders laid out like a [`Card`] or a [`Menu`] of menu items.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Skeleton, SkeletonEffect};
+///
+/// let line = Skeleton::text().width(200).effect(SkeletonEffect::Sheen);
+/// let avatar = Skeleton::circle(36.0).effect(SkeletonEffect::Pulse);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Skeleton {
+    shape: SkeletonShape,
+    effect: SkeletonEffect,
+    width: Length,
+    height: Option<Length>,
+    sizes: Sizes,
+}
+
+impl Skeleton {
+    /// Creates a text line placeholder filling the available width
+    pub fn text() -> Self {
+        Self {
+            shape: SkeletonShape::Text,
+            effect: SkeletonEffect::None,
+            width: Length::Fill,
+            height: None,
+            sizes: SIZES,
+        }
+    }
+
+    /// Creates a rectangle placeholder
+    pub fn rectangle(width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        Self {
+            shape: SkeletonShape::Rectangle,
+            effect: SkeletonEffect::None,
+            width: width.into(),
+            height: Some(height.into()),
+            sizes: SIZES,
+        }
+    }
+
+    /// Creates a circle placeholder with the given diameter
+    pub fn circle(size: f32) -> Self {
+        Self {
+            shape: SkeletonShape::Circle,
+            effect: SkeletonEffect::None,
+            width: Length::Fixed(size),
+            height: Some(Length::Fixed(size)),
+            sizes: SIZES,
+        }
+    }
+
+    /// Sets the animation effect
+    /// Default: None
+    pub fn effect(mut self, effect: SkeletonEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Sets the width of the placeholder
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the placeholder
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Builds a card placeholder with an image, a title, a paragraph and a footer,
+    /// laid out with `sizes`
+    pub fn card<'a, Message: 'a>(effect: SkeletonEffect, sizes: Sizes) -> Card<'a, Message> {
+        let line = |width: f32| Skeleton::text().width(width).effect(effect).sizes(sizes);
+
+        Card::new(
+            column![
+                line(160.0).height(sizes.font_size.large),
+                Skeleton::text().effect(effect).sizes(sizes),
+                Skeleton::text().effect(effect).sizes(sizes),
+                line(120.0),
+            ]
+            .spacing(sizes.spacing.small),
+        )
+        .sizes(sizes)
+        .image(Skeleton::rectangle(Length::Fill, 150).effect(effect))
+        .footer(
+            row![
+                line(80.0),
+                container(column![]).width(Length::Fill),
+                Skeleton::rectangle(64, 28).effect(effect),
+            ]
+            .align_y(Alignment::Center),
+        )
+    }
+
+    /// Builds a menu placeholder with `count` items, each an icon and a label, laid
+    /// out with `sizes`
+    pub fn menu_items<'a, Message: 'a>(
+        count: usize,
+        effect: SkeletonEffect,
+        sizes: Sizes,
+    ) -> Menu<'a, Message> {
+        // Matches the padding and line height of `MenuItem`
+        let line_height = sizes.font_size.medium * sizes.line_height.dense;
+        let widths = [120.0, 90.0, 105.0, 75.0];
+
+        (0..count).fold(Menu::new().sizes(sizes), |menu, index| {
+            menu.push(
+                container(
+                    row![
+                        Skeleton::circle(line_height).effect(effect),
+                        Skeleton::text()
+                            .width(widths[index % widths.len()])
+                            .effect(effect)
+                            .sizes(sizes),
+                    ]
+                    .spacing(sizes.spacing.small)
+                    .align_y(Alignment::Center),
+                )
+                .padding(Padding::from([sizes.spacing.x2_small, sizes.spacing.small]))
+                .width(Length::Fill),
+            )
+        })
+    }
+}
+
+impl<'a, Message> From<Skeleton> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(skeleton: Skeleton) -> Self {
+        Canvas::new(Placeholder {
+            shape: skeleton.shape,
+            effect: skeleton.effect,
+        })
+        .width(skeleton.width)
+        .height(
+            skeleton
+                .height
+                .unwrap_or(Length::Fixed(skeleton.sizes.font_size.medium)),
+        )
+        .into()
+    }
+}
+
+/// How long one fade of the pulse effect takes
+const PULSE_PERIOD: Duration = Duration::from_secs(2);
+
+/// How long one sweep of the sheen effect takes
+const SHEEN_PERIOD: Duration = Duration::from_secs(3);
+
+/// Canvas program drawing a placeholder shape
+struct Placeholder {
+    shape: SkeletonShape,
+    effect: SkeletonEffect,
+}
+
+struct PlaceholderState {
+    started: Instant,
+    now: Instant,
+}
+
+impl Default for PlaceholderState {
+    fn default() -> Self {
+        let now = Instant::now();
+
+        Self { started: now, now }
+    }
+}
+
+impl<Message> canvas::Program<Message, Theme> for Placeholder {
+    type State = PlaceholderState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &iced::Event,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Option<canvas::Action<Message>> {
+        if self.effect != SkeletonEffect::None
+            && let iced::Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            state.now = *now;
+            return Some(canvas::Action::request_redraw());
+        }
+
+        None
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let tokens = theme.tokens();
+        let mut frame = Frame::new(renderer, bounds.size());
+        let size = bounds.size();
+        let radius = theme.sizes().border_radius.medium;
+
+        let path = match self.shape {
+            SkeletonShape::Text => {
+                Path::rounded_rectangle(Point::ORIGIN, size, (size.height / 2.0).into())
+            }
+            SkeletonShape::Rectangle => {
+                Path::rounded_rectangle(Point::ORIGIN, size, radius.min(size.height / 2.0).into())
+            }
+            SkeletonShape::Circle => Path::circle(
+                Point::new(size.width / 2.0, size.height / 2.0),
+                size.width.min(size.height) / 2.0,
+            ),
+        };
+
+        let elapsed = state.now.saturating_duration_since(state.started);
+        let color = tokens.neutral.c200;
+
+        match self.effect {
+            SkeletonEffect::None => frame.fill(&path, color),
+            SkeletonEffect::Pulse => {
+                // Fade down to 40% opacity and back, like Shoelace's pulse keyframes
+                let t = (elapsed.as_secs_f32() / PULSE_PERIOD.as_secs_f32()).fract();
+                let fade = 0.5 - 0.5 * (t * TAU).cos();
+
+                frame.fill(&path, color.scale_alpha(1.0 - 0.6 * fade));
+            }
+            SkeletonEffect::Sheen => {
+                // The band travels from fully before the left edge to fully past the right edge
+                let t = (elapsed.as_secs_f32() / SHEEN_PERIOD.as_secs_f32()).fract();
+                let band = size.width.max(size.height);
+                let center = -band + t * (size.width + 2.0 * band);
+
+                let sheen = Linear::new(
+                    Point::new(center - band, 0.0),
+                    Point::new(center + band, 0.0),
+                )
+                .add_stop(0.0, color)
+                .add_stop(0.5, tokens.neutral.c300)
+                .add_stop(1.0, color);
+
+                frame.fill(&path, Gradient::Linear(sheen));
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
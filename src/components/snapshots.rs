@@ -0,0 +1,280 @@
+//! Snapshots of every variant and size of the core components
+//!
+//! See [`crate::snapshot`] for how they are rendered, compared and updated. Loading
+//! states are left out, as spinners are drawn at the current time.
+use iced::widget::{Column, Row, column, container, row, text};
+
+use crate::{
+    Element,
+    components::{
+        Badge, Breadcrumb, BreadcrumbItem, Card, Checkbox, CheckboxSize, Divider, Input, InputSize,
+        Menu, MenuItem, MenuItemType, MenuLabel, button::Button,
+    },
+    snapshot::{assert_snapshot, render},
+    theme::{
+        Theme,
+        badge::BadgeVariant,
+        button::{ButtonSize, ButtonVariant},
+    },
+};
+
+const BUTTON_VARIANTS: [ButtonVariant; 7] = [
+    ButtonVariant::Default,
+    ButtonVariant::Primary,
+    ButtonVariant::Success,
+    ButtonVariant::Neutral,
+    ButtonVariant::Warning,
+    ButtonVariant::Danger,
+    ButtonVariant::Text,
+];
+
+const BADGE_VARIANTS: [BadgeVariant; 5] = [
+    BadgeVariant::Primary,
+    BadgeVariant::Success,
+    BadgeVariant::Neutral,
+    BadgeVariant::Warning,
+    BadgeVariant::Danger,
+];
+
+/// Pads the content so borders and shadows are not cut off
+fn frame<'a>(content: impl Into<Element<'a, ()>>) -> Element<'a, ()> {
+    container(content).padding(12).into()
+}
+
+fn buttons(button: impl Fn(ButtonVariant) -> Button<()>) -> Element<'static, ()> {
+    frame(Row::with_children(BUTTON_VARIANTS.map(|variant| button(variant).into())).spacing(8))
+}
+
+#[test]
+fn button_variants() {
+    assert_snapshot("button-variants", (760.0, 72.0), || {
+        buttons(|variant| Button::new(format!("{variant:?}")).variant(variant))
+    });
+}
+
+#[test]
+fn button_outline() {
+    assert_snapshot("button-outline", (760.0, 72.0), || {
+        buttons(|variant| {
+            Button::new(format!("{variant:?}"))
+                .variant(variant)
+                .outline(true)
+        })
+    });
+}
+
+#[test]
+fn button_pill() {
+    assert_snapshot("button-pill", (760.0, 72.0), || {
+        buttons(|variant| {
+            Button::new(format!("{variant:?}"))
+                .variant(variant)
+                .pill(true)
+        })
+    });
+}
+
+#[test]
+fn button_disabled() {
+    assert_snapshot("button-disabled", (760.0, 72.0), || {
+        buttons(|variant| {
+            Button::new(format!("{variant:?}"))
+                .variant(variant)
+                .disabled(true)
+        })
+    });
+}
+
+#[test]
+fn button_sizes() {
+    assert_snapshot("button-sizes", (980.0, 184.0), || {
+        let sizes = [ButtonSize::Small, ButtonSize::Medium, ButtonSize::Large];
+
+        frame(
+            Column::with_children(sizes.map(|size| -> Element<'static, ()> {
+                Row::with_children(BUTTON_VARIANTS.map(|variant| {
+                    Button::new(format!("{variant:?}"))
+                        .variant(variant)
+                        .size(size)
+                        .into()
+                }))
+                .spacing(8)
+                .into()
+            }))
+            .spacing(8),
+        )
+    });
+}
+
+#[test]
+fn button_prefix_suffix() {
+    assert_snapshot("button-prefix-suffix", (420.0, 72.0), || {
+        frame(
+            row![
+                Button::<()>::new("Add").prefix("+"),
+                Button::<()>::new("Download").suffix("↓"),
+                Button::<()>::new("Delete")
+                    .variant(ButtonVariant::Danger)
+                    .prefix("×")
+                    .suffix("→"),
+            ]
+            .spacing(8),
+        )
+    });
+}
+
+#[test]
+fn badge_variants() {
+    assert_snapshot("badge-variants", (420.0, 96.0), || {
+        let badges = |pill: bool| -> Element<'static, ()> {
+            Row::with_children(BADGE_VARIANTS.map(|variant| {
+                Badge::new(format!("{variant:?}"))
+                    .variant(variant)
+                    .pill(pill)
+                    .into()
+            }))
+            .spacing(8)
+            .into()
+        };
+
+        frame(column![badges(false), badges(true)].spacing(8))
+    });
+}
+
+#[test]
+fn input_sizes() {
+    assert_snapshot("input-sizes", (320.0, 200.0), || {
+        frame(
+            column![
+                Input::<()>::new("Small").size(InputSize::Small),
+                Input::<()>::new("Medium").size(InputSize::Medium),
+                Input::<()>::new("Large").size(InputSize::Large),
+            ]
+            .spacing(12),
+        )
+    });
+}
+
+#[test]
+fn input_styles() {
+    assert_snapshot("input-styles", (320.0, 320.0), || {
+        frame(
+            column![
+                Input::<()>::new("Placeholder"),
+                Input::<()>::new("Filled").filled(true),
+                Input::<()>::new("Pill").pill(true),
+                Input::<()>::new("Disabled").disabled(true),
+                Input::<()>::new("Placeholder")
+                    .value("Value")
+                    .label("Name")
+                    .required(true)
+                    .help_text("Help text"),
+            ]
+            .spacing(12),
+        )
+    });
+}
+
+#[test]
+fn checkbox_states() {
+    assert_snapshot("checkbox-states", (320.0, 200.0), || {
+        frame(
+            column![
+                Checkbox::<()>::new("Unchecked", false),
+                Checkbox::<()>::new("Checked", true),
+                Checkbox::<()>::new("Indeterminate", false).indeterminate(true),
+                Checkbox::<()>::new("Disabled", true).disabled(true),
+                Checkbox::<()>::new("Help", false).help_text("Help text"),
+            ]
+            .spacing(8),
+        )
+    });
+}
+
+#[test]
+fn checkbox_sizes() {
+    assert_snapshot("checkbox-sizes", (420.0, 64.0), || {
+        frame(
+            row![
+                Checkbox::<()>::new("Small", true).size(CheckboxSize::Small),
+                Checkbox::<()>::new("Medium", true).size(CheckboxSize::Medium),
+                Checkbox::<()>::new("Large", true).size(CheckboxSize::Large),
+            ]
+            .spacing(16),
+        )
+    });
+}
+
+#[test]
+fn card() {
+    assert_snapshot("card", (360.0, 240.0), || {
+        frame(
+            Card::new(text("The card's content"))
+                .header(text("Header"))
+                .footer(Button::<()>::new("Footer").variant(ButtonVariant::Primary))
+                .width(320),
+        )
+    });
+}
+
+#[test]
+fn menu() {
+    assert_snapshot("menu", (240.0, 260.0), || {
+        frame(
+            Menu::<()>::new()
+                .push(MenuLabel::new("File"))
+                .push(MenuItem::new("New").prefix("+").on_select(()))
+                .push(MenuItem::<()>::new("Open").suffix("⌘O"))
+                .push(
+                    MenuItem::<()>::new("Autosave")
+                        .item_type(MenuItemType::Checkbox)
+                        .checked(true),
+                )
+                .push(Divider::new())
+                .push(MenuItem::<()>::new("Disabled").disabled(true))
+                .width(200),
+        )
+    });
+}
+
+#[test]
+fn breadcrumb() {
+    assert_snapshot("breadcrumb", (420.0, 96.0), || {
+        let items = || {
+            Breadcrumb::<()>::new()
+                .push(BreadcrumbItem::new("Home").on_press(()))
+                .push(BreadcrumbItem::new("Components").on_press(()))
+                .push(BreadcrumbItem::new("Breadcrumb"))
+        };
+
+        frame(column![items(), items().separator("›")].spacing(12))
+    });
+}
+
+#[test]
+fn divider() {
+    assert_snapshot("divider", (320.0, 160.0), || {
+        frame(
+            column![
+                Divider::new(),
+                Divider::new().width(4.0),
+                row![text("Left"), Divider::new().vertical(true), text("Right")].height(40),
+            ]
+            .width(280),
+        )
+    });
+}
+
+#[test]
+fn themes_render_differently() {
+    let view = || -> Element<'static, ()> {
+        Column::new()
+            .push(Button::<()>::new("Primary").variant(ButtonVariant::Primary))
+            .into()
+    };
+
+    let light = render(view(), &Theme::Light, (200.0, 60.0).into());
+    let dark = render(view(), &Theme::Dark, (200.0, 60.0).into());
+
+    assert!(light != dark, "the light and dark renderings are identical");
+}
@@ -0,0 +1,153 @@
+use crate::{Element, theme::split_panel::SplitPanelStyleClass, widgets::split::Split};
+use iced::{Length, widget::container};
+
+pub use crate::widgets::split::{Orientation, Pane, Position};
+
+/// A Shoelace-style split panel component for iced
+///
+/// This component implements the features from Shoelace's split panel component:
+/// - Two panes separated by a draggable divider
+/// - Horizontal and vertical orientation
+/// - Position in pixels or as a percentage
+/// - Snap points and minimum/maximum pane sizes
+/// - Keyboard resizing with the arrow keys, Home and End once the divider is clicked
+///   or focused with Tab
+/// - A primary pane that keeps its pixel size when the panel is resized
+///
+/// The position is controlled: store the value produced by `on_reposition` and pass
+/// it back, which also lets the application persist the layout.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{SplitPanel, SplitPosition};
+///
+/// let panel = SplitPanel::new(sidebar, editor, state.sidebar)
+///     .min(120.0)
+///     .snaps([SplitPosition::Pixels(200.0)])
+///     .on_reposition(Message::SidebarResized);
+/// ```
+pub struct SplitPanel<'a, Message> {
+    start: Element<'a, Message>,
+    end: Element<'a, Message>,
+    position: Position,
+    orientation: Orientation,
+    primary: Pane,
+    min: f32,
+    max: f32,
+    snaps: Vec<Position>,
+    width: Length,
+    height: Length,
+    on_reposition: Option<Box<dyn Fn(Position) -> Message + 'a>>,
+}
+
+impl<'a, Message> SplitPanel<'a, Message> {
+    /// Creates a new split panel with the primary pane at the given position
+    pub fn new(
+        start: impl Into<Element<'a, Message>>,
+        end: impl Into<Element<'a, Message>>,
+        position: Position,
+    ) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+            position,
+            orientation: Orientation::Horizontal,
+            primary: Pane::Start,
+            min: 0.0,
+            max: f32::INFINITY,
+            snaps: Vec::new(),
+            width: Length::Fill,
+            height: Length::Fill,
+            on_reposition: None,
+        }
+    }
+
+    /// Sets whether the panes are side by side or stacked
+    /// Default: Horizontal
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets which pane the position measures and keeps its size on resize
+    /// Default: Start
+    pub fn primary(mut self, primary: Pane) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Sets the minimum size of the primary pane (in pixels)
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum size of the primary pane (in pixels)
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the positions the divider snaps to while dragged
+    pub fn snaps(mut self, snaps: impl IntoIterator<Item = Position>) -> Self {
+        self.snaps = snaps.into_iter().collect();
+        self
+    }
+
+    /// Sets the width of the panel
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the panel
+    /// Default: Fill
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the callback invoked with the new position when the divider is moved
+    pub fn on_reposition<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Position) -> Message,
+    {
+        self.on_reposition = Some(Box::new(f));
+        self
+    }
+}
+
+impl<'a, Message> From<SplitPanel<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(panel: SplitPanel<'a, Message>) -> Self {
+        let mut split = Split::new(panel.start, panel.end, panel.position)
+            .orientation(panel.orientation)
+            .primary(panel.primary)
+            .min(panel.min)
+            .max(panel.max)
+            .snaps(panel.snaps)
+            .class(SplitPanelStyleClass::Default);
+
+        if let Some(on_reposition) = panel.on_reposition {
+            split = split.on_reposition(on_reposition);
+        }
+
+        container(split)
+            .width(panel.width)
+            .height(panel.height)
+            .into()
+    }
+}
+
+/// Helper function to create a split panel
+pub fn split_panel<'a, Message>(
+    start: impl Into<Element<'a, Message>>,
+    end: impl Into<Element<'a, Message>>,
+    position: Position,
+) -> SplitPanel<'a, Message> {
+    SplitPanel::new(start, end, position)
+}
@@ -0,0 +1,504 @@
+use crate::{
+    Element,
+    theme::{
+        Theme,
+        button::{ButtonStyleClass, ButtonVariant},
+        container::ContainerStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        parts::Part,
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+    widgets::{
+        keyboard_area::KeyboardArea,
+        scroll_arrows::ScrollArrows,
+        tab_indicator::{Edge, TabIndicator},
+    },
+};
+use iced::{
+    Alignment, Length, Shadow,
+    border::Radius,
+    keyboard::{Key, key::Named},
+    widget::{self, Component, Id, component, container, mouse_area, text},
+};
+use iced_widget::{Column, Row};
+
+use super::{Direction, scrollable_with};
+
+/// Where the tabs are placed relative to the panels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Start,
+    End,
+}
+
+impl Placement {
+    fn is_vertical(self) -> bool {
+        matches!(self, Placement::Start | Placement::End)
+    }
+
+    /// The edge of the tab strip that faces the panels
+    fn edge(self) -> Edge {
+        match self {
+            Placement::Top => Edge::Bottom,
+            Placement::Bottom => Edge::Top,
+            Placement::Start => Edge::Right,
+            Placement::End => Edge::Left,
+        }
+    }
+}
+
+/// How keyboard navigation selects tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Activation {
+    /// Moving focus with the arrow keys selects the tab immediately
+    #[default]
+    Auto,
+    /// Arrow keys only move focus; Enter or Space selects the focused tab
+    Manual,
+}
+
+/// A single tab in a [`TabGroup`], linked to the [`TabPanel`] with the same name
+#[derive(Debug, Clone)]
+pub struct Tab {
+    panel: String,
+    label: String,
+    closable: bool,
+    disabled: bool,
+}
+
+impl Tab {
+    /// Creates a new tab showing `label` that activates the panel named `panel`
+    pub fn new(panel: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            panel: panel.into(),
+            label: label.into(),
+            closable: false,
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the tab shows a close button
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Sets whether the tab is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The content shown when the [`Tab`] with the same name is active
+pub struct TabPanel<'a, Message> {
+    name: String,
+    content: Element<'a, Message>,
+}
+
+impl<'a, Message> TabPanel<'a, Message> {
+    /// Creates a new panel with the given name and content
+    pub fn new(name: impl Into<String>, content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+        }
+    }
+}
+
+/// A Shoelace-style tab group component for iced
+///
+/// This component implements the features from Shoelace's tab group component:
+/// - Tabs placed on the top, bottom, start or end of the panels
+/// - Animated active tab indicator
+/// - Disabled tabs
+/// - Closable tabs with a close callback
+/// - Scroll arrows when the tabs overflow horizontally
+/// - Keyboard navigation (arrows, Home and End) with automatic or manual activation
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Tab, TabGroup, TabPanel};
+///
+/// let tabs = TabGroup::new(&state.active_tab)
+///     .tab(Tab::new("general", "General"))
+///     .tab(Tab::new("custom", "Custom").closable(true))
+///     .panel(TabPanel::new("general", text("General settings")))
+///     .panel(TabPanel::new("custom", text("Custom settings")))
+///     .on_select(Message::TabSelected)
+///     .on_close(Message::TabClosed);
+/// ```
+pub struct TabGroup<'a, Message> {
+    active: String,
+    tabs: Vec<Tab>,
+    panels: Vec<TabPanel<'a, Message>>,
+    placement: Placement,
+    activation: Activation,
+    on_select: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<'a, Message> TabGroup<'a, Message> {
+    /// Creates a new tab group with the panel named `active` shown
+    pub fn new(active: impl Into<String>) -> Self {
+        Self {
+            active: active.into(),
+            tabs: Vec::new(),
+            panels: Vec::new(),
+            placement: Placement::Top,
+            activation: Activation::Auto,
+            on_select: None,
+            on_close: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Adds a tab
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Adds a panel
+    pub fn panel(mut self, panel: TabPanel<'a, Message>) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Sets where the tabs are placed
+    /// Default: Top
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets how keyboard navigation selects tabs
+    /// Default: Auto
+    pub fn activation(mut self, activation: Activation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    /// Sets the callback invoked with the panel name when a tab is selected
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback invoked with the panel name when a tab's close button is pressed
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+/// The strip of tabs; a component so it can track keyboard focus
+struct TabNav<'a, Message> {
+    tabs: Vec<Tab>,
+    active: Option<usize>,
+    placement: Placement,
+    activation: Activation,
+    on_select: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<Message> TabNav<'_, Message> {
+    fn select(&self, index: usize) -> Option<Message> {
+        let tab = self.tabs.get(index).filter(|tab| !tab.disabled)?;
+        self.on_select.as_ref().map(|f| f(tab.panel.clone()))
+    }
+
+    /// Finds the next enabled tab from `from`, stepping by `step` and wrapping around
+    fn step_from(&self, from: usize, step: isize) -> Option<usize> {
+        let count = self.tabs.len() as isize;
+
+        (1..=count)
+            .map(|offset| (from as isize + step * offset).rem_euclid(count) as usize)
+            .find(|&index| !self.tabs[index].disabled)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Selected(usize),
+    Closed(usize),
+    KeyPressed(Key),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TabNavState {
+    focused: Option<usize>,
+}
+
+impl<'a, Message> Component<'a, Message, Theme> for TabNav<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    type State = TabNavState;
+    type Event = Event;
+
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
+        match event {
+            Event::Selected(index) => {
+                state.focused = Some(index);
+                self.select(index)
+            }
+            Event::Closed(index) => {
+                let tab = self.tabs.get(index)?;
+                self.on_close.as_ref().map(|f| f(tab.panel.clone()))
+            }
+            Event::KeyPressed(key) => {
+                if self.tabs.is_empty() {
+                    return None;
+                }
+
+                let current = state.focused.or(self.active).unwrap_or(0);
+                let (previous_key, next_key) = if self.placement.is_vertical() {
+                    (Named::ArrowUp, Named::ArrowDown)
+                } else {
+                    (Named::ArrowLeft, Named::ArrowRight)
+                };
+
+                let target = match key {
+                    Key::Named(named) if named == previous_key => self.step_from(current, -1),
+                    Key::Named(named) if named == next_key => self.step_from(current, 1),
+                    Key::Named(Named::Home) => self.step_from(self.tabs.len() - 1, 1),
+                    Key::Named(Named::End) => self.step_from(0, -1),
+                    Key::Named(Named::Enter | Named::Space) => {
+                        return self.select(current);
+                    }
+                    _ => None,
+                }?;
+
+                state.focused = Some(target);
+
+                match self.activation {
+                    Activation::Auto => self.select(target),
+                    Activation::Manual => None,
+                }
+            }
+        }
+    }
+
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let is_vertical = self.placement.is_vertical();
+        let show_focus = self.activation == Activation::Manual;
+
+        let tabs = self.tabs.iter().enumerate().map(|(index, tab)| {
+            let is_active = self.active == Some(index);
+            let is_focused = show_focus && state.focused == Some(index);
+
+            let color = if tab.disabled {
+                ColorToken::new(ColorVariant::Neutral, ColorValue::C400)
+            } else if is_active {
+                ColorToken::new(ColorVariant::Primary, ColorValue::C600)
+            } else {
+                ColorToken::new(ColorVariant::Neutral, ColorValue::C600)
+            };
+
+            let mut content = Row::new()
+                .spacing(sizes.spacing.x_small)
+                .align_y(Alignment::Center)
+                .push(
+                    text(tab.label.clone())
+                        .size(sizes.font_size.small)
+                        .line_height(sizes.line_height.dense)
+                        .font(fonts.sans)
+                        .class(TextStyleClass { color: Some(color) }),
+                );
+
+            if tab.closable {
+                content = content.push(
+                    widget::button(
+                        text("✕")
+                            .size(sizes.font_size.x_small)
+                            .line_height(1.0)
+                            .font(fonts.sans)
+                            .class(TextStyleClass { color: Some(color) }),
+                    )
+                    .padding(sizes.spacing.x3_small)
+                    .class(ButtonStyleClass {
+                        variant: ButtonVariant::Text,
+                        outline: false,
+                        border_radius: Radius::from(sizes.border_radius.medium),
+                        disabled: tab.disabled,
+                        base: Part::default(),
+                        label: Part::default(),
+                    })
+                    .on_press_maybe((!tab.disabled).then_some(Event::Closed(index))),
+                );
+            }
+
+            let content = container(content)
+                .padding([sizes.spacing.medium, sizes.spacing.large])
+                .class(ContainerStyleClass::Custom {
+                    background: None,
+                    text_color: None,
+                    border_color: is_focused
+                        .then(|| ColorToken::new(ColorVariant::Primary, ColorValue::C600)),
+                    border_width: if is_focused { 2.0 } else { 0.0 },
+                    border_radius: sizes.border_radius.medium,
+                    shadow: Shadow::default(),
+                    snap: true,
+                });
+
+            if tab.disabled {
+                content.into()
+            } else {
+                mouse_area(content)
+                    .on_press(Event::Selected(index))
+                    .interaction(iced::mouse::Interaction::Pointer)
+                    .into()
+            }
+        });
+
+        let nav: Element<'a, Event> = if is_vertical {
+            TabIndicator::new(Column::with_children(tabs), self.active)
+                .edge(self.placement.edge())
+                .into()
+        } else {
+            let id = Id::unique();
+
+            let strip = scrollable_with(
+                TabIndicator::new(Row::with_children(tabs), self.active)
+                    .edge(self.placement.edge()),
+                Direction::Horizontal,
+                sizes,
+            )
+            .id(id.clone())
+            .width(Length::Fill);
+
+            let arrow = |symbol: &'static str| {
+                container(
+                    text(symbol)
+                        .size(sizes.font_size.large)
+                        .line_height(1.0)
+                        .font(fonts.sans)
+                        .class(TextStyleClass {
+                            color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C600)),
+                        }),
+                )
+                .padding([sizes.spacing.small, sizes.spacing.x_small])
+            };
+
+            ScrollArrows::new(strip, id, arrow("‹"), arrow("›")).into()
+        };
+
+        KeyboardArea::new(nav)
+            .on_key_press(|key, _modifiers| match key {
+                Key::Named(
+                    Named::ArrowLeft
+                    | Named::ArrowRight
+                    | Named::ArrowUp
+                    | Named::ArrowDown
+                    | Named::Home
+                    | Named::End
+                    | Named::Enter
+                    | Named::Space,
+                ) => Some(Event::KeyPressed(key)),
+                _ => None,
+            })
+            .into()
+    }
+}
+
+impl<'a, Message> From<TabGroup<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(group: TabGroup<'a, Message>) -> Self {
+        let TabGroup {
+            active,
+            tabs,
+            panels,
+            placement,
+            activation,
+            on_select,
+            on_close,
+            sizes,
+            fonts,
+        } = group;
+
+        let active_index = tabs.iter().position(|tab| tab.panel == active);
+
+        let nav = component(TabNav {
+            tabs,
+            active: active_index,
+            placement,
+            activation,
+            on_select,
+            on_close,
+            sizes,
+            fonts,
+        });
+
+        // Only the active panel is built into the tree
+        let panel = panels
+            .into_iter()
+            .find(|panel| panel.name == active)
+            .map(|panel| panel.content)
+            .unwrap_or_else(|| Column::new().into());
+
+        let panel = container(panel).width(Length::Fill);
+
+        match placement {
+            Placement::Top => Column::new()
+                .push(nav)
+                .push(panel.padding([sizes.spacing.medium, 0.0]))
+                .into(),
+            Placement::Bottom => Column::new()
+                .push(panel.padding([sizes.spacing.medium, 0.0]))
+                .push(nav)
+                .into(),
+            Placement::Start => Row::new()
+                .push(container(nav).width(Length::Shrink))
+                .push(panel.padding([0.0, sizes.spacing.medium]))
+                .into(),
+            Placement::End => Row::new()
+                .push(panel.padding([0.0, sizes.spacing.medium]))
+                .push(container(nav).width(Length::Shrink))
+                .into(),
+        }
+    }
+}
+
+/// Helper function to create a tab group
+pub fn tab_group<'a, Message>(active: impl Into<String>) -> TabGroup<'a, Message>
+where
+    Message: Clone,
+{
+    TabGroup::new(active)
+}
@@ -0,0 +1,203 @@
+use crate::{
+    Element,
+    theme::{
+        badge::BadgeVariant,
+        container::ContainerStyleClass,
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
+    },
+};
+use iced::{
+    Alignment, Padding, mouse,
+    widget::{container, mouse_area, row, text},
+};
+
+/// Size variants for tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+/// A Shoelace-style tag component for iced
+///
+/// This component implements the features from Shoelace's tag component:
+/// - Variants sharing the badge palette (primary, success, neutral, warning, danger)
+/// - Multiple sizes (small, medium, large)
+/// - Pill style (fully rounded corners)
+/// - Removable mode with an ✕ button producing `on_remove`
+///
+/// Tags are plain elements, so they can be placed in a row as a free-standing
+/// tag list or inside other inputs to show selected values.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Tag;
+/// use iced_shoelace::theme::badge::BadgeVariant;
+///
+/// let tag = Tag::new("Rust")
+///     .variant(BadgeVariant::Success)
+///     .pill(true)
+///     .removable(true)
+///     .on_remove(Message::RemoveTag("Rust".into()));
+/// ```
+pub struct Tag<Message> {
+    label: String,
+    variant: BadgeVariant,
+    size: TagSize,
+    pill: bool,
+    removable: bool,
+    on_remove: Option<Message>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<Message> Tag<Message> {
+    /// Creates a new neutral tag with the given label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            variant: BadgeVariant::Neutral,
+            size: TagSize::Medium,
+            pill: false,
+            removable: false,
+            on_remove: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the tag variant
+    /// Default: Neutral
+    pub fn variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the tag size
+    pub fn size(mut self, size: TagSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets whether the tag has fully rounded corners (pill style)
+    pub fn pill(mut self, pill: bool) -> Self {
+        self.pill = pill;
+        self
+    }
+
+    /// Sets whether the tag shows a remove button
+    pub fn removable(mut self, removable: bool) -> Self {
+        self.removable = removable;
+        self
+    }
+
+    /// Sets the message produced when the remove button is pressed
+    pub fn on_remove(mut self, message: Message) -> Self {
+        self.on_remove = Some(message);
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Gets the appropriate padding based on size
+    fn get_padding(&self) -> Padding {
+        // Shoelace tags are padded 0 var(--sl-spacing-small|medium|large) horizontally
+        let sizes = self.sizes;
+        match self.size {
+            TagSize::Small => Padding::from([sizes.spacing.x3_small, sizes.spacing.small]),
+            TagSize::Medium => Padding::from([sizes.spacing.x2_small, sizes.spacing.small]),
+            TagSize::Large => Padding::from([sizes.spacing.x_small, sizes.spacing.medium]),
+        }
+    }
+
+    /// Gets the appropriate font size
+    fn get_font_size(&self) -> f32 {
+        let sizes = self.sizes;
+        match self.size {
+            TagSize::Small => sizes.font_size.x_small,
+            TagSize::Medium => sizes.font_size.small,
+            TagSize::Large => sizes.font_size.medium,
+        }
+    }
+
+    /// Gets the appropriate border radius
+    fn get_border_radius(&self) -> f32 {
+        let sizes = self.sizes;
+        if self.pill {
+            sizes.border_radius.x_large * 10.0
+        } else {
+            match self.size {
+                TagSize::Small => sizes.border_radius.small,
+                TagSize::Medium => sizes.border_radius.medium,
+                TagSize::Large => sizes.border_radius.large,
+            }
+        }
+    }
+}
+
+impl<'a, Message> From<Tag<Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(tag: Tag<Message>) -> Self {
+        let sizes = tag.sizes;
+        let fonts = tag.fonts;
+        let padding = tag.get_padding();
+        let font_size = tag.get_font_size();
+        let border_radius = tag.get_border_radius();
+
+        let mut content = row![
+            text(tag.label)
+                .size(font_size)
+                .line_height(sizes.line_height.dense)
+                .font(fonts.sans)
+        ]
+        .spacing(sizes.spacing.x_small)
+        .align_y(Alignment::Center);
+
+        // The remove button inherits the tag's text color
+        if tag.removable {
+            let remove = mouse_area(
+                text("✕")
+                    .size(font_size)
+                    .line_height(sizes.line_height.dense)
+                    .font(fonts.sans),
+            )
+            .interaction(mouse::Interaction::Pointer);
+
+            content = content.push(match tag.on_remove {
+                Some(on_remove) => remove.on_press(on_remove),
+                None => remove,
+            });
+        }
+
+        container(content)
+            .padding(padding)
+            .class(ContainerStyleClass::Tag {
+                variant: tag.variant,
+                border_radius,
+            })
+            .into()
+    }
+}
+
+/// Helper function to create a tag
+pub fn tag<Message>(label: impl Into<String>) -> Tag<Message> {
+    Tag::new(label)
+}
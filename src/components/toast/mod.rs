@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use crate::{
+    Element,
+    components::alert::Alert,
+    theme::{badge::BadgeVariant, sizes::Sizes, typography::Fonts},
+};
+use iced::{
+    Length, alignment,
+    widget::{column, container, keyed_column, stack, text},
+};
+
+/// Identifies a toast in a [`Toasts`] stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// The corner of the window toasts are stacked in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPosition {
+    TopStart,
+    #[default]
+    TopEnd,
+    BottomStart,
+    BottomEnd,
+}
+
+/// A transient notification shown as an alert in the toast stack
+#[derive(Debug, Clone)]
+pub struct Toast {
+    title: String,
+    body: Option<String>,
+    variant: BadgeVariant,
+    icon: Option<String>,
+    duration: Option<Duration>,
+    closable: bool,
+}
+
+impl Toast {
+    /// Creates a new closable toast that dismisses itself after five seconds
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            variant: BadgeVariant::Primary,
+            icon: None,
+            duration: Some(Duration::from_secs(5)),
+            closable: true,
+        }
+    }
+
+    /// Sets the text shown under the title
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the toast variant
+    pub fn variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the icon shown before the text
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets how long the toast is shown, or `None` to keep it until closed
+    /// Default: 5 seconds
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets whether the toast shows a close button
+    /// Default: true
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// A queue of toasts, owned by the application state
+///
+/// Only the first `max_visible` toasts are shown; the rest wait in the queue and
+/// appear, with a fresh countdown, as earlier toasts are dismissed.
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Toast, Toasts, toast_stack};
+///
+/// // In update
+/// state.toasts.push(Toast::new("Saved").variant(BadgeVariant::Success));
+///
+/// Message::ToastDismissed(id) => {
+///     state.toasts.dismiss(id);
+/// }
+///
+/// // In view, around the whole window content
+/// toast_stack(&state.toasts, content, theme.sizes(), theme.fonts(), Message::ToastDismissed)
+/// ```
+#[derive(Debug, Clone)]
+pub struct Toasts {
+    toasts: Vec<(ToastId, Toast)>,
+    next_id: u64,
+    max_visible: usize,
+    position: ToastPosition,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    /// Creates an empty stack showing up to three toasts at a time
+    pub fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            next_id: 0,
+            max_visible: 3,
+            position: ToastPosition::TopEnd,
+        }
+    }
+
+    /// Sets how many toasts are shown at once
+    /// Default: 3
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Sets the corner the toasts are shown in
+    /// Default: TopEnd
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Queues a toast, returning its id
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.toasts.push((id, toast));
+        id
+    }
+
+    /// Removes a toast, returning whether it was in the stack
+    pub fn dismiss(&mut self, id: ToastId) -> bool {
+        let len = self.toasts.len();
+        self.toasts.retain(|(toast_id, _)| *toast_id != id);
+        self.toasts.len() != len
+    }
+
+    /// Removes every toast
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// The number of toasts, shown or queued
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether there are no toasts
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// The number of toasts waiting for a free slot
+    pub fn queued(&self) -> usize {
+        self.toasts.len().saturating_sub(self.max_visible)
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &(ToastId, Toast)> {
+        self.toasts.iter().take(self.max_visible)
+    }
+}
+
+/// Renders `content` with the visible toasts stacked in a corner above it
+///
+/// `on_dismiss` is produced when a toast is closed or its duration runs out; the
+/// application should then call [`Toasts::dismiss`]. The toasts are laid out with
+/// `sizes`, usually `theme.sizes()`, and set in `fonts`, usually `theme.fonts()`.
+pub fn toast_stack<'a, Message>(
+    toasts: &'a Toasts,
+    content: impl Into<Element<'a, Message>>,
+    sizes: Sizes,
+    fonts: Fonts,
+    on_dismiss: impl Fn(ToastId) -> Message + 'a,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    if toasts.is_empty() {
+        return content.into();
+    }
+
+    // Keyed so each toast keeps its own countdown when the ones above it are dismissed
+    let alerts = toasts.visible().map(|(id, toast)| {
+        let font_size = sizes.font_size;
+
+        let mut message = column![
+            text(toast.title.clone())
+                .size(font_size.medium)
+                .font(fonts.sans)
+        ]
+        .spacing(sizes.spacing.x2_small);
+
+        if let Some(body) = &toast.body {
+            message = message.push(text(body.clone()).size(font_size.medium).font(fonts.sans));
+        }
+
+        let mut alert = Alert::new(message)
+            .variant(toast.variant)
+            .closable(toast.closable)
+            .countdown(true)
+            .toast(true)
+            .on_close(on_dismiss(*id))
+            .sizes(sizes)
+            .fonts(fonts);
+
+        if let Some(icon) = &toast.icon {
+            alert = alert.icon(text(icon.clone()).size(font_size.x_large).font(fonts.sans));
+        }
+
+        if let Some(duration) = toast.duration {
+            alert = alert.duration(duration);
+        }
+
+        (id.0, Element::from(alert))
+    });
+
+    let mut stack_column = keyed_column(alerts).spacing(sizes.spacing.small);
+
+    let queued = toasts.queued();
+    if queued > 0 {
+        stack_column = stack_column.push(
+            u64::MAX,
+            container(
+                text(format!("+{queued} more"))
+                    .size(sizes.font_size.small)
+                    .font(fonts.sans),
+            )
+            .width(Length::Fill)
+            .align_x(alignment::Horizontal::Right)
+            .into(),
+        );
+    }
+
+    // Shoelace's toast stack is 28rem wide with --sl-spacing-medium around it
+    let (align_x, align_y) = match toasts.position {
+        ToastPosition::TopStart => (alignment::Horizontal::Left, alignment::Vertical::Top),
+        ToastPosition::TopEnd => (alignment::Horizontal::Right, alignment::Vertical::Top),
+        ToastPosition::BottomStart => (alignment::Horizontal::Left, alignment::Vertical::Bottom),
+        ToastPosition::BottomEnd => (alignment::Horizontal::Right, alignment::Vertical::Bottom),
+    };
+
+    let overlay = container(container(stack_column).width(448))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(sizes.spacing.medium)
+        .align_x(align_x)
+        .align_y(align_y);
+
+    stack![content.into(), overlay].into()
+}
@@ -5,7 +5,8 @@ use crate::{
     Element,
     theme::{
         container::ContainerStyleClass,
-        sizes::{FONT_SIZE, SPACING},
+        sizes::{SIZES, Sizes},
+        typography::{FONTS, Fonts},
     },
     widgets::tooltip::Position,
 };
@@ -122,6 +123,10 @@ pub struct Tooltip<'a, Message> {
     disabled: bool,
     hoist: bool,
     duration: Duration,
+    trigger: Trigger,
+    open: bool,
+    sizes: Sizes,
+    fonts: Fonts,
 }
 
 impl<'a, Message> Tooltip<'a, Message> {
@@ -136,6 +141,10 @@ impl<'a, Message> Tooltip<'a, Message> {
             disabled: false,
             hoist: false,
             duration: Duration::from_millis(500),
+            trigger: Trigger::Hover,
+            open: false,
+            sizes: SIZES,
+            fonts: FONTS,
         }
     }
 
@@ -179,6 +188,35 @@ impl<'a, Message> Tooltip<'a, Message> {
         self.duration = duration;
         self
     }
+
+    /// Sets how the tooltip is triggered
+    /// Default: Hover
+    /// Note: Focus and Click triggers currently behave like Hover
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Sets whether the tooltip is open when using the Manual trigger
+    /// Ignored for other triggers
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
 }
 
 impl<'a, Message> From<Tooltip<'a, Message>> for Element<'a, Message>
@@ -189,12 +227,16 @@ where
         if t.disabled {
             t.child
         } else {
+            let sizes = t.sizes;
+
             // Create tooltip with Shoelace styling
-            let tooltip_text = text(t.content.clone()).size(FONT_SIZE.small);
+            let tooltip_text = text(t.content.clone())
+                .size(sizes.font_size.small)
+                .font(t.fonts.sans);
 
             // Wrap in a container with Shoelace tooltip styling
             let tooltip_container = container(tooltip_text)
-                .padding(Padding::from([SPACING.x_small, SPACING.small]))
+                .padding(Padding::from([sizes.spacing.x_small, sizes.spacing.small]))
                 .class(ContainerStyleClass::Tooltip);
 
             let mut tooltip_widget =
@@ -206,6 +248,11 @@ where
             // When hoist is true, we don't snap to viewport to allow overflow
             tooltip_widget = tooltip_widget.snap_within_viewport(!t.hoist);
 
+            // Manual tooltips ignore hover and follow the open flag
+            if t.trigger == Trigger::Manual {
+                tooltip_widget = tooltip_widget.open(t.open);
+            }
+
             tooltip_widget.into()
         }
     }
@@ -0,0 +1,800 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::{
+    Element,
+    components::{checkbox::CheckState, checkbox::Checkbox, spinner::Spinner},
+    theme::{
+        Theme,
+        container::ContainerStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts},
+    },
+    widgets::keyboard_area::KeyboardArea,
+};
+use iced::{
+    Alignment, Length, Shadow,
+    keyboard::{Key, key::Named},
+    widget::{Component, component, container, mouse_area, rule, text},
+};
+use iced_widget::{Column, Row};
+
+/// How long typed characters are remembered for typeahead
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How items in a tree are selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TreeSelection {
+    /// One item at a time
+    #[default]
+    Single,
+    /// Any number of items through checkboxes; checking a parent checks its children
+    Multiple,
+    /// One leaf at a time; pressing a parent expands or collapses it
+    Leaf,
+}
+
+/// An item in a [`TreeState`], identified by a key of type `K`
+#[derive(Debug, Clone)]
+pub struct TreeNode<K> {
+    key: K,
+    label: String,
+    children: Vec<TreeNode<K>>,
+    lazy: bool,
+    disabled: bool,
+}
+
+impl<K> TreeNode<K> {
+    /// Creates a new leaf node
+    pub fn new(key: K, label: impl Into<String>) -> Self {
+        Self {
+            key,
+            label: label.into(),
+            children: Vec::new(),
+            lazy: false,
+            disabled: false,
+        }
+    }
+
+    /// Adds a child node
+    pub fn child(mut self, child: TreeNode<K>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds several child nodes
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeNode<K>>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Marks the node as having children that are loaded the first time it is expanded
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Sets whether the node can be selected
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the key of the node
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+/// An interaction with a [`Tree`], to be applied with [`TreeState::update`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeEvent<K> {
+    Expanded(K),
+    Collapsed(K),
+    /// The item was pressed, or Enter or Space was pressed while it had focus
+    Selected(K),
+}
+
+/// The nodes of a tree together with their expansion and selection, owned by the
+/// application state
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::{Tree, TreeNode, TreeState};
+///
+/// // In update
+/// Message::Tree(event) => {
+///     if let Some(key) = state.files.update(event) {
+///         // `key` was expanded for the first time; load its children
+///         return Task::perform(list_directory(key.clone()), move |children| {
+///             Message::Loaded(key.clone(), children)
+///         });
+///     }
+/// }
+/// Message::Loaded(key, children) => state.files.set_children(&key, children),
+///
+/// // In view
+/// Tree::new(&state.files).on_event(Message::Tree)
+/// ```
+#[derive(Debug, Clone)]
+pub struct TreeState<K> {
+    nodes: Vec<TreeNode<K>>,
+    selection: TreeSelection,
+    expanded: HashSet<K>,
+    selected: HashSet<K>,
+    loading: HashSet<K>,
+}
+
+impl<K> TreeState<K>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Creates a collapsed tree with nothing selected
+    pub fn new(nodes: impl IntoIterator<Item = TreeNode<K>>) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            selection: TreeSelection::Single,
+            expanded: HashSet::new(),
+            selected: HashSet::new(),
+            loading: HashSet::new(),
+        }
+    }
+
+    /// Sets how items are selected
+    /// Default: Single
+    pub fn selection(mut self, selection: TreeSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Applies an event produced by a [`Tree`]
+    ///
+    /// Returns the key of a lazy node that was expanded for the first time; the
+    /// application should load its children and pass them to [`TreeState::set_children`].
+    /// The node shows a spinner until then.
+    pub fn update(&mut self, event: TreeEvent<K>) -> Option<K> {
+        match event {
+            TreeEvent::Expanded(key) => self.expand(key),
+            TreeEvent::Collapsed(key) => {
+                self.expanded.remove(&key);
+                None
+            }
+            TreeEvent::Selected(key) => {
+                let node = self.find(&key)?;
+
+                if node.disabled {
+                    return None;
+                }
+
+                match self.selection {
+                    TreeSelection::Single => {
+                        self.selected.clear();
+                        self.selected.insert(key);
+                        None
+                    }
+                    TreeSelection::Leaf if node.is_expandable() => {
+                        if self.expanded.contains(&key) {
+                            self.expanded.remove(&key);
+                            None
+                        } else {
+                            self.expand(key)
+                        }
+                    }
+                    TreeSelection::Leaf => {
+                        self.selected.clear();
+                        self.selected.insert(key);
+                        None
+                    }
+                    TreeSelection::Multiple => {
+                        let checked = !self.check_state_of(node).is_checked();
+                        self.set_checked(&key, checked);
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    /// Replaces the children of a node, typically once its lazy load has finished
+    ///
+    /// In multiple selection, children of a checked node start out checked.
+    pub fn set_children(&mut self, key: &K, children: Vec<TreeNode<K>>) {
+        self.loading.remove(key);
+
+        let Some(node) = find_mut(&mut self.nodes, key) else {
+            return;
+        };
+
+        node.children = children;
+        node.lazy = false;
+
+        if self.selection == TreeSelection::Multiple && self.selected.contains(key) {
+            self.set_checked(key, true);
+        }
+    }
+
+    /// Returns whether a node is expanded
+    pub fn is_expanded(&self, key: &K) -> bool {
+        self.expanded.contains(key)
+    }
+
+    /// Returns whether the children of a node are being loaded
+    pub fn is_loading(&self, key: &K) -> bool {
+        self.loading.contains(key)
+    }
+
+    /// Returns whether a node is selected
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    /// The keys of the selected nodes, in no particular order
+    pub fn selected(&self) -> impl Iterator<Item = &K> {
+        self.selected.iter()
+    }
+
+    /// Returns the checkbox state of a node; parents are derived from their children
+    pub fn check_state(&self, key: &K) -> CheckState {
+        self.find(key)
+            .map(|node| self.check_state_of(node))
+            .unwrap_or_default()
+    }
+
+    fn expand(&mut self, key: K) -> Option<K> {
+        let node = self.find(&key)?;
+        let needs_load = node.lazy && !self.loading.contains(&key);
+
+        self.expanded.insert(key.clone());
+
+        if needs_load {
+            self.loading.insert(key.clone());
+            Some(key)
+        } else {
+            None
+        }
+    }
+
+    fn find(&self, key: &K) -> Option<&TreeNode<K>> {
+        find(&self.nodes, key)
+    }
+
+    fn check_state_of(&self, node: &TreeNode<K>) -> CheckState {
+        let mut children = node
+            .children
+            .iter()
+            .filter(|child| !child.disabled)
+            .peekable();
+
+        if self.selection == TreeSelection::Multiple && children.peek().is_some() {
+            CheckState::from_states(children.map(|child| self.check_state_of(child)))
+        } else {
+            CheckState::from(self.selected.contains(&node.key))
+        }
+    }
+
+    /// Checks or unchecks a node and its descendants, then updates its ancestors
+    fn set_checked(&mut self, key: &K, checked: bool) {
+        let Some(path) = path_to(&self.nodes, key) else {
+            return;
+        };
+
+        if let Some(node) = self.find(key) {
+            let mut keys = Vec::new();
+            collect_enabled(node, &mut keys);
+
+            for key in keys {
+                if checked {
+                    self.selected.insert(key);
+                } else {
+                    self.selected.remove(&key);
+                }
+            }
+        }
+
+        // Walk up from the parent so each ancestor sees its updated children
+        for ancestor in path.iter().rev().skip(1) {
+            let state = self
+                .find(ancestor)
+                .map(|node| self.check_state_of(node))
+                .unwrap_or_default();
+
+            if state.is_checked() {
+                self.selected.insert(ancestor.clone());
+            } else {
+                self.selected.remove(ancestor);
+            }
+        }
+    }
+
+    /// Flattens the expanded part of the tree into rows
+    fn rows(&self) -> Vec<TreeRow<K>> {
+        let mut rows = Vec::new();
+        self.push_rows(&self.nodes, 0, &mut rows);
+        rows
+    }
+
+    fn push_rows(&self, nodes: &[TreeNode<K>], depth: usize, rows: &mut Vec<TreeRow<K>>) {
+        for node in nodes {
+            let expanded = self.expanded.contains(&node.key);
+
+            rows.push(TreeRow {
+                key: node.key.clone(),
+                label: node.label.clone(),
+                depth,
+                expandable: node.is_expandable(),
+                expanded,
+                loading: self.loading.contains(&node.key),
+                disabled: node.disabled,
+                check: self.check_state_of(node),
+            });
+
+            if expanded {
+                self.push_rows(&node.children, depth + 1, rows);
+            }
+        }
+    }
+}
+
+fn find<'a, K: PartialEq>(nodes: &'a [TreeNode<K>], key: &K) -> Option<&'a TreeNode<K>> {
+    nodes.iter().find_map(|node| {
+        if node.key == *key {
+            Some(node)
+        } else {
+            find(&node.children, key)
+        }
+    })
+}
+
+fn find_mut<'a, K: PartialEq>(
+    nodes: &'a mut [TreeNode<K>],
+    key: &K,
+) -> Option<&'a mut TreeNode<K>> {
+    for node in nodes {
+        if node.key == *key {
+            return Some(node);
+        }
+
+        if let Some(found) = find_mut(&mut node.children, key) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Returns the keys from a root node down to and including `key`
+fn path_to<K: Clone + PartialEq>(nodes: &[TreeNode<K>], key: &K) -> Option<Vec<K>> {
+    nodes.iter().find_map(|node| {
+        if node.key == *key {
+            return Some(vec![node.key.clone()]);
+        }
+
+        let mut path = path_to(&node.children, key)?;
+        path.insert(0, node.key.clone());
+        Some(path)
+    })
+}
+
+fn collect_enabled<K: Clone>(node: &TreeNode<K>, keys: &mut Vec<K>) {
+    if node.disabled {
+        return;
+    }
+
+    keys.push(node.key.clone());
+
+    for child in &node.children {
+        collect_enabled(child, keys);
+    }
+}
+
+/// A visible row of the tree
+#[derive(Debug, Clone)]
+struct TreeRow<K> {
+    key: K,
+    label: String,
+    depth: usize,
+    expandable: bool,
+    expanded: bool,
+    loading: bool,
+    disabled: bool,
+    check: CheckState,
+}
+
+/// A Shoelace-style tree component for iced
+///
+/// This component implements the features from Shoelace's tree component:
+/// - Expandable nodes with expand/collapse icons and indentation guides
+/// - Single, multiple (checkboxes) and leaf-only selection
+/// - Children loaded lazily, with a spinner while loading
+/// - Keyboard navigation: arrow keys, Home, End, Enter, Space and typeahead
+///
+/// The nodes, expansion and selection live in a [`TreeState`] owned by the
+/// application; the tree reports [`TreeEvent`]s to apply to it.
+pub struct Tree<'a, K, Message> {
+    state: &'a TreeState<K>,
+    indent: f32,
+    width: Length,
+    on_event: Option<Box<dyn Fn(TreeEvent<K>) -> Message + 'a>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<'a, K, Message> Tree<'a, K, Message> {
+    /// Creates a new tree showing the given state
+    pub fn new(state: &'a TreeState<K>) -> Self {
+        Self {
+            state,
+            indent: 20.0,
+            width: Length::Fill,
+            on_event: None,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets how far each level is indented (in pixels)
+    /// Default: 20
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the width of the tree
+    /// Default: Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the callback invoked with each interaction
+    pub fn on_event<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(TreeEvent<K>) -> Message,
+    {
+        self.on_event = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+}
+
+/// The rows of the tree; a component so it can track keyboard focus
+struct TreeView<'a, K, Message> {
+    rows: Vec<TreeRow<K>>,
+    selection: TreeSelection,
+    indent: f32,
+    width: Length,
+    on_event: Option<Box<dyn Fn(TreeEvent<K>) -> Message + 'a>>,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl<K, Message> TreeView<'_, K, Message>
+where
+    K: Clone,
+{
+    fn emit(&self, event: TreeEvent<K>) -> Option<Message> {
+        self.on_event.as_ref().map(|f| f(event))
+    }
+
+    /// Finds the next enabled row after `from`, stepping by `step` without wrapping
+    fn step_from(&self, from: isize, step: isize) -> Option<usize> {
+        let mut index = from + step;
+
+        while (0..self.rows.len() as isize).contains(&index) {
+            if !self.rows[index as usize].disabled {
+                return Some(index as usize);
+            }
+            index += step;
+        }
+
+        None
+    }
+
+    /// Finds the nearest row above `index` that is one level shallower
+    fn parent_of(&self, index: usize) -> Option<usize> {
+        let depth = self.rows[index].depth;
+        (0..index).rev().find(|&i| self.rows[i].depth < depth)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Pressed(usize),
+    Toggled(usize),
+    KeyPressed(Key),
+}
+
+pub struct TreeViewState<K> {
+    focused: Option<K>,
+    /// Whether focus was last moved with the keyboard, which shows the focus ring
+    keyboard: bool,
+    typeahead: String,
+    typed_at: Option<Instant>,
+}
+
+impl<K> Default for TreeViewState<K> {
+    fn default() -> Self {
+        Self {
+            focused: None,
+            keyboard: false,
+            typeahead: String::new(),
+            typed_at: None,
+        }
+    }
+}
+
+impl<'a, K, Message> Component<'a, Message, Theme> for TreeView<'a, K, Message>
+where
+    K: Clone + PartialEq + 'static,
+    Message: Clone + 'a,
+{
+    type State = TreeViewState<K>;
+    type Event = Event;
+
+    fn update(&mut self, state: &mut Self::State, event: Self::Event) -> Option<Message> {
+        match event {
+            Event::Pressed(index) => {
+                let row = self.rows.get(index)?;
+                state.focused = Some(row.key.clone());
+                state.keyboard = false;
+                self.emit(TreeEvent::Selected(row.key.clone()))
+            }
+            Event::Toggled(index) => {
+                let row = self.rows.get(index)?;
+                state.focused = Some(row.key.clone());
+                state.keyboard = false;
+
+                if row.expanded {
+                    self.emit(TreeEvent::Collapsed(row.key.clone()))
+                } else {
+                    self.emit(TreeEvent::Expanded(row.key.clone()))
+                }
+            }
+            Event::KeyPressed(key) => {
+                if self.rows.is_empty() {
+                    return None;
+                }
+
+                state.keyboard = true;
+
+                let current = state
+                    .focused
+                    .as_ref()
+                    .and_then(|focused| self.rows.iter().position(|row| row.key == *focused))
+                    .unwrap_or(0);
+                let row = &self.rows[current];
+
+                let target = match key {
+                    Key::Named(Named::ArrowDown) => self.step_from(current as isize, 1),
+                    Key::Named(Named::ArrowUp) => self.step_from(current as isize, -1),
+                    Key::Named(Named::ArrowRight) => {
+                        if row.expandable && !row.expanded {
+                            return self.emit(TreeEvent::Expanded(row.key.clone()));
+                        }
+
+                        // Move into the first child once it is shown
+                        self.rows
+                            .get(current + 1)
+                            .filter(|next| row.expanded && next.depth > row.depth)
+                            .map(|_| current + 1)
+                    }
+                    Key::Named(Named::ArrowLeft) => {
+                        if row.expanded {
+                            return self.emit(TreeEvent::Collapsed(row.key.clone()));
+                        }
+
+                        self.parent_of(current)
+                    }
+                    Key::Named(Named::Home) => self.step_from(-1, 1),
+                    Key::Named(Named::End) => self.step_from(self.rows.len() as isize, -1),
+                    Key::Named(Named::Enter | Named::Space) => {
+                        if row.disabled {
+                            return None;
+                        }
+
+                        return self.emit(TreeEvent::Selected(row.key.clone()));
+                    }
+                    Key::Character(characters) => {
+                        let now = Instant::now();
+
+                        if state
+                            .typed_at
+                            .is_none_or(|typed_at| now.duration_since(typed_at) > TYPEAHEAD_TIMEOUT)
+                        {
+                            state.typeahead.clear();
+                        }
+
+                        state.typeahead.push_str(&characters.to_lowercase());
+                        state.typed_at = Some(now);
+
+                        // Search from the current row so repeating a letter cycles through matches
+                        let start = if state.typeahead.chars().count() == 1 {
+                            current + 1
+                        } else {
+                            current
+                        };
+
+                        (0..self.rows.len())
+                            .map(|offset| (start + offset) % self.rows.len())
+                            .find(|&index| {
+                                let row = &self.rows[index];
+                                !row.disabled
+                                    && row.label.to_lowercase().starts_with(&state.typeahead)
+                            })
+                    }
+                    _ => None,
+                }?;
+
+                state.focused = Some(self.rows[target].key.clone());
+                None
+            }
+        }
+    }
+
+    fn view(&self, state: &Self::State) -> Element<'a, Self::Event> {
+        let sizes = self.sizes;
+        let fonts = self.fonts;
+        let indent = self.indent;
+        // Every row has the same height, so indentation guides line up between rows
+        let row_height = sizes.input_height.small + sizes.spacing.x_small;
+
+        let rows = self.rows.iter().enumerate().map(|(index, row)| {
+            let is_focused =
+                state.keyboard && state.focused.as_ref().is_some_and(|key| *key == row.key);
+            let is_selected = self.selection != TreeSelection::Multiple && row.check.is_checked();
+
+            let mut line = Row::new().align_y(Alignment::Center).height(Length::Fill);
+
+            // One guide per level, centered under the expand icon of the ancestor
+            for _ in 0..row.depth {
+                line = line.push(
+                    container(rule::vertical(1))
+                        .center_x(indent)
+                        .height(Length::Fill),
+                );
+            }
+
+            let expander: Element<'a, Event> = if row.loading {
+                container(Spinner::new().size(sizes.font_size.small).track_color(None))
+                    .center(indent)
+                    .into()
+            } else if row.expandable {
+                let chevron = if row.expanded { "▾" } else { "▸" };
+
+                mouse_area(
+                    container(
+                        text(chevron)
+                            .size(sizes.font_size.medium)
+                            .line_height(1.0)
+                            .font(fonts.sans)
+                            .class(TextStyleClass {
+                                color: Some(ColorToken::new(
+                                    ColorVariant::Neutral,
+                                    ColorValue::C500,
+                                )),
+                            }),
+                    )
+                    .center(indent),
+                )
+                .on_press(Event::Toggled(index))
+                .interaction(iced::mouse::Interaction::Pointer)
+                .into()
+            } else {
+                container(Column::new()).width(indent).into()
+            };
+
+            line = line.push(expander);
+
+            if self.selection == TreeSelection::Multiple {
+                line = line.push(
+                    Checkbox::with_state("", row.check)
+                        .disabled(row.disabled)
+                        .on_toggle(move |_| Event::Pressed(index)),
+                );
+            }
+
+            line = line.push(
+                text(row.label.clone())
+                    .size(sizes.font_size.medium)
+                    .line_height(sizes.line_height.dense)
+                    .font(fonts.sans)
+                    .width(Length::Fill)
+                    .class(TextStyleClass {
+                        color: Some(if row.disabled {
+                            ColorToken::new(ColorVariant::Neutral, ColorValue::C400)
+                        } else {
+                            ColorToken::new(ColorVariant::Neutral, ColorValue::C700)
+                        }),
+                    }),
+            );
+
+            let content = container(line)
+                .width(Length::Fill)
+                .height(row_height)
+                .padding([0.0, sizes.spacing.x_small])
+                .class(ContainerStyleClass::Custom {
+                    background: is_selected
+                        .then(|| ColorToken::new(ColorVariant::Neutral, ColorValue::C100)),
+                    text_color: None,
+                    border_color: is_focused
+                        .then(|| ColorToken::new(ColorVariant::Primary, ColorValue::C600)),
+                    border_width: if is_focused { 2.0 } else { 0.0 },
+                    border_radius: sizes.border_radius.medium,
+                    shadow: Shadow::default(),
+                    snap: true,
+                });
+
+            if row.disabled {
+                content.into()
+            } else {
+                mouse_area(content)
+                    .on_press(Event::Pressed(index))
+                    .interaction(iced::mouse::Interaction::Pointer)
+                    .into()
+            }
+        });
+
+        KeyboardArea::new(Column::with_children(rows).width(self.width))
+            .on_key_press(|key, modifiers| match key {
+                Key::Named(
+                    Named::ArrowLeft
+                    | Named::ArrowRight
+                    | Named::ArrowUp
+                    | Named::ArrowDown
+                    | Named::Home
+                    | Named::End
+                    | Named::Enter
+                    | Named::Space,
+                ) => Some(Event::KeyPressed(key)),
+                Key::Character(_) if !modifiers.command() && !modifiers.alt() => {
+                    Some(Event::KeyPressed(key))
+                }
+                _ => None,
+            })
+            .into()
+    }
+}
+
+impl<'a, K, Message> From<Tree<'a, K, Message>> for Element<'a, Message>
+where
+    K: Clone + Eq + Hash + 'static,
+    Message: Clone + 'a,
+{
+    fn from(tree: Tree<'a, K, Message>) -> Self {
+        component(TreeView {
+            rows: tree.state.rows(),
+            selection: tree.state.selection,
+            indent: tree.indent,
+            width: tree.width,
+            on_event: tree.on_event,
+            sizes: tree.sizes,
+            fonts: tree.fonts,
+        })
+    }
+}
+
+/// Helper function to create a tree
+pub fn tree<'a, K, Message>(state: &'a TreeState<K>) -> Tree<'a, K, Message> {
+    Tree::new(state)
+}
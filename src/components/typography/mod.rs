@@ -0,0 +1,184 @@
+use crate::{
+    Element,
+    theme::{
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        sizes::{SIZES, Sizes},
+        text::TextStyleClass,
+        typography::{FONTS, Fonts, Role},
+    },
+};
+use iced::{Length, widget::text};
+
+/// Text styled by its semantic role
+///
+/// The role picks the font size, weight and line height tokens, and the sans or
+/// mono family of the theme's fonts, so text follows the theme instead of
+/// hard-coded sizes:
+/// - Display and H1-H6 for headings, see [`Heading`]
+/// - Body for running text (default)
+/// - Caption for small, muted supporting text
+/// - Code for text in the mono family
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Text;
+/// use iced_shoelace::theme::typography::Role;
+///
+/// let body = Text::new("Components follow the Shoelace design system.");
+/// let hint = Text::new("Changes are saved automatically").role(Role::Caption);
+/// let code = Text::new("cargo run").role(Role::Code);
+///
+/// // Text of a theme with its own families and sizes
+/// let branded = Text::new("Welcome").fonts(theme.fonts()).sizes(theme.sizes());
+/// ```
+pub struct Text {
+    content: String,
+    role: Role,
+    color: Option<ColorToken>,
+    width: Length,
+    fonts: Fonts,
+    sizes: Sizes,
+}
+
+impl Text {
+    /// Creates body text with the given content
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            role: Role::Body,
+            color: None,
+            width: Length::Shrink,
+            fonts: FONTS,
+            sizes: SIZES,
+        }
+    }
+
+    /// Sets the semantic role
+    /// Default: body
+    pub fn role(mut self, role: Role) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Sets the text color
+    /// Default: inherited, or neutral-600 for captions
+    pub fn color(mut self, color: ColorToken) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the width of the text
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+}
+
+impl<'a, Message: 'a> From<Text> for Element<'a, Message> {
+    fn from(value: Text) -> Self {
+        let sizes = value.sizes;
+        let role = value.role;
+
+        let color = value.color.or_else(|| {
+            (role == Role::Caption)
+                .then(|| ColorToken::new(ColorVariant::Neutral, ColorValue::C600))
+        });
+
+        text(value.content)
+            .size(role.size(&sizes.font_size))
+            .line_height(role.line_height(&sizes.line_height))
+            .font(role.font(&value.fonts, &sizes.font_weight))
+            .width(value.width)
+            .class(TextStyleClass { color })
+            .into()
+    }
+}
+
+/// A heading, from display size down to H6
+///
+/// ## Example
+///
+/// ```rust
+/// use iced_shoelace::components::Heading;
+/// use iced_shoelace::theme::typography::Role;
+///
+/// let title = Heading::new("Dialogs");
+/// let section = Heading::new("Basic Dialog").role(Role::H2);
+/// ```
+pub struct Heading {
+    text: Text,
+}
+
+impl Heading {
+    /// Creates an H1 heading with the given content
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            text: Text::new(content).role(Role::H1),
+        }
+    }
+
+    /// Sets the heading level
+    /// Default: H1
+    /// Roles that aren't headings, such as Body, fall back to H6
+    pub fn role(mut self, role: Role) -> Self {
+        self.text = self
+            .text
+            .role(if role.is_heading() { role } else { Role::H6 });
+        self
+    }
+
+    /// Sets the heading color
+    /// Default: inherited
+    pub fn color(mut self, color: ColorToken) -> Self {
+        self.text = self.text.color(color);
+        self
+    }
+
+    /// Sets the width of the heading
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text = self.text.width(width);
+        self
+    }
+
+    /// Sets the font families, usually `theme.fonts()`
+    /// Default: the fonts of the built-in themes
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.text = self.text.fonts(fonts);
+        self
+    }
+
+    /// Sets the size tokens, usually `theme.sizes()`
+    /// Default: the built-in sizes
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.text = self.text.sizes(sizes);
+        self
+    }
+}
+
+impl<'a, Message: 'a> From<Heading> for Element<'a, Message> {
+    fn from(heading: Heading) -> Self {
+        heading.text.into()
+    }
+}
+
+/// Creates an H1 heading with the given content
+///
+/// This is a convenience function equivalent to `Heading::new(content)`.
+pub fn heading(content: impl Into<String>) -> Heading {
+    Heading::new(content)
+}
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use iced::alignment;
+use iced_widget::{Row, column, text};
+
+use crate::components::Heading;
+use crate::components::button::Button;
+use crate::components::{Alert, Toast, ToastId, Toasts};
+use crate::theme::badge::BadgeVariant;
+use crate::theme::button::ButtonVariant;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct AlertState {
+    pub closable_open: bool,
+    pub timed_open: bool,
+    pub toasts: Toasts,
+    pub toast_count: usize,
+}
+
+impl Default for AlertState {
+    fn default() -> Self {
+        Self {
+            closable_open: true,
+            timed_open: false,
+            toasts: Toasts::new(),
+            toast_count: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AlertMessage {
+    CloseClosable,
+    ShowClosable,
+    ShowTimed,
+    CloseTimed,
+    PushToast(BadgeVariant),
+    ToastDismissed(ToastId),
+}
+
+pub fn handle_alert_message(state: &mut AlertState, message: AlertMessage) {
+    match message {
+        AlertMessage::CloseClosable => state.closable_open = false,
+        AlertMessage::ShowClosable => state.closable_open = true,
+        AlertMessage::ShowTimed => state.timed_open = true,
+        AlertMessage::CloseTimed => state.timed_open = false,
+        AlertMessage::PushToast(variant) => {
+            state.toast_count += 1;
+
+            let (icon, title) = match variant {
+                BadgeVariant::Primary => ("ℹ", "This is super informative"),
+                BadgeVariant::Success => ("✓", "Your changes have been saved"),
+                BadgeVariant::Neutral => ("⚙", "Settings have been updated"),
+                BadgeVariant::Warning => ("⚠", "Your session is about to expire"),
+                BadgeVariant::Danger => ("✕", "Your account has been deleted"),
+            };
+
+            state.toasts.push(
+                Toast::new(title)
+                    .body(format!("Toast #{}", state.toast_count))
+                    .variant(variant)
+                    .icon(icon),
+            );
+        }
+        AlertMessage::ToastDismissed(id) => {
+            state.toasts.dismiss(id);
+        }
+    }
+}
+
+pub fn view(state: &AlertState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Alerts").sizes(sizes);
+    let description =
+        text("Alerts are used to display important messages inline or as toast notifications")
+            .size(14);
+
+    // Variants
+    let variants_title = Heading::new("Variants").sizes(sizes).role(Role::H2);
+    let variants = column![
+        Alert::new(text(
+            "This is super informative. You can tell by how pretty the alert is."
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Primary)
+        .icon(text("ℹ").size(20)),
+        Alert::new(text(
+            "Your changes have been saved. You can safely exit the app now."
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Success)
+        .icon(text("✓").size(20)),
+        Alert::new(text(
+            "Your settings have been updated. Some settings will take effect on restart."
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Neutral)
+        .icon(text("⚙").size(20)),
+        Alert::new(text(
+            "Your session has ended. Please login again to continue."
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Warning)
+        .icon(text("⚠").size(20)),
+        Alert::new(text(
+            "Your account has been deleted. We're very sorry to see you go!"
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Danger)
+        .icon(text("✕").size(20)),
+    ]
+    .spacing(12);
+
+    // Closable
+    let closable_title = Heading::new("Closable").sizes(sizes).role(Role::H2);
+    let closable: Element<'_, Message> = if state.closable_open {
+        Alert::new(text("You can close this alert any time!"))
+            .sizes(sizes)
+            .icon(text("ℹ").size(20))
+            .closable(true)
+            .on_close(Message::Alert(AlertMessage::CloseClosable))
+            .into()
+    } else {
+        Button::new("Show Alert")
+            .sizes(sizes)
+            .on_press(Message::Alert(AlertMessage::ShowClosable))
+            .into()
+    };
+
+    // Duration and countdown
+    let duration_title = Heading::new("Duration and Countdown")
+        .sizes(sizes)
+        .role(Role::H2);
+    let duration_desc = text("The countdown pauses while the pointer is over the alert").size(14);
+    let timed: Element<'_, Message> = if state.timed_open {
+        Alert::new(text(
+            "This alert will automatically hide itself after five seconds.",
+        ))
+        .sizes(sizes)
+        .variant(BadgeVariant::Warning)
+        .icon(text("⏱").size(20))
+        .closable(true)
+        .duration(Duration::from_secs(5))
+        .countdown(true)
+        .on_close(Message::Alert(AlertMessage::CloseTimed))
+        .into()
+    } else {
+        Button::new("Show Alert")
+            .sizes(sizes)
+            .on_press(Message::Alert(AlertMessage::ShowTimed))
+            .into()
+    };
+
+    // Toasts
+    let toasts_title = Heading::new("Toast Notifications")
+        .sizes(sizes)
+        .role(Role::H2);
+    let toasts_desc = text(format!(
+        "Toasts stack in the top right corner; up to three are shown at once ({} queued)",
+        state.toasts.queued()
+    ))
+    .size(14);
+    let toast_buttons = Row::with_children([
+        toast_button(
+            "Primary",
+            ButtonVariant::Primary,
+            BadgeVariant::Primary,
+            sizes,
+        ),
+        toast_button(
+            "Success",
+            ButtonVariant::Success,
+            BadgeVariant::Success,
+            sizes,
+        ),
+        toast_button(
+            "Neutral",
+            ButtonVariant::Neutral,
+            BadgeVariant::Neutral,
+            sizes,
+        ),
+        toast_button(
+            "Warning",
+            ButtonVariant::Warning,
+            BadgeVariant::Warning,
+            sizes,
+        ),
+        toast_button("Danger", ButtonVariant::Danger, BadgeVariant::Danger, sizes),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    column![
+        title,
+        description,
+        variants_title,
+        variants,
+        closable_title,
+        closable,
+        duration_title,
+        duration_desc,
+        timed,
+        toasts_title,
+        toasts_desc,
+        toast_buttons,
+    ]
+    .spacing(20)
+    .padding(20)
+    .max_width(600)
+    .into()
+}
+
+fn toast_button<'a>(
+    label: &str,
+    button_variant: ButtonVariant,
+    variant: BadgeVariant,
+    sizes: Sizes,
+) -> Element<'a, Message> {
+    Button::new(label)
+        .sizes(sizes)
+        .variant(button_variant)
+        .on_press(Message::Alert(AlertMessage::PushToast(variant)))
+        .into()
+}
@@ -0,0 +1,126 @@
+use iced::alignment;
+use iced_widget::{Row, column, text};
+
+use crate::components::Heading;
+use crate::components::{Avatar, AvatarGroup, AvatarShape};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Avatars").sizes(sizes);
+    let description =
+        text("Avatars represent a person or object with an image, initials or an icon").size(14);
+
+    // Initials and icon
+    let basic_title = Heading::new("Initials and Icon")
+        .sizes(sizes)
+        .role(Role::H2);
+    let basic = Row::with_children([
+        Avatar::new().sizes(sizes).into(),
+        Avatar::new().sizes(sizes).name("Ada Lovelace").into(),
+        Avatar::new().sizes(sizes).name("Grace Hopper").into(),
+        Avatar::new().sizes(sizes).name("Alan Turing").into(),
+        Avatar::new()
+            .sizes(sizes)
+            .name("Margaret Hamilton")
+            .initials("MH")
+            .into(),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    // Images that fail to load fall back to initials, then the icon
+    let fallback_title = Heading::new("Image Fallback").sizes(sizes).role(Role::H2);
+    let fallback_desc =
+        text("Images that cannot be loaded fall back to the initials, then to the icon").size(14);
+    let fallback = Row::with_children([
+        Avatar::new()
+            .sizes(sizes)
+            .name("Katherine Johnson")
+            .image_path("missing/katherine.png")
+            .into(),
+        Avatar::new()
+            .sizes(sizes)
+            .image_path("missing/unknown.png")
+            .into(),
+        Avatar::new()
+            .sizes(sizes)
+            .name("Linus Torvalds")
+            .image_bytes(&b"not an image"[..])
+            .into(),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    // Shapes
+    let shapes_title = Heading::new("Shapes").sizes(sizes).role(Role::H2);
+    let shapes = Row::with_children([
+        Avatar::new()
+            .sizes(sizes)
+            .name("Circle Shape")
+            .shape(AvatarShape::Circle)
+            .into(),
+        Avatar::new()
+            .sizes(sizes)
+            .name("Square Shape")
+            .shape(AvatarShape::Square)
+            .into(),
+        Avatar::new()
+            .sizes(sizes)
+            .name("Rounded Shape")
+            .shape(AvatarShape::Rounded)
+            .into(),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    // Sizes
+    let sizes_title = Heading::new("Sizes").sizes(sizes).role(Role::H2);
+    let size_row = Row::with_children([
+        Avatar::new()
+            .sizes(sizes)
+            .name("Small Size")
+            .size(24.0)
+            .into(),
+        Avatar::new().sizes(sizes).name("Medium Size").into(),
+        Avatar::new()
+            .sizes(sizes)
+            .name("Large Size")
+            .size(48.0)
+            .into(),
+        Avatar::new().sizes(sizes).size(64.0).into(),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    // Groups
+    let group_title = Heading::new("Avatar Group").sizes(sizes).role(Role::H2);
+    let group = AvatarGroup::new()
+        .push(Avatar::new().sizes(sizes).name("Ada Lovelace"))
+        .push(Avatar::new().sizes(sizes).name("Grace Hopper"))
+        .push(Avatar::new().sizes(sizes).name("Alan Turing"))
+        .push(Avatar::new().sizes(sizes).name("Margaret Hamilton"))
+        .push(Avatar::new().sizes(sizes).name("Katherine Johnson"))
+        .push(Avatar::new().sizes(sizes).name("Linus Torvalds"))
+        .max(4);
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic,
+        fallback_title,
+        fallback_desc,
+        fallback,
+        shapes_title,
+        shapes,
+        sizes_title,
+        size_row,
+        group_title,
+        group,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
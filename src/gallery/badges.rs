@@ -2,46 +2,69 @@ use iced::alignment;
 use iced_widget::{Row, column, text};
 
 use crate::components::Badge;
+use crate::components::Heading;
 use crate::theme::badge::BadgeVariant as BadgeVar;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Badges").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Badges").sizes(sizes);
     let description = text("Use badges to highlight important information").size(14);
 
     // Badge variants
-    let variants_title = text("Variants").size(24);
+    let variants_title = Heading::new("Variants").sizes(sizes).role(Role::H2);
     let badge_variants_row = Row::with_children([
-        Badge::new("Primary").variant(BadgeVar::Primary).into(),
-        Badge::new("Success").variant(BadgeVar::Success).into(),
-        Badge::new("Neutral").variant(BadgeVar::Neutral).into(),
-        Badge::new("Warning").variant(BadgeVar::Warning).into(),
-        Badge::new("Danger").variant(BadgeVar::Danger).into(),
+        Badge::new("Primary")
+            .sizes(sizes)
+            .variant(BadgeVar::Primary)
+            .into(),
+        Badge::new("Success")
+            .sizes(sizes)
+            .variant(BadgeVar::Success)
+            .into(),
+        Badge::new("Neutral")
+            .sizes(sizes)
+            .variant(BadgeVar::Neutral)
+            .into(),
+        Badge::new("Warning")
+            .sizes(sizes)
+            .variant(BadgeVar::Warning)
+            .into(),
+        Badge::new("Danger")
+            .sizes(sizes)
+            .variant(BadgeVar::Danger)
+            .into(),
     ])
     .spacing(10)
     .align_y(alignment::Vertical::Center);
 
     // Pill badges
-    let pill_title = text("Pill Badges").size(24);
+    let pill_title = Heading::new("Pill Badges").sizes(sizes).role(Role::H2);
     let pill_desc = text("Rounded pill-shaped badges").size(14);
     let pill_badges_row = Row::with_children([
         Badge::new("Primary")
+            .sizes(sizes)
             .variant(BadgeVar::Primary)
             .pill(true)
             .into(),
         Badge::new("Success")
+            .sizes(sizes)
             .variant(BadgeVar::Success)
             .pill(true)
             .into(),
         Badge::new("Neutral")
+            .sizes(sizes)
             .variant(BadgeVar::Neutral)
             .pill(true)
             .into(),
         Badge::new("Warning")
+            .sizes(sizes)
             .variant(BadgeVar::Warning)
             .pill(true)
             .into(),
         Badge::new("Danger")
+            .sizes(sizes)
             .variant(BadgeVar::Danger)
             .pill(true)
             .into(),
@@ -50,29 +73,38 @@ pub fn page() -> Element<'static, Message> {
     .align_y(alignment::Vertical::Center);
 
     // Pulse badges
-    let pulse_title = text("Pulse Badges (Animated)").size(24);
+    let pulse_title = Heading::new("Pulse Badges (Animated)")
+        .sizes(sizes)
+        .role(Role::H2);
     let pulse_desc = text("Badges with pulse animation for attention-grabbing").size(14);
     let pulse_badges_row = Row::with_children([
         Badge::new("1")
+            .sizes(sizes)
             .variant(BadgeVar::Primary)
             .pulse(true)
             .into(),
         Badge::new("New")
+            .sizes(sizes)
             .variant(BadgeVar::Success)
             .pulse(true)
             .into(),
-        Badge::new("!").variant(BadgeVar::Danger).pulse(true).into(),
+        Badge::new("!")
+            .sizes(sizes)
+            .variant(BadgeVar::Danger)
+            .pulse(true)
+            .into(),
     ])
     .spacing(10)
     .align_y(alignment::Vertical::Center);
 
     // Usage examples
-    let usage_title = text("Usage Examples").size(24);
+    let usage_title = Heading::new("Usage Examples").sizes(sizes).role(Role::H2);
     let usage_desc = text("Badges can be used in various contexts").size(14);
 
     let notification_example = Row::with_children([
         text("Notifications").into(),
         Badge::new("3")
+            .sizes(sizes)
             .variant(BadgeVar::Danger)
             .pill(true)
             .pulse(true)
@@ -83,21 +115,27 @@ pub fn page() -> Element<'static, Message> {
 
     let status_example = Row::with_children([
         text("Status:").into(),
-        Badge::new("Online").variant(BadgeVar::Success).into(),
+        Badge::new("Online")
+            .sizes(sizes)
+            .variant(BadgeVar::Success)
+            .into(),
     ])
     .spacing(10)
     .align_y(alignment::Vertical::Center);
 
     let category_example = Row::with_children([
         Badge::new("Rust")
+            .sizes(sizes)
             .variant(BadgeVar::Neutral)
             .pill(true)
             .into(),
         Badge::new("UI")
+            .sizes(sizes)
             .variant(BadgeVar::Primary)
             .pill(true)
             .into(),
         Badge::new("Desktop")
+            .sizes(sizes)
             .variant(BadgeVar::Success)
             .pill(true)
             .into(),
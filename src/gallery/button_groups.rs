@@ -1,91 +1,120 @@
 use iced_widget::{column, text};
 
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::components::button_group::button_group_with;
 use crate::theme::button::ButtonVariant;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Button Groups").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Button Groups").sizes(sizes);
     let description = text("Group related buttons together").size(14);
 
     // Basic button group
-    let basic_title = text("Basic Button Group").size(24);
+    let basic_title = Heading::new("Basic Button Group")
+        .sizes(sizes)
+        .role(Role::H2);
     let basic_group = button_group_with(vec![
         Button::new("Left")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Group Left".into())),
         Button::new("Middle")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Group Middle".into())),
         Button::new("Right")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Group Right".into())),
-    ]);
+    ])
+    .sizes(sizes);
 
     // Button group with label
-    let labeled_title = text("Button Group with Label").size(24);
+    let labeled_title = Heading::new("Button Group with Label")
+        .sizes(sizes)
+        .role(Role::H2);
     let labeled_group = button_group_with(vec![
         Button::new("Bold")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Bold".into())),
         Button::new("Italic")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Italic".into())),
         Button::new("Underline")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Underline".into())),
     ])
+    .sizes(sizes)
     .label("Text Formatting");
 
     // Button group with different variants
-    let action_title = text("Mixed Variants").size(24);
+    let action_title = Heading::new("Mixed Variants").sizes(sizes).role(Role::H2);
     let action_group = button_group_with(vec![
         Button::new("View")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("View".into())),
         Button::new("Edit")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Edit".into())),
         Button::new("Delete")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("Delete Group".into())),
     ])
+    .sizes(sizes)
     .label("Actions");
 
     // Button group with outline buttons
-    let outline_title = text("Outline Button Group").size(24);
+    let outline_title = Heading::new("Outline Button Group")
+        .sizes(sizes)
+        .role(Role::H2);
     let outline_group = button_group_with(vec![
         Button::new("One")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .outline(true)
             .on_press(Message::ButtonPressed("One".into())),
         Button::new("Two")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .outline(true)
             .on_press(Message::ButtonPressed("Two".into())),
         Button::new("Three")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .outline(true)
             .on_press(Message::ButtonPressed("Three".into())),
-    ]);
+    ])
+    .sizes(sizes);
 
     // Toolbar example
-    let toolbar_title = text("Toolbar Example").size(24);
+    let toolbar_title = Heading::new("Toolbar Example").sizes(sizes).role(Role::H2);
     let toolbar_group = button_group_with(vec![
         Button::new("New")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .prefix("➕")
             .on_press(Message::ButtonPressed("New".into())),
         Button::new("Open")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .prefix("📂")
             .on_press(Message::ButtonPressed("Open".into())),
         Button::new("Save")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .prefix("💾")
             .on_press(Message::ButtonPressed("Save".into())),
     ])
+    .sizes(sizes)
     .label("File Operations");
 
     column![
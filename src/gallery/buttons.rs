@@ -1,38 +1,48 @@
-use iced::alignment;
+use iced::{Color, alignment};
 use iced_widget::{Row, column, text};
 
-use crate::components::button::Button;
+use crate::components::Heading;
+use crate::components::button::{Button, ButtonPart};
 use crate::theme::button::{ButtonSize, ButtonVariant};
+use crate::theme::parts::{Part, PartStyle, Status};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Buttons").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Buttons").sizes(sizes);
     let description = text("Interactive button components with various styles").size(14);
 
     // Variants
-    let variants_title = text("Variants").size(24);
+    let variants_title = Heading::new("Variants").sizes(sizes).role(Role::H2);
     let variants_row = Row::with_children([
         Button::new("Default")
+            .sizes(sizes)
             .variant(ButtonVariant::Default)
             .on_press(Message::ButtonPressed("Default".into()))
             .into(),
         Button::new("Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Primary".into()))
             .into(),
         Button::new("Success")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Success".into()))
             .into(),
         Button::new("Neutral")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Neutral".into()))
             .into(),
         Button::new("Warning")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .on_press(Message::ButtonPressed("Warning".into()))
             .into(),
         Button::new("Danger")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("Danger".into()))
             .into(),
@@ -40,19 +50,22 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // Sizes
-    let sizes_title = text("Sizes").size(24);
+    let sizes_title = Heading::new("Sizes").sizes(sizes).role(Role::H2);
     let sizes_row = Row::with_children([
         Button::new("Small")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .size(ButtonSize::Small)
             .on_press(Message::ButtonPressed("Small".into()))
             .into(),
         Button::new("Medium")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .size(ButtonSize::Medium)
             .on_press(Message::ButtonPressed("Medium".into()))
             .into(),
         Button::new("Large")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .size(ButtonSize::Large)
             .on_press(Message::ButtonPressed("Large".into()))
@@ -62,24 +75,28 @@ pub fn page() -> Element<'static, Message> {
     .align_y(alignment::Vertical::Center);
 
     // Outline
-    let outline_title = text("Outline Buttons").size(24);
+    let outline_title = Heading::new("Outline Buttons").sizes(sizes).role(Role::H2);
     let outline_row = Row::with_children([
         Button::new("Default")
+            .sizes(sizes)
             .variant(ButtonVariant::Default)
             .outline(true)
             .on_press(Message::ButtonPressed("Outline Default".into()))
             .into(),
         Button::new("Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .outline(true)
             .on_press(Message::ButtonPressed("Outline Primary".into()))
             .into(),
         Button::new("Success")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .outline(true)
             .on_press(Message::ButtonPressed("Outline Success".into()))
             .into(),
         Button::new("Danger")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .outline(true)
             .on_press(Message::ButtonPressed("Outline Danger".into()))
@@ -88,19 +105,22 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // Pill
-    let pill_title = text("Pill Buttons").size(24);
+    let pill_title = Heading::new("Pill Buttons").sizes(sizes).role(Role::H2);
     let pill_row = Row::with_children([
         Button::new("Default")
+            .sizes(sizes)
             .variant(ButtonVariant::Default)
             .pill(true)
             .on_press(Message::ButtonPressed("Pill Default".into()))
             .into(),
         Button::new("Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .pill(true)
             .on_press(Message::ButtonPressed("Pill Primary".into()))
             .into(),
         Button::new("Success")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .pill(true)
             .on_press(Message::ButtonPressed("Pill Success".into()))
@@ -109,13 +129,15 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // Text buttons
-    let text_title = text("Text Buttons").size(24);
+    let text_title = Heading::new("Text Buttons").sizes(sizes).role(Role::H2);
     let text_row = Row::with_children([
         Button::new("Text Default")
+            .sizes(sizes)
             .variant(ButtonVariant::Text)
             .on_press(Message::ButtonPressed("Text Default".into()))
             .into(),
         Button::new("Text Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Text)
             .on_press(Message::ButtonPressed("Text Primary".into()))
             .into(),
@@ -123,18 +145,21 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // States
-    let states_title = text("States").size(24);
+    let states_title = Heading::new("States").sizes(sizes).role(Role::H2);
     let states_row = Row::with_children([
         Button::new("Normal")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Normal".into()))
             .into(),
         Button::new("Loading")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .loading(true)
             .on_press(Message::ButtonPressed("Loading".into()))
             .into(),
         Button::new("Disabled")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .disabled(true)
             .on_press(Message::ButtonPressed("Disabled".into()))
@@ -143,19 +168,22 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // Prefix and Suffix
-    let prefix_suffix_title = text("Prefix & Suffix").size(24);
+    let prefix_suffix_title = Heading::new("Prefix & Suffix").sizes(sizes).role(Role::H2);
     let prefix_suffix_row = Row::with_children([
         Button::new("Settings")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .prefix("⚙")
             .on_press(Message::ButtonPressed("Settings".into()))
             .into(),
         Button::new("Download")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .suffix("↓")
             .on_press(Message::ButtonPressed("Download".into()))
             .into(),
         Button::new("Delete")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .prefix("🗑")
             .suffix("×")
@@ -164,6 +192,43 @@ pub fn page() -> Element<'static, Message> {
     ])
     .spacing(10);
 
+    // Custom styles
+    let custom_title = Heading::new("Custom Styles").sizes(sizes).role(Role::H2);
+    let brand = Color::from_rgb8(0x7c, 0x3a, 0xed);
+    let brand_dark = Color::from_rgb8(0x6d, 0x28, 0xd9);
+    let custom_row = Row::with_children([
+        Button::new("Upgrade")
+            .sizes(sizes)
+            .prefix("★")
+            .part(
+                ButtonPart::Base,
+                Part::with(|status| {
+                    let background = match status {
+                        Status::Hovered | Status::Pressed => brand_dark,
+                        _ => brand,
+                    };
+
+                    PartStyle::new()
+                        .background(background)
+                        .border_color(background)
+                        .text_color(Color::WHITE)
+                }),
+            )
+            .part(
+                ButtonPart::Prefix,
+                PartStyle::new().text_color(Color::from_rgb8(0xfd, 0xe0, 0x47)),
+            )
+            .on_press(Message::ButtonPressed("Upgrade".into()))
+            .into(),
+        Button::new("Square")
+            .sizes(sizes)
+            .variant(ButtonVariant::Primary)
+            .part(ButtonPart::Base, PartStyle::new().border_radius(0.0))
+            .on_press(Message::ButtonPressed("Square".into()))
+            .into(),
+    ])
+    .spacing(10);
+
     column![
         title,
         description,
@@ -181,6 +246,8 @@ pub fn page() -> Element<'static, Message> {
         states_row,
         prefix_suffix_title,
         prefix_suffix_row,
+        custom_title,
+        custom_row,
     ]
     .spacing(20)
     .padding(20)
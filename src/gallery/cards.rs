@@ -2,42 +2,50 @@ use iced_widget::{Row, column, text};
 
 use crate::components::button::Button;
 use crate::components::Card;
+use crate::components::Heading;
 use crate::theme::button::{ButtonSize, ButtonVariant};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Cards").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Cards").sizes(sizes);
     let description = text("Cards group related subjects in a container with optional header, footer, and image").size(14);
 
     // Basic Card
-    let basic_title = text("Basic Card").size(24);
+    let basic_title = Heading::new("Basic Card").sizes(sizes).role(Role::H2);
     let basic_card = Card::new(
         text("This is a basic card with some content. Cards can contain any widgets you want to display.")
     )
+    .sizes(sizes)
     .width(300);
 
     // Card with Header
-    let header_title = text("Card with Header").size(24);
+    let header_title = Heading::new("Card with Header").sizes(sizes).role(Role::H2);
     let header_card = Card::new(
         text("This card has a header. Headers are great for titles and can contain any widget.")
     )
+    .sizes(sizes)
     .header(text("Card Header").size(18))
     .width(300);
 
     // Card with Footer
-    let footer_title = text("Card with Footer").size(24);
+    let footer_title = Heading::new("Card with Footer").sizes(sizes).role(Role::H2);
     let footer_card = Card::new(
         text("This card has a footer with action buttons. Footers are perfect for actions or additional information.")
     )
+    .sizes(sizes)
     .header(text("Card with Actions").size(18))
     .footer(
         Row::with_children([
             Button::new("Cancel")
+                .sizes(sizes)
                 .variant(ButtonVariant::Default)
                 .size(ButtonSize::Small)
                 .on_press(Message::ButtonPressed("Cancel".into()))
                 .into(),
             Button::new("Save")
+                .sizes(sizes)
                 .variant(ButtonVariant::Primary)
                 .size(ButtonSize::Small)
                 .on_press(Message::ButtonPressed("Save".into()))
@@ -48,7 +56,7 @@ pub fn page() -> Element<'static, Message> {
     .width(300);
 
     // Card with All Slots
-    let complete_title = text("Complete Card").size(24);
+    let complete_title = Heading::new("Complete Card").sizes(sizes).role(Role::H2);
     let complete_card = Card::new(
         column([
             text("This card uses all available slots:").into(),
@@ -59,6 +67,7 @@ pub fn page() -> Element<'static, Message> {
         ])
         .spacing(8)
     )
+    .sizes(sizes)
     .header(
         column([
             text("Complete Example").size(18).into(),
@@ -69,6 +78,7 @@ pub fn page() -> Element<'static, Message> {
     .footer(
         Row::with_children([
             Button::new("Learn More")
+                .sizes(sizes)
                 .variant(ButtonVariant::Primary)
                 .size(ButtonSize::Small)
                 .on_press(Message::ButtonPressed("Learn More".into()))
@@ -78,7 +88,7 @@ pub fn page() -> Element<'static, Message> {
     .width(350);
 
     // Cards in a Row
-    let row_title = text("Multiple Cards").size(24);
+    let row_title = Heading::new("Multiple Cards").sizes(sizes).role(Role::H2);
     let cards_row = Row::with_children([
         Card::new(
             column([
@@ -87,6 +97,7 @@ pub fn page() -> Element<'static, Message> {
             ])
             .spacing(8)
         )
+        .sizes(sizes)
         .header(text("Card 1").size(18))
         .width(250)
         .into(),
@@ -97,6 +108,7 @@ pub fn page() -> Element<'static, Message> {
             ])
             .spacing(8)
         )
+        .sizes(sizes)
         .header(text("Card 2").size(18))
         .width(250)
         .into(),
@@ -107,6 +119,7 @@ pub fn page() -> Element<'static, Message> {
             ])
             .spacing(8)
         )
+        .sizes(sizes)
         .header(text("Card 3").size(18))
         .width(250)
         .into(),
@@ -114,17 +127,20 @@ pub fn page() -> Element<'static, Message> {
     .spacing(20);
 
     // Different Widths
-    let width_title = text("Different Sizes").size(24);
+    let width_title = Heading::new("Different Sizes").sizes(sizes).role(Role::H2);
     let width_examples = column([
         Card::new(text("Small card"))
+            .sizes(sizes)
             .header(text("Small").size(16))
             .width(200)
             .into(),
         Card::new(text("Medium card"))
+            .sizes(sizes)
             .header(text("Medium").size(16))
             .width(400)
             .into(),
         Card::new(text("Large card with more content to demonstrate how the card expands"))
+            .sizes(sizes)
             .header(text("Large").size(16))
             .width(600)
             .into(),
@@ -1,51 +1,107 @@
 use iced::alignment;
 use iced_widget::{Row, column, text};
 
-use crate::components::checkbox::{Checkbox, CheckboxSize};
+use crate::components::Heading;
+use crate::components::checkbox::{Checkbox, CheckboxSize, select_all};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Checkboxes").size(32);
+#[derive(Debug, Clone)]
+pub struct CheckboxState {
+    pub notifications: [bool; 3],
+}
+
+impl Default for CheckboxState {
+    fn default() -> Self {
+        Self {
+            notifications: [true, false, false],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CheckboxMessage {
+    SelectAll(bool),
+    Toggle(usize, bool),
+}
+
+pub fn handle_checkbox_message(state: &mut CheckboxState, message: CheckboxMessage) {
+    match message {
+        CheckboxMessage::SelectAll(checked) => state.notifications = [checked; 3],
+        CheckboxMessage::Toggle(index, checked) => state.notifications[index] = checked,
+    }
+}
+
+pub fn view(state: &CheckboxState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Checkboxes").sizes(sizes);
     let description = text("Allow users to toggle an option on or off").size(14);
 
     // Basic checkbox
-    let basic_title = text("Basic").size(24);
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
     let basic_checkbox = Checkbox::new("Checkbox", false)
+        .sizes(sizes)
         .on_toggle(|checked| Message::CheckboxChanged("Basic".into(), checked));
 
     // Checked
-    let checked_title = text("Checked").size(24);
+    let checked_title = Heading::new("Checked").sizes(sizes).role(Role::H2);
     let checked_checkbox = Checkbox::new("Checked", true)
+        .sizes(sizes)
         .on_toggle(|checked| Message::CheckboxChanged("Checked".into(), checked));
 
     // Indeterminate
-    let indeterminate_title = text("Indeterminate").size(24);
+    let indeterminate_title = Heading::new("Indeterminate").sizes(sizes).role(Role::H2);
     let indeterminate_checkbox = Checkbox::new("Indeterminate", false)
+        .sizes(sizes)
         .indeterminate(true)
         .on_toggle(|checked| Message::CheckboxChanged("Indeterminate".into(), checked));
 
+    // Select all
+    let select_all_title = Heading::new("Select All").sizes(sizes).role(Role::H2);
+    let select_all_desc =
+        text("A parent checkbox derives its indeterminate state from its children").size(14);
+    let mut children = column![].spacing(10).padding([0.0, sizes.spacing.large]);
+    for (index, label) in ["Email", "Push notifications", "SMS"].into_iter().enumerate() {
+        children = children.push(
+            Checkbox::new(label, state.notifications[index]).sizes(sizes).on_toggle(move |checked| {
+                Message::Checkbox(CheckboxMessage::Toggle(index, checked))
+            }),
+        );
+    }
+    let select_all_column = column![
+        select_all("All notifications", state.notifications)
+            .sizes(sizes)
+            .on_toggle(|checked| Message::Checkbox(CheckboxMessage::SelectAll(checked))),
+        children,
+    ]
+    .spacing(10);
+
     // Disabled
-    let disabled_title = text("Disabled").size(24);
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
     let disabled_row = Row::with_children([
-        Checkbox::new("Disabled", false).disabled(true).into(),
+        Checkbox::new("Disabled", false).sizes(sizes).disabled(true).into(),
         Checkbox::new("Disabled Checked", true)
+            .sizes(sizes)
             .disabled(true)
             .into(),
     ])
     .spacing(20);
 
     // Sizes
-    let sizes_title = text("Sizes").size(24);
+    let sizes_title = Heading::new("Sizes").sizes(sizes).role(Role::H2);
     let sizes_row = Row::with_children([
         Checkbox::new("Small", false)
+            .sizes(sizes)
             .size(CheckboxSize::Small)
             .on_toggle(|checked| Message::CheckboxChanged("Small".into(), checked))
             .into(),
         Checkbox::new("Medium", false)
+            .sizes(sizes)
             .size(CheckboxSize::Medium)
             .on_toggle(|checked| Message::CheckboxChanged("Medium".into(), checked))
             .into(),
         Checkbox::new("Large", false)
+            .sizes(sizes)
             .size(CheckboxSize::Large)
             .on_toggle(|checked| Message::CheckboxChanged("Large".into(), checked))
             .into(),
@@ -54,19 +110,23 @@ pub fn page() -> Element<'static, Message> {
     .align_y(alignment::Vertical::Center);
 
     // Help Text
-    let help_text_title = text("Help Text").size(24);
+    let help_text_title = Heading::new("Help Text").sizes(sizes).role(Role::H2);
     let help_text_checkbox = Checkbox::new("Label", false)
+        .sizes(sizes)
         .help_text("What should the user know about the checkbox?")
         .on_toggle(|checked| Message::CheckboxChanged("HelpText".into(), checked));
 
     // Different labels
-    let labels_title = text("Different Labels").size(24);
+    let labels_title = Heading::new("Different Labels").sizes(sizes).role(Role::H2);
     let labels_column = column![
         Checkbox::new("I agree to the terms and conditions", false)
+            .sizes(sizes)
             .on_toggle(|checked| Message::CheckboxChanged("Terms".into(), checked)),
         Checkbox::new("Subscribe to newsletter", false)
+            .sizes(sizes)
             .on_toggle(|checked| Message::CheckboxChanged("Newsletter".into(), checked)),
         Checkbox::new("Remember me", false)
+            .sizes(sizes)
             .on_toggle(|checked| Message::CheckboxChanged("Remember".into(), checked)),
     ]
     .spacing(15);
@@ -80,6 +140,9 @@ pub fn page() -> Element<'static, Message> {
         checked_checkbox,
         indeterminate_title,
         indeterminate_checkbox,
+        select_all_title,
+        select_all_desc,
+        select_all_column,
         disabled_title,
         disabled_row,
         sizes_title,
@@ -0,0 +1,120 @@
+use iced::Color;
+use iced_widget::{column, text};
+
+use crate::components::Heading;
+use crate::components::{ColorFormat, ColorPicker};
+use crate::theme::color;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct ColorPickerState {
+    pub accent: Color,
+    pub overlay: Color,
+    pub brand: Color,
+}
+
+impl Default for ColorPickerState {
+    fn default() -> Self {
+        Self {
+            accent: color::hsl(199.0, 89.0, 48.0),
+            overlay: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            brand: color::hsl(262.0, 83.0, 58.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ColorPickerMessage {
+    AccentChanged(Color),
+    OverlayChanged(Color),
+    BrandChanged(Color),
+}
+
+pub fn handle_color_picker_message(state: &mut ColorPickerState, message: ColorPickerMessage) {
+    match message {
+        ColorPickerMessage::AccentChanged(color) => state.accent = color,
+        ColorPickerMessage::OverlayChanged(color) => state.overlay = color,
+        ColorPickerMessage::BrandChanged(color) => state.brand = color,
+    }
+}
+
+pub fn view(state: &ColorPickerState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Color Pickers").sizes(sizes);
+    let description = text(
+        "Color pickers let the user choose a color from a grid, sliders, a text field or preset swatches",
+    )
+    .size(14);
+
+    // Dropdown
+    let dropdown_title = Heading::new("Dropdown").sizes(sizes).role(Role::H2);
+    let dropdown_desc = text(format!(
+        "Click the swatch to open the picker. Selected: {}",
+        color::to_hex(state.accent)
+    ))
+    .size(14);
+    let dropdown = ColorPicker::new(state.accent)
+        .sizes(sizes)
+        .label("Accent color")
+        .on_change(|color| Message::ColorPicker(ColorPickerMessage::AccentChanged(color)));
+
+    // Inline with opacity
+    let inline_title = Heading::new("Inline With Opacity")
+        .sizes(sizes)
+        .role(Role::H2);
+    let inline_desc = text(format!(
+        "The opacity slider adds an alpha channel. Selected: {}",
+        color::format(state.overlay, ColorFormat::Rgb)
+    ))
+    .size(14);
+    let inline = ColorPicker::new(state.overlay)
+        .sizes(sizes)
+        .inline(true)
+        .opacity(true)
+        .format(ColorFormat::Rgb)
+        .on_change(|color| Message::ColorPicker(ColorPickerMessage::OverlayChanged(color)));
+
+    // Custom swatches
+    let swatches_title = Heading::new("Custom Swatches").sizes(sizes).role(Role::H2);
+    let swatches_desc =
+        text("Swatches can be replaced with a custom palette, here shown in HSL notation").size(14);
+    let swatches = ColorPicker::new(state.brand)
+        .sizes(sizes)
+        .inline(true)
+        .format(ColorFormat::Hsl)
+        .swatches(
+            [
+                "#7c3aed", "#db2777", "#ea580c", "#16a34a", "#0891b2", "#1e293b",
+            ]
+            .into_iter()
+            .filter_map(color::from_hex),
+        )
+        .on_change(|color| Message::ColorPicker(ColorPickerMessage::BrandChanged(color)));
+
+    // Disabled
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
+    let disabled = ColorPicker::<Message>::new(state.brand)
+        .inline(true)
+        .swatches([])
+        .disabled(true);
+
+    column![
+        title,
+        description,
+        dropdown_title,
+        dropdown_desc,
+        dropdown,
+        inline_title,
+        inline_desc,
+        inline,
+        swatches_title,
+        swatches_desc,
+        swatches,
+        disabled_title,
+        disabled,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
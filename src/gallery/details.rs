@@ -0,0 +1,91 @@
+use iced_widget::{column, text};
+
+use crate::components::Heading;
+use crate::components::{Accordion, Details};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone, Default)]
+pub struct DetailsState {
+    pub basic: bool,
+    pub faq: Option<usize>,
+    pub settings: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub enum DetailsMessage {
+    BasicToggled(bool),
+    FaqChanged(Option<usize>),
+    SettingsChanged(Option<usize>),
+}
+
+pub fn handle_details_message(state: &mut DetailsState, message: DetailsMessage) {
+    match message {
+        DetailsMessage::BasicToggled(open) => state.basic = open,
+        DetailsMessage::FaqChanged(open) => state.faq = open,
+        DetailsMessage::SettingsChanged(open) => state.settings = open,
+    }
+}
+
+const LOREM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat.";
+
+pub fn view(state: &DetailsState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Details").sizes(sizes);
+    let description =
+        text("Details show a brief summary and expand to show additional content").size(14);
+
+    // Basic details
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
+    let basic = Details::new("Toggle Me", text(LOREM))
+        .sizes(sizes)
+        .open(state.basic)
+        .on_toggle(|open| Message::Details(DetailsMessage::BasicToggled(open)));
+
+    // Disabled
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
+    let disabled = Details::<Message>::new("Disabled", text(LOREM)).disabled(true);
+
+    // Accordion
+    let accordion_title = Heading::new("Accordion").sizes(sizes).role(Role::H2);
+    let accordion_description = text("Opening an item closes the others").size(14);
+    let faq = Accordion::new(state.faq)
+        .sizes(sizes)
+        .push(Details::new("What is a details component?", text(LOREM)).sizes(sizes))
+        .push(Details::new("Can I nest other components inside?", text(LOREM)).sizes(sizes))
+        .push(Details::new("How many items can be open at once?", text("Just one.")).sizes(sizes))
+        .on_change(|open| Message::Details(DetailsMessage::FaqChanged(open)));
+
+    // Accordion with a disabled item
+    let settings_title = Heading::new("Advanced Settings")
+        .sizes(sizes)
+        .role(Role::H2);
+    let settings = Accordion::new(state.settings)
+        .sizes(sizes)
+        .push(Details::new("Network", text("Proxy and connection settings.")).sizes(sizes))
+        .push(Details::new("Storage", text("Cache size and location.")).sizes(sizes))
+        .push(
+            Details::new("Experimental", text("Nothing to see here yet."))
+                .sizes(sizes)
+                .disabled(true),
+        )
+        .on_change(|open| Message::Details(DetailsMessage::SettingsChanged(open)));
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic,
+        disabled_title,
+        disabled,
+        accordion_title,
+        accordion_description,
+        faq,
+        settings_title,
+        settings,
+    ]
+    .spacing(20)
+    .padding(20)
+    .max_width(600)
+    .into()
+}
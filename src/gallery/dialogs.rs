@@ -1,8 +1,9 @@
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::theme::Theme;
 use crate::theme::button::ButtonVariant;
 use crate::theme::container::ContainerStyleClass;
-use crate::theme::sizes::SPACING;
+use crate::theme::sizes::Sizes;
 use crate::widgets::overlay::Overlay;
 use crate::{Element, Message};
 
@@ -43,24 +44,25 @@ pub fn handle_dialog_message(state: &mut DialogState, message: DialogMessage) {
     }
 }
 
-pub fn view(state: &DialogState) -> Element<'static, Message> {
+pub fn view(state: &DialogState, sizes: Sizes) -> Element<'static, Message> {
     container(
         column![
-            text("Dialogs").size(32),
+            Heading::new("Dialogs").sizes(sizes),
             text("Dialogs display important information that requires user attention.").size(14),
-            basic_dialog(state.show_basic),
-            custom_width_dialog(state.show_custom_width),
-            no_header_dialog(state.show_no_header),
-            scroll_dialog(state.show_scroll),
+            basic_dialog(state.show_basic, sizes),
+            custom_width_dialog(state.show_custom_width, sizes),
+            no_header_dialog(state.show_no_header, sizes),
+            scroll_dialog(state.show_scroll, sizes),
         ]
-        .spacing(SPACING.large),
+        .spacing(sizes.spacing.large),
     )
-    .padding(SPACING.large)
+    .padding(sizes.spacing.large)
     .into()
 }
 
-fn basic_dialog(show: bool) -> Element<'static, Message> {
+fn basic_dialog(show: bool, sizes: Sizes) -> Element<'static, Message> {
     let trigger = Button::new("Open Basic Dialog")
+        .sizes(sizes)
         .variant(ButtonVariant::Primary)
         .on_press(Message::Dialog(DialogMessage::OpenBasic));
 
@@ -69,7 +71,7 @@ fn basic_dialog(show: bool) -> Element<'static, Message> {
             column![
                 // Header - uses Shoelace's --header-spacing (medium = 16px)
                 container(text("Dialog Title").size(20))
-                    .padding(SPACING.large)
+                    .padding(sizes.spacing.large)
                     .width(Fill)
                     .class(ContainerStyleClass::DialogHeader),
                 // Content - uses Shoelace's --body-spacing (large = 20px for better readability)
@@ -78,25 +80,27 @@ fn basic_dialog(show: bool) -> Element<'static, Message> {
                         text("This is a basic dialog."),
                         text("It has a title, content, and footer with action buttons."),
                     ]
-                    .spacing(SPACING.small)
+                    .spacing(sizes.spacing.small)
                 )
-                .padding(SPACING.large),
+                .padding(sizes.spacing.large),
                 // Footer with right-aligned buttons - uses Shoelace's --footer-spacing (large = 20px)
                 container(
                     Row::with_children([
                         Button::new("Cancel")
+                            .sizes(sizes)
                             .variant(ButtonVariant::Default)
                             .on_press(Message::Dialog(DialogMessage::CloseBasic))
                             .into(),
                         Button::new("OK")
+                            .sizes(sizes)
                             .variant(ButtonVariant::Primary)
                             .on_press(Message::Dialog(DialogMessage::CloseBasic))
                             .into(),
                     ])
-                    .spacing(SPACING.small)
+                    .spacing(sizes.spacing.small)
                     .align_y(Alignment::Center)
                 )
-                .padding(SPACING.large)
+                .padding(sizes.spacing.large)
                 .width(Fill)
                 .align_x(Alignment::End)
                 .class(ContainerStyleClass::DialogFooter),
@@ -115,8 +119,9 @@ fn basic_dialog(show: bool) -> Element<'static, Message> {
     }
 }
 
-fn custom_width_dialog(show: bool) -> Element<'static, Message> {
+fn custom_width_dialog(show: bool, sizes: Sizes) -> Element<'static, Message> {
     let trigger = Button::new("Open Custom Width Dialog")
+        .sizes(sizes)
         .variant(ButtonVariant::Primary)
         .on_press(Message::Dialog(DialogMessage::OpenCustomWidth));
 
@@ -125,7 +130,7 @@ fn custom_width_dialog(show: bool) -> Element<'static, Message> {
             column![
                 // Header
                 container(text("Custom Width Dialog").size(20))
-                    .padding(SPACING.large)
+                    .padding(sizes.spacing.large)
                     .width(Fill)
                     .class(ContainerStyleClass::DialogHeader),
                 // Content
@@ -134,16 +139,17 @@ fn custom_width_dialog(show: bool) -> Element<'static, Message> {
                         text("This dialog has a custom width."),
                         text("You can set the width using the width() method."),
                     ]
-                    .spacing(SPACING.small)
+                    .spacing(sizes.spacing.small)
                 )
-                .padding(SPACING.large),
+                .padding(sizes.spacing.large),
                 // Footer
                 container(
                     Button::new("Close")
+                        .sizes(sizes)
                         .variant(ButtonVariant::Primary)
                         .on_press(Message::Dialog(DialogMessage::CloseCustomWidth))
                 )
-                .padding(SPACING.large)
+                .padding(sizes.spacing.large)
                 .width(Fill)
                 .align_x(Alignment::End)
                 .class(ContainerStyleClass::DialogFooter),
@@ -162,8 +168,9 @@ fn custom_width_dialog(show: bool) -> Element<'static, Message> {
     }
 }
 
-fn no_header_dialog(show: bool) -> Element<'static, Message> {
+fn no_header_dialog(show: bool, sizes: Sizes) -> Element<'static, Message> {
     let trigger = Button::new("Open No Header Dialog")
+        .sizes(sizes)
         .variant(ButtonVariant::Primary)
         .on_press(Message::Dialog(DialogMessage::OpenNoHeader));
 
@@ -176,16 +183,17 @@ fn no_header_dialog(show: bool) -> Element<'static, Message> {
                         text("This dialog has no header.").size(20),
                         text("Content can be displayed without a header bar."),
                     ]
-                    .spacing(SPACING.small)
+                    .spacing(sizes.spacing.small)
                 )
-                .padding(SPACING.large),
+                .padding(sizes.spacing.large),
                 // Footer
                 container(
                     Button::new("Close")
+                        .sizes(sizes)
                         .variant(ButtonVariant::Primary)
                         .on_press(Message::Dialog(DialogMessage::CloseNoHeader))
                 )
-                .padding(SPACING.large)
+                .padding(sizes.spacing.large)
                 .width(Fill)
                 .align_x(Alignment::End)
                 .class(ContainerStyleClass::DialogFooter),
@@ -204,13 +212,14 @@ fn no_header_dialog(show: bool) -> Element<'static, Message> {
     }
 }
 
-fn scroll_dialog(show: bool) -> Element<'static, Message> {
+fn scroll_dialog(show: bool, sizes: Sizes) -> Element<'static, Message> {
     let trigger = Button::new("Open Scrolling Dialog")
+        .sizes(sizes)
         .variant(ButtonVariant::Primary)
         .on_press(Message::Dialog(DialogMessage::OpenScroll));
 
     if show {
-        let mut content: Column<'static, Message, Theme> = column![].spacing(SPACING.small);
+        let mut content: Column<'static, Message, Theme> = column![].spacing(sizes.spacing.small);
 
         for i in 1..=30 {
             content = content.push(text(format!("Line {} of scrolling content", i)));
@@ -220,18 +229,19 @@ fn scroll_dialog(show: bool) -> Element<'static, Message> {
             column![
                 // Header
                 container(text("Scrolling Dialog").size(20))
-                    .padding(SPACING.large)
+                    .padding(sizes.spacing.large)
                     .width(Fill)
                     .class(ContainerStyleClass::DialogHeader),
                 // Scrollable content
-                container(content).padding(SPACING.large),
+                container(content).padding(sizes.spacing.large),
                 // Footer
                 container(
                     Button::new("Close")
+                        .sizes(sizes)
                         .variant(ButtonVariant::Primary)
                         .on_press(Message::Dialog(DialogMessage::CloseScroll))
                 )
-                .padding(SPACING.large)
+                .padding(sizes.spacing.large)
                 .width(Fill)
                 .align_x(Alignment::End)
                 .class(ContainerStyleClass::DialogFooter),
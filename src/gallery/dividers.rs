@@ -2,47 +2,60 @@ use iced::{Color, Length, alignment};
 use iced_widget::{Row, column, text};
 
 use crate::components::Divider;
+use crate::components::Heading;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Dividers").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Dividers").sizes(sizes);
     let description = text("Use dividers to visually separate or group elements").size(14);
 
     // Basic divider
-    let basic_title = text("Basic Divider").size(24);
+    let basic_title = Heading::new("Basic Divider").sizes(sizes).role(Role::H2);
     let basic_desc = text("A simple horizontal divider").size(14);
-    let basic_divider = Divider::new();
+    let basic_divider = Divider::new().sizes(sizes);
 
     // Width variations
-    let width_title = text("Width Variations").size(24);
+    let width_title = Heading::new("Width Variations").sizes(sizes).role(Role::H2);
     let width_desc = text("Customize the thickness of the divider").size(14);
-    let width_1px = Divider::new().width(1.0);
-    let width_2px = Divider::new().width(2.0);
-    let width_4px = Divider::new().width(4.0);
+    let width_1px = Divider::new().sizes(sizes).width(1.0);
+    let width_2px = Divider::new().sizes(sizes).width(2.0);
+    let width_4px = Divider::new().sizes(sizes).width(4.0);
 
     // Color variations
-    let color_title = text("Color Variations").size(24);
+    let color_title = Heading::new("Color Variations").sizes(sizes).role(Role::H2);
     let color_desc = text("Customize the color of the divider").size(14);
-    let color_default = Divider::new();
-    let color_red = Divider::new().color(Color::from_rgb(0.9, 0.2, 0.2));
-    let color_blue = Divider::new().color(Color::from_rgb(0.2, 0.5, 0.9));
-    let color_green = Divider::new().color(Color::from_rgb(0.2, 0.7, 0.3));
+    let color_default = Divider::new().sizes(sizes);
+    let color_red = Divider::new()
+        .sizes(sizes)
+        .color(Color::from_rgb(0.9, 0.2, 0.2));
+    let color_blue = Divider::new()
+        .sizes(sizes)
+        .color(Color::from_rgb(0.2, 0.5, 0.9));
+    let color_green = Divider::new()
+        .sizes(sizes)
+        .color(Color::from_rgb(0.2, 0.7, 0.3));
 
     // Spacing variations
-    let spacing_title = text("Spacing Variations").size(24);
+    let spacing_title = Heading::new("Spacing Variations")
+        .sizes(sizes)
+        .role(Role::H2);
     let spacing_desc = text("Customize the space around the divider").size(14);
-    let spacing_small = Divider::new().spacing(8.0);
-    let spacing_default = Divider::new(); // 16px default
-    let spacing_large = Divider::new().spacing(32.0);
+    let spacing_small = Divider::new().sizes(sizes).spacing(8.0);
+    let spacing_default = Divider::new().sizes(sizes); // 16px default
+    let spacing_large = Divider::new().sizes(sizes).spacing(32.0);
 
     // Vertical dividers
-    let vertical_title = text("Vertical Dividers").size(24);
+    let vertical_title = Heading::new("Vertical Dividers")
+        .sizes(sizes)
+        .role(Role::H2);
     let vertical_desc = text("Dividers can also be displayed vertically").size(14);
     let vertical_example = Row::with_children([
         text("First").into(),
-        Divider::new().vertical(true).into(),
+        Divider::new().sizes(sizes).vertical(true).into(),
         text("Second").into(),
-        Divider::new().vertical(true).into(),
+        Divider::new().sizes(sizes).vertical(true).into(),
         text("Third").into(),
     ])
     .spacing(0)
@@ -53,6 +66,7 @@ pub fn page() -> Element<'static, Message> {
     let vertical_styled = Row::with_children([
         text("Item 1").into(),
         Divider::new()
+            .sizes(sizes)
             .vertical(true)
             .width(2.0)
             .color(Color::from_rgb(0.2, 0.5, 0.9))
@@ -60,6 +74,7 @@ pub fn page() -> Element<'static, Message> {
             .into(),
         text("Item 2").into(),
         Divider::new()
+            .sizes(sizes)
             .vertical(true)
             .width(2.0)
             .color(Color::from_rgb(0.2, 0.5, 0.9))
@@ -72,16 +87,16 @@ pub fn page() -> Element<'static, Message> {
     .height(Length::Fixed(40.0));
 
     // Usage in menus/lists
-    let menu_title = text("Menu Dividers").size(24);
+    let menu_title = Heading::new("Menu Dividers").sizes(sizes).role(Role::H2);
     let menu_desc = text("Use dividers to group menu items").size(14);
     let menu_example = column![
         text("File"),
         text("Edit"),
         text("View"),
-        Divider::new(),
+        Divider::new().sizes(sizes),
         text("Settings"),
         text("Help"),
-        Divider::new(),
+        Divider::new().sizes(sizes),
         text("Exit"),
     ]
     .spacing(8)
@@ -1,33 +1,49 @@
 use iced_widget::{Row, column, container, text};
 
+use crate::components::Heading;
+use crate::theme::typography::Role;
 use crate::components::{
     Divider, Menu, MenuItemType, MenuItem, MenuLabel, PopupPlacement, dropdown,
 };
 use crate::components::button::Button;
 use crate::theme::button::ButtonVariant;
 use crate::theme::container::ContainerStyleClass;
-use crate::theme::sizes::SPACING;
+use crate::theme::sizes::Sizes;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Dropdowns").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Dropdowns").sizes(sizes);
     let description = text(
         "Display menus attached to a trigger element. Combines positioning with menu functionality."
     )
     .size(14);
 
     // Basic Dropdown
-    let basic_title = text("Basic Dropdown").size(24);
+    let basic_title = Heading::new("Basic Dropdown").sizes(sizes).role(Role::H2);
     let basic_desc = text("A simple dropdown with menu items").size(14);
 
     let basic_menu = Menu::new()
-        .push(MenuItem::new("Option 1").on_select(Message::ButtonPressed("Option 1".into())))
-        .push(MenuItem::new("Option 2").on_select(Message::ButtonPressed("Option 2".into())))
-        .push(MenuItem::new("Option 3").on_select(Message::ButtonPressed("Option 3".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("Option 1")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Option 1".into())),
+        )
+        .push(
+            MenuItem::new("Option 2")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Option 2".into())),
+        )
+        .push(
+            MenuItem::new("Option 3")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Option 3".into())),
+        )
         .width(200);
 
     let basic_dropdown = dropdown(
         Button::new("Dropdown")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Toggle Dropdown".into())),
@@ -38,17 +54,31 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     // Dropdown with different button variants
-    let variants_title = text("Button Variants").size(24);
+    let variants_title = Heading::new("Button Variants").sizes(sizes).role(Role::H2);
     let variants_desc = text("Dropdowns can use any button variant").size(14);
 
     let menu_primary = Menu::new()
-        .push(MenuItem::new("New File").on_select(Message::ButtonPressed("New".into())))
-        .push(MenuItem::new("Open File").on_select(Message::ButtonPressed("Open".into())))
-        .push(MenuItem::new("Save File").on_select(Message::ButtonPressed("Save".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("New File")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("New".into())),
+        )
+        .push(
+            MenuItem::new("Open File")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Open".into())),
+        )
+        .push(
+            MenuItem::new("Save File")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Save".into())),
+        )
         .width(150);
 
     let dropdown_primary = dropdown(
         Button::new("Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Primary".into())),
@@ -58,12 +88,18 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_success = Menu::new()
-        .push(MenuItem::new("Confirm").on_select(Message::ButtonPressed("Confirm".into())))
-        .push(MenuItem::new("Apply").on_select(Message::ButtonPressed("Apply".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("Confirm")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Confirm".into())),
+        )
+        .push(MenuItem::new("Apply").sizes(sizes).on_select(Message::ButtonPressed("Apply".into())))
         .width(150);
 
     let dropdown_success = dropdown(
         Button::new("Success")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Success".into())),
@@ -73,12 +109,14 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_neutral = Menu::new()
-        .push(MenuItem::new("View").on_select(Message::ButtonPressed("View".into())))
-        .push(MenuItem::new("Edit").on_select(Message::ButtonPressed("Edit".into())))
+        .sizes(sizes)
+        .push(MenuItem::new("View").sizes(sizes).on_select(Message::ButtonPressed("View".into())))
+        .push(MenuItem::new("Edit").sizes(sizes).on_select(Message::ButtonPressed("Edit".into())))
         .width(150);
 
     let dropdown_neutral = dropdown(
         Button::new("Neutral")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Neutral".into())),
@@ -88,11 +126,17 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_warning = Menu::new()
-        .push(MenuItem::new("Warning Action").on_select(Message::ButtonPressed("Warning".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("Warning Action")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Warning".into())),
+        )
         .width(150);
 
     let dropdown_warning = dropdown(
         Button::new("Warning")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Warning".into())),
@@ -102,12 +146,22 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_danger = Menu::new()
-        .push(MenuItem::new("Delete").on_select(Message::ButtonPressed("Delete".into())))
-        .push(MenuItem::new("Remove").on_select(Message::ButtonPressed("Remove".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("Delete")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Delete".into())),
+        )
+        .push(
+            MenuItem::new("Remove")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Remove".into())),
+        )
         .width(150);
 
     let dropdown_danger = dropdown(
         Button::new("Danger")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Danger".into())),
@@ -126,17 +180,19 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Placements
-    let placements_title = text("Dropdown Placements").size(24);
+    let placements_title = Heading::new("Dropdown Placements").sizes(sizes).role(Role::H2);
     let placements_desc = text("Dropdowns support 12 placement options").size(14);
 
     // Top placements
     let menu_top = Menu::new()
-        .push(MenuItem::new("Top Item 1"))
-        .push(MenuItem::new("Top Item 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Top Item 1").sizes(sizes))
+        .push(MenuItem::new("Top Item 2").sizes(sizes))
         .width(120);
 
     let dropdown_top = dropdown(
         Button::new("Top")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Top".into())),
         menu_top,
@@ -146,12 +202,14 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_top_start = Menu::new()
-        .push(MenuItem::new("Top Start 1"))
-        .push(MenuItem::new("Top Start 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Top Start 1").sizes(sizes))
+        .push(MenuItem::new("Top Start 2").sizes(sizes))
         .width(120);
 
     let dropdown_top_start = dropdown(
         Button::new("Top Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("TopStart".into())),
         menu_top_start,
@@ -161,12 +219,14 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_top_end = Menu::new()
-        .push(MenuItem::new("Top End 1"))
-        .push(MenuItem::new("Top End 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Top End 1").sizes(sizes))
+        .push(MenuItem::new("Top End 2").sizes(sizes))
         .width(120);
 
     let dropdown_top_end = dropdown(
         Button::new("Top End")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("TopEnd".into())),
         menu_top_end,
@@ -184,12 +244,14 @@ pub fn page() -> Element<'static, Message> {
 
     // Bottom placements (most common)
     let menu_bottom = Menu::new()
-        .push(MenuItem::new("Bottom Item 1"))
-        .push(MenuItem::new("Bottom Item 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Bottom Item 1").sizes(sizes))
+        .push(MenuItem::new("Bottom Item 2").sizes(sizes))
         .width(120);
 
     let dropdown_bottom = dropdown(
         Button::new("Bottom")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Bottom".into())),
         menu_bottom,
@@ -199,12 +261,14 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_bottom_start = Menu::new()
-        .push(MenuItem::new("Bottom Start 1"))
-        .push(MenuItem::new("Bottom Start 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Bottom Start 1").sizes(sizes))
+        .push(MenuItem::new("Bottom Start 2").sizes(sizes))
         .width(150);
 
     let dropdown_bottom_start = dropdown(
         Button::new("Bottom Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("BottomStart".into())),
         menu_bottom_start,
@@ -214,12 +278,14 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     let menu_bottom_end = Menu::new()
-        .push(MenuItem::new("Bottom End 1"))
-        .push(MenuItem::new("Bottom End 2"))
+        .sizes(sizes)
+        .push(MenuItem::new("Bottom End 1").sizes(sizes))
+        .push(MenuItem::new("Bottom End 2").sizes(sizes))
         .width(120);
 
     let dropdown_bottom_end = dropdown(
         Button::new("Bottom End")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("BottomEnd".into())),
         menu_bottom_end,
@@ -236,27 +302,37 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Grouped menu items
-    let grouped_title = text("Grouped Menu Items").size(24);
+    let grouped_title = Heading::new("Grouped Menu Items").sizes(sizes).role(Role::H2);
     let grouped_desc = text("Use menu labels and dividers to organize items").size(14);
 
     let grouped_menu = Menu::new()
-        .push(MenuLabel::new("File"))
-        .push(MenuItem::new("New").on_select(Message::ButtonPressed("New".into())))
-        .push(MenuItem::new("Open").on_select(Message::ButtonPressed("Open".into())))
-        .push(MenuItem::new("Save").on_select(Message::ButtonPressed("Save".into())))
-        .push(Divider::new())
-        .push(MenuLabel::new("Edit"))
-        .push(MenuItem::new("Cut").on_select(Message::ButtonPressed("Cut".into())))
-        .push(MenuItem::new("Copy").on_select(Message::ButtonPressed("Copy".into())))
-        .push(MenuItem::new("Paste").on_select(Message::ButtonPressed("Paste".into())))
-        .push(Divider::new())
-        .push(MenuLabel::new("View"))
-        .push(MenuItem::new("Zoom In").on_select(Message::ButtonPressed("Zoom In".into())))
-        .push(MenuItem::new("Zoom Out").on_select(Message::ButtonPressed("Zoom Out".into())))
+        .sizes(sizes)
+        .push(MenuLabel::new("File").sizes(sizes))
+        .push(MenuItem::new("New").sizes(sizes).on_select(Message::ButtonPressed("New".into())))
+        .push(MenuItem::new("Open").sizes(sizes).on_select(Message::ButtonPressed("Open".into())))
+        .push(MenuItem::new("Save").sizes(sizes).on_select(Message::ButtonPressed("Save".into())))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("Edit").sizes(sizes))
+        .push(MenuItem::new("Cut").sizes(sizes).on_select(Message::ButtonPressed("Cut".into())))
+        .push(MenuItem::new("Copy").sizes(sizes).on_select(Message::ButtonPressed("Copy".into())))
+        .push(MenuItem::new("Paste").sizes(sizes).on_select(Message::ButtonPressed("Paste".into())))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("View").sizes(sizes))
+        .push(
+            MenuItem::new("Zoom In")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Zoom In".into())),
+        )
+        .push(
+            MenuItem::new("Zoom Out")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Zoom Out".into())),
+        )
         .width(200);
 
     let grouped_dropdown = dropdown(
         Button::new("File Menu")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .suffix("▼")
             .on_press(Message::ButtonPressed("File Menu".into())),
@@ -267,29 +343,37 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     // Menu item types
-    let types_title = text("Menu Item Types").size(24);
+    let types_title = Heading::new("Menu Item Types").sizes(sizes).role(Role::H2);
     let types_desc = text("Menu items can be normal, checkbox, or disabled").size(14);
 
     let types_menu = Menu::new()
-        .push(MenuItem::new("Normal Item").on_select(Message::ButtonPressed("Normal".into())))
+        .sizes(sizes)
+        .push(
+            MenuItem::new("Normal Item")
+                .sizes(sizes)
+                .on_select(Message::ButtonPressed("Normal".into())),
+        )
         .push(
             MenuItem::new("Checked Item")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::ButtonPressed("Checked".into())),
         )
         .push(
             MenuItem::new("Unchecked Item")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::ButtonPressed("Unchecked".into())),
         )
-        .push(Divider::new())
-        .push(MenuItem::new("Disabled Item").disabled(true))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuItem::new("Disabled Item").sizes(sizes).disabled(true))
         .width(200);
 
     let types_dropdown = dropdown(
         Button::new("Menu Types")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .suffix("▼")
             .on_press(Message::ButtonPressed("Menu Types".into())),
@@ -300,36 +384,42 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     // Icon menu items
-    let icons_title = text("Menu Items with Icons").size(24);
+    let icons_title = Heading::new("Menu Items with Icons").sizes(sizes).role(Role::H2);
     let icons_desc = text("Add prefix and suffix icons to menu items").size(14);
 
     let icons_menu = Menu::new()
+        .sizes(sizes)
         .push(
             MenuItem::new("New File")
+                .sizes(sizes)
                 .prefix("📄")
                 .suffix("Ctrl+N")
                 .on_select(Message::ButtonPressed("New File".into())),
         )
         .push(
             MenuItem::new("Open Folder")
+                .sizes(sizes)
                 .prefix("📁")
                 .suffix("Ctrl+O")
                 .on_select(Message::ButtonPressed("Open Folder".into())),
         )
         .push(
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .suffix("Ctrl+S")
                 .on_select(Message::ButtonPressed("Save".into())),
         )
-        .push(Divider::new())
+        .push(Divider::new().sizes(sizes))
         .push(
             MenuItem::new("Settings")
+                .sizes(sizes)
                 .prefix("⚙️")
                 .on_select(Message::ButtonPressed("Settings".into())),
         )
         .push(
             MenuItem::new("Help")
+                .sizes(sizes)
                 .prefix("❓")
                 .suffix("F1")
                 .on_select(Message::ButtonPressed("Help".into())),
@@ -338,6 +428,7 @@ pub fn page() -> Element<'static, Message> {
 
     let icons_dropdown = dropdown(
         Button::new("File")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .suffix("▼")
             .on_press(Message::ButtonPressed("File".into())),
@@ -348,15 +439,17 @@ pub fn page() -> Element<'static, Message> {
     .distance(4.0);
 
     // Distance customization
-    let distance_title = text("Custom Distance").size(24);
+    let distance_title = Heading::new("Custom Distance").sizes(sizes).role(Role::H2);
     let distance_desc = text("Control the gap between dropdown and trigger").size(14);
 
     let menu_default_distance = Menu::new()
-        .push(MenuItem::new("Default Distance (4px)"))
+        .sizes(sizes)
+        .push(MenuItem::new("Default Distance (4px)").sizes(sizes))
         .width(180);
 
     let dropdown_default_distance = dropdown(
         Button::new("Default (4px)")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Default Distance".into())),
         menu_default_distance,
@@ -365,11 +458,13 @@ pub fn page() -> Element<'static, Message> {
     .placement(PopupPlacement::Bottom);
 
     let menu_custom_distance = Menu::new()
-        .push(MenuItem::new("Custom Distance (16px)"))
+        .sizes(sizes)
+        .push(MenuItem::new("Custom Distance (16px)").sizes(sizes))
         .width(180);
 
     let dropdown_custom_distance = dropdown(
         Button::new("Custom (16px)")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Custom Distance".into())),
         menu_custom_distance,
@@ -385,7 +480,7 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Usage notes
-    let notes_title = text("Usage Notes").size(24);
+    let notes_title = Heading::new("Usage Notes").sizes(sizes).role(Role::H2);
     let notes = column![
         text("• Dropdowns combine a trigger element with a menu"),
         text("• Typically used with buttons as triggers"),
@@ -400,7 +495,7 @@ pub fn page() -> Element<'static, Message> {
     .spacing(10);
 
     // State management example
-    let state_title = text("State Management Example").size(24);
+    let state_title = Heading::new("State Management Example").sizes(sizes).role(Role::H2);
     let state_desc = text(
         "In practice, you'd toggle the 'open' state in response to button clicks"
     )
@@ -416,6 +511,7 @@ Message::ToggleDropdown => {
 // In your view:
 dropdown(
     Button::new("Options")
+        .sizes(sizes)
         .on_press(Message::ToggleDropdown),
     menu().push(menu_item("Option 1")),
     self.dropdown_open, // State controlled here
@@ -423,7 +519,7 @@ dropdown(
         )
         .size(12),
     )
-    .padding(SPACING.small)
+    .padding(sizes.spacing.small)
     .class(ContainerStyleClass::Card);
 
     column![
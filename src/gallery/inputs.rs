@@ -1,7 +1,10 @@
 use iced::alignment;
 use iced_widget::{Row, column, text};
 
+use crate::components::Heading;
 use crate::components::input::{Input, InputSize, InputType};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
 #[derive(Debug, Clone)]
@@ -104,33 +107,37 @@ pub fn handle_input_message(state: &mut InputState, message: InputMessage) {
     }
 }
 
-pub fn view(state: &InputState) -> Element<'_, Message> {
-    let title = text("Inputs").size(32);
+pub fn view(state: &InputState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Inputs").sizes(sizes);
     let description = text("Input fields allow users to enter text data").size(14);
 
     // Basic input
-    let basic_title = text("Basic").size(24);
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
     let basic_input = Input::new("Type something...")
+        .sizes(sizes)
         .value(&state.basic)
         .on_input(|value| Message::Input(InputMessage::BasicChanged(value)));
 
     // With Label
-    let label_title = text("With Label").size(24);
+    let label_title = Heading::new("With Label").sizes(sizes).role(Role::H2);
     let label_input = Input::new("Enter your name")
+        .sizes(sizes)
         .label("Name")
         .value(&state.name)
         .on_input(|value| Message::Input(InputMessage::NameChanged(value)));
 
     // With Value
-    let value_title = text("With Value").size(24);
+    let value_title = Heading::new("With Value").sizes(sizes).role(Role::H2);
     let value_input = Input::new("Enter your email")
+        .sizes(sizes)
         .label("Email")
         .value(&state.email)
         .on_input(|value| Message::Input(InputMessage::EmailChanged(value)));
 
     // Help Text
-    let help_text_title = text("Help Text").size(24);
+    let help_text_title = Heading::new("Help Text").sizes(sizes).role(Role::H2);
     let help_text_input = Input::new("Enter your password")
+        .sizes(sizes)
         .label("Password")
         .input_type(InputType::Password)
         .value(&state.password)
@@ -138,17 +145,20 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
         .on_input(|value| Message::Input(InputMessage::PasswordChanged(value)));
 
     // Sizes
-    let sizes_title = text("Sizes").size(24);
+    let sizes_title = Heading::new("Sizes").sizes(sizes).role(Role::H2);
     let sizes_column = column![
         Input::new("Small input")
+            .sizes(sizes)
             .size(InputSize::Small)
             .value(&state.small)
             .on_input(|value| Message::Input(InputMessage::SmallChanged(value))),
         Input::new("Medium input (default)")
+            .sizes(sizes)
             .size(InputSize::Medium)
             .value(&state.medium)
             .on_input(|value| Message::Input(InputMessage::MediumChanged(value))),
         Input::new("Large input")
+            .sizes(sizes)
             .size(InputSize::Large)
             .value(&state.large)
             .on_input(|value| Message::Input(InputMessage::LargeChanged(value))),
@@ -156,34 +166,40 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
     .spacing(15);
 
     // Input Types
-    let types_title = text("Input Types").size(24);
+    let types_title = Heading::new("Input Types").sizes(sizes).role(Role::H2);
     let types_column = column![
         Input::new("Enter text")
+            .sizes(sizes)
             .label("Text")
             .input_type(InputType::Text)
             .value(&state.text)
             .on_input(|value| Message::Input(InputMessage::TextChanged(value))),
         Input::new("Enter email")
+            .sizes(sizes)
             .label("Email")
             .input_type(InputType::Email)
             .value(&state.email_type)
             .on_input(|value| Message::Input(InputMessage::EmailTypeChanged(value))),
         Input::new("Enter password")
+            .sizes(sizes)
             .label("Password")
             .input_type(InputType::Password)
             .value(&state.password_type)
             .on_input(|value| Message::Input(InputMessage::PasswordTypeChanged(value))),
         Input::new("Enter number")
+            .sizes(sizes)
             .label("Number")
             .input_type(InputType::Number)
             .value(&state.number)
             .on_input(|value| Message::Input(InputMessage::NumberChanged(value))),
         Input::new("Enter phone")
+            .sizes(sizes)
             .label("Telephone")
             .input_type(InputType::Tel)
             .value(&state.tel)
             .on_input(|value| Message::Input(InputMessage::TelChanged(value))),
         Input::new("Enter URL")
+            .sizes(sizes)
             .label("URL")
             .input_type(InputType::Url)
             .value(&state.url)
@@ -192,22 +208,25 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
     .spacing(15);
 
     // Filled Variant
-    let filled_title = text("Filled").size(24);
+    let filled_title = Heading::new("Filled").sizes(sizes).role(Role::H2);
     let filled_input = Input::new("Type something...")
+        .sizes(sizes)
         .label("Filled Input")
         .filled(true)
         .value(&state.filled)
         .on_input(|value| Message::Input(InputMessage::FilledChanged(value)));
 
     // Pill Variant
-    let pill_title = text("Pill").size(24);
+    let pill_title = Heading::new("Pill").sizes(sizes).role(Role::H2);
     let pill_row = Row::with_children([
         Input::new("Standard")
+            .sizes(sizes)
             .size(InputSize::Small)
             .value(&state.pill_standard)
             .on_input(|value| Message::Input(InputMessage::PillStandardChanged(value)))
             .into(),
         Input::new("Pill shaped")
+            .sizes(sizes)
             .size(InputSize::Small)
             .pill(true)
             .value(&state.pill)
@@ -218,31 +237,38 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
     .align_y(alignment::Vertical::Center);
 
     // Disabled
-    let disabled_title = text("Disabled").size(24);
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
     let disabled_input = Input::new("You can't type here")
+        .sizes(sizes)
         .label("Disabled Input")
         .value("Disabled value")
         .disabled(true);
 
     // Readonly
-    let readonly_title = text("Readonly").size(24);
+    let readonly_title = Heading::new("Readonly").sizes(sizes).role(Role::H2);
     let readonly_input = Input::new("You can't edit this")
+        .sizes(sizes)
         .label("Readonly Input")
         .value("This is readonly")
         .readonly(true);
 
     // Different placeholders
-    let placeholders_title = text("Different Placeholders").size(24);
+    let placeholders_title = Heading::new("Different Placeholders")
+        .sizes(sizes)
+        .role(Role::H2);
     let placeholders_column = column![
         Input::new("Enter your first name")
+            .sizes(sizes)
             .label("First Name")
             .value(&state.first_name)
             .on_input(|value| Message::Input(InputMessage::FirstNameChanged(value))),
         Input::new("Enter your last name")
+            .sizes(sizes)
             .label("Last Name")
             .value(&state.last_name)
             .on_input(|value| Message::Input(InputMessage::LastNameChanged(value))),
         Input::new("Search for products...")
+            .sizes(sizes)
             .label("Search")
             .input_type(InputType::Search)
             .value(&state.search)
@@ -251,8 +277,9 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
     .spacing(15);
 
     // Filled + Pill combination
-    let combo_title = text("Filled + Pill").size(24);
+    let combo_title = Heading::new("Filled + Pill").sizes(sizes).role(Role::H2);
     let combo_input = Input::new("Search...")
+        .sizes(sizes)
         .filled(true)
         .pill(true)
         .input_type(InputType::Search)
@@ -260,16 +287,18 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
         .on_input(|value| Message::Input(InputMessage::ComboChanged(value)));
 
     // Required Fields
-    let required_title = text("Required Fields").size(24);
+    let required_title = Heading::new("Required Fields").sizes(sizes).role(Role::H2);
     let required_desc = text("Required fields are marked with an asterisk (*)").size(14);
     let required_column = column![
         Input::new("Enter your email")
+            .sizes(sizes)
             .label("Email")
             .input_type(InputType::Email)
             .required(true)
             .value(&state.email_type)
             .on_input(|value| Message::Input(InputMessage::EmailTypeChanged(value))),
         Input::new("Enter your name")
+            .sizes(sizes)
             .label("Full Name")
             .required(true)
             .value(&state.first_name)
@@ -278,9 +307,12 @@ pub fn view(state: &InputState) -> Element<'_, Message> {
     .spacing(15);
 
     // Combined Features
-    let combined_title = text("Combined Features").size(24);
+    let combined_title = Heading::new("Combined Features")
+        .sizes(sizes)
+        .role(Role::H2);
     let combined_desc = text("Inputs can combine multiple features").size(14);
     let combined_input = Input::new("Search...")
+        .sizes(sizes)
         .label("Advanced Search")
         .filled(true)
         .pill(true)
@@ -1,21 +1,24 @@
 use iced::Length;
 use iced_widget::{column, container, text};
 
+use crate::components::Heading;
 use crate::components::{Divider, MenuItem, MenuItemType, MenuLabel};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Menu Items").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Menu Items").sizes(sizes);
     let description = text("Menu items are clickable elements that can be used in menus and dropdowns").size(14);
 
     // Basic menu items
-    let basic_title = text("Basic Menu Items").size(24);
+    let basic_title = Heading::new("Basic Menu Items").sizes(sizes).role(Role::H2);
     let basic_desc = text("Simple clickable menu items").size(14);
     let basic_menu = container(
         column![
-            MenuItem::new("Option 1").on_select(Message::MenuItemSelected),
-            MenuItem::new("Option 2").on_select(Message::MenuItemSelected),
-            MenuItem::new("Option 3").on_select(Message::MenuItemSelected),
+            MenuItem::new("Option 1").sizes(sizes).on_select(Message::MenuItemSelected),
+            MenuItem::new("Option 2").sizes(sizes).on_select(Message::MenuItemSelected),
+            MenuItem::new("Option 3").sizes(sizes).on_select(Message::MenuItemSelected),
         ]
         .spacing(4)
     )
@@ -23,17 +26,20 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Menu items with prefix icons
-    let prefix_title = text("Menu Items with Prefix").size(24);
+    let prefix_title = Heading::new("Menu Items with Prefix").sizes(sizes).role(Role::H2);
     let prefix_desc = text("Add icons or text before the label").size(14);
     let prefix_menu = container(
         column![
             MenuItem::new("New File")
+                .sizes(sizes)
                 .prefix("📄")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Open Folder")
+                .sizes(sizes)
                 .prefix("📁")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .on_select(Message::MenuItemSelected),
         ]
@@ -43,19 +49,22 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Menu items with suffix content
-    let suffix_title = text("Menu Items with Suffix").size(24);
+    let suffix_title = Heading::new("Menu Items with Suffix").sizes(sizes).role(Role::H2);
     let suffix_desc = text("Show keyboard shortcuts or additional info").size(14);
     let suffix_menu = container(
         column![
             MenuItem::new("Copy")
+                .sizes(sizes)
                 .prefix("📋")
                 .suffix("⌘C")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Cut")
+                .sizes(sizes)
                 .prefix("✂️")
                 .suffix("⌘X")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Paste")
+                .sizes(sizes)
                 .prefix("📌")
                 .suffix("⌘V")
                 .on_select(Message::MenuItemSelected),
@@ -66,16 +75,19 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Disabled menu items
-    let disabled_title = text("Disabled Menu Items").size(24);
+    let disabled_title = Heading::new("Disabled Menu Items").sizes(sizes).role(Role::H2);
     let disabled_desc = text("Use disabled state for unavailable options").size(14);
     let disabled_menu = container(
         column![
             MenuItem::new("Available Option")
+                .sizes(sizes)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Disabled Option")
+                .sizes(sizes)
                 .disabled(true)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Another Available")
+                .sizes(sizes)
                 .on_select(Message::MenuItemSelected),
         ]
         .spacing(4)
@@ -84,16 +96,19 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Loading menu items
-    let loading_title = text("Loading State").size(24);
+    let loading_title = Heading::new("Loading State").sizes(sizes).role(Role::H2);
     let loading_desc = text("Show loading state for async operations").size(14);
     let loading_menu = container(
         column![
             MenuItem::new("Normal Item")
+                .sizes(sizes)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Loading Item")
+                .sizes(sizes)
                 .loading(true)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Another Normal Item")
+                .sizes(sizes)
                 .on_select(Message::MenuItemSelected),
         ]
         .spacing(4)
@@ -102,19 +117,22 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Checkbox menu items
-    let checkbox_title = text("Checkbox Menu Items").size(24);
+    let checkbox_title = Heading::new("Checkbox Menu Items").sizes(sizes).role(Role::H2);
     let checkbox_desc = text("Use checkbox items for toggleable options").size(14);
     let checkbox_menu = container(
         column![
             MenuItem::new("Show Line Numbers")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Show Minimap")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Word Wrap")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
@@ -125,47 +143,56 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(250.0));
 
     // Complete menu example with labels and dividers
-    let complete_title = text("Complete Menu Example").size(24);
+    let complete_title = Heading::new("Complete Menu Example").sizes(sizes).role(Role::H2);
     let complete_desc = text("A full menu with labels, dividers, and various item types").size(14);
     let complete_menu = container(
         column![
-            MenuLabel::new("File Operations"),
+            MenuLabel::new("File Operations").sizes(sizes),
             MenuItem::new("New")
+                .sizes(sizes)
                 .prefix("📄")
                 .suffix("⌘N")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Open")
+                .sizes(sizes)
                 .prefix("📁")
                 .suffix("⌘O")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .suffix("⌘S")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Save As")
+                .sizes(sizes)
                 .prefix("💾")
                 .disabled(true)
                 .on_select(Message::MenuItemSelected),
-            Divider::new(),
-            MenuLabel::new("View Options"),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("View Options").sizes(sizes),
             MenuItem::new("Show Sidebar")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Show Status Bar")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Full Screen")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
-            Divider::new(),
-            MenuLabel::new("Actions"),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Actions").sizes(sizes),
             MenuItem::new("Refresh")
+                .sizes(sizes)
                 .prefix("🔄")
                 .on_select(Message::MenuItemSelected),
             MenuItem::new("Settings")
+                .sizes(sizes)
                 .prefix("⚙️")
                 .on_select(Message::MenuItemSelected),
         ]
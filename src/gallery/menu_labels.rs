@@ -1,39 +1,42 @@
 use iced::Length;
 use iced_widget::{column, container, text};
 
+use crate::components::Heading;
 use crate::components::{Divider, MenuLabel};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Menu Labels").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Menu Labels").sizes(sizes);
     let description = text("Use menu labels to group and describe related menu items").size(14);
 
     // Basic menu label
-    let basic_title = text("Basic Menu Label").size(24);
+    let basic_title = Heading::new("Basic Menu Label").sizes(sizes).role(Role::H2);
     let basic_desc = text("A simple menu label that describes a section").size(14);
-    let basic_label = MenuLabel::new("Section Title");
+    let basic_label = MenuLabel::new("Section Title").sizes(sizes);
 
     // Uppercase menu label
-    let uppercase_title = text("Uppercase Menu Labels").size(24);
+    let uppercase_title = Heading::new("Uppercase Menu Labels").sizes(sizes).role(Role::H2);
     let uppercase_desc = text("Use uppercase to create more prominent section headers").size(14);
-    let uppercase_label = MenuLabel::new("Section Title").uppercase(true);
+    let uppercase_label = MenuLabel::new("Section Title").sizes(sizes).uppercase(true);
 
     // Menu example
-    let menu_title = text("Menu with Labels").size(24);
+    let menu_title = Heading::new("Menu with Labels").sizes(sizes).role(Role::H2);
     let menu_desc = text("A typical menu structure using menu labels").size(14);
     let menu_example = container(
         column![
-            MenuLabel::new("Fruits"),
+            MenuLabel::new("Fruits").sizes(sizes),
             text("Apple").size(14),
             text("Banana").size(14),
             text("Orange").size(14),
-            Divider::new(),
-            MenuLabel::new("Vegetables"),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Vegetables").sizes(sizes),
             text("Broccoli").size(14),
             text("Carrot").size(14),
             text("Zucchini").size(14),
-            Divider::new(),
-            MenuLabel::new("Beverages"),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Beverages").sizes(sizes),
             text("Water").size(14),
             text("Juice").size(14),
             text("Tea").size(14),
@@ -44,21 +47,23 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(200.0));
 
     // Menu with uppercase labels
-    let uppercase_menu_title = text("Menu with Uppercase Labels").size(24);
+    let uppercase_menu_title = Heading::new("Menu with Uppercase Labels")
+        .sizes(sizes)
+        .role(Role::H2);
     let uppercase_menu_desc = text("Using uppercase for more prominent headers").size(14);
     let uppercase_menu_example = container(
         column![
-            MenuLabel::new("File").uppercase(true),
+            MenuLabel::new("File").sizes(sizes).uppercase(true),
             text("New").size(14),
             text("Open").size(14),
             text("Save").size(14),
-            Divider::new(),
-            MenuLabel::new("Edit").uppercase(true),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Edit").sizes(sizes).uppercase(true),
             text("Cut").size(14),
             text("Copy").size(14),
             text("Paste").size(14),
-            Divider::new(),
-            MenuLabel::new("View").uppercase(true),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("View").sizes(sizes).uppercase(true),
             text("Zoom In").size(14),
             text("Zoom Out").size(14),
             text("Full Screen").size(14),
@@ -69,21 +74,21 @@ pub fn page() -> Element<'static, Message> {
     .width(Length::Fixed(200.0));
 
     // Sidebar navigation example
-    let sidebar_title = text("Sidebar Navigation").size(24);
+    let sidebar_title = Heading::new("Sidebar Navigation").sizes(sizes).role(Role::H2);
     let sidebar_desc = text("Use menu labels to organize navigation items").size(14);
     let sidebar_example = container(
         column![
-            MenuLabel::new("Dashboard").uppercase(true),
+            MenuLabel::new("Dashboard").sizes(sizes).uppercase(true),
             text("Overview").size(14),
             text("Analytics").size(14),
             text("Reports").size(14),
-            Divider::new(),
-            MenuLabel::new("Content").uppercase(true),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Content").sizes(sizes).uppercase(true),
             text("Posts").size(14),
             text("Pages").size(14),
             text("Media").size(14),
-            Divider::new(),
-            MenuLabel::new("Settings").uppercase(true),
+            Divider::new().sizes(sizes),
+            MenuLabel::new("Settings").sizes(sizes).uppercase(true),
             text("General").size(14),
             text("Profile").size(14),
             text("Security").size(14),
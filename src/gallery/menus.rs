@@ -1,61 +1,73 @@
 use iced::Length;
 use iced_widget::{column, text};
 
+use crate::components::Heading;
 use crate::components::{Divider, Menu, MenuItem, MenuItemType, MenuLabel};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Menus").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Menus").sizes(sizes);
     let description = text("Menus provide a list of options for the user to choose from").size(14);
 
     // Basic menu
-    let basic_title = text("Basic Menu").size(24);
+    let basic_title = Heading::new("Basic Menu").sizes(sizes).role(Role::H2);
     let basic_desc = text("A simple menu with clickable items").size(14);
     let basic_menu = Menu::new()
-        .push(MenuItem::new("Option 1").on_select(Message::MenuItemSelected))
-        .push(MenuItem::new("Option 2").on_select(Message::MenuItemSelected))
-        .push(MenuItem::new("Option 3").on_select(Message::MenuItemSelected))
+        .sizes(sizes)
+        .push(MenuItem::new("Option 1").sizes(sizes).on_select(Message::MenuItemSelected))
+        .push(MenuItem::new("Option 2").sizes(sizes).on_select(Message::MenuItemSelected))
+        .push(MenuItem::new("Option 3").sizes(sizes).on_select(Message::MenuItemSelected))
         .width(Length::Fixed(250.0));
 
     // Menu with icons
-    let icons_title = text("Menu with Icons").size(24);
+    let icons_title = Heading::new("Menu with Icons").sizes(sizes).role(Role::H2);
     let icons_desc = text("Add visual indicators with prefix icons").size(14);
     let icons_menu = Menu::new()
+        .sizes(sizes)
         .push(
             MenuItem::new("New File")
+                .sizes(sizes)
                 .prefix("📄")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Open Folder")
+                .sizes(sizes)
                 .prefix("📁")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .on_select(Message::MenuItemSelected),
         )
         .width(Length::Fixed(250.0));
 
     // Menu with keyboard shortcuts
-    let shortcuts_title = text("Menu with Shortcuts").size(24);
+    let shortcuts_title = Heading::new("Menu with Shortcuts").sizes(sizes).role(Role::H2);
     let shortcuts_desc = text("Show keyboard shortcuts in the suffix").size(14);
     let shortcuts_menu = Menu::new()
+        .sizes(sizes)
         .push(
             MenuItem::new("Copy")
+                .sizes(sizes)
                 .prefix("📋")
                 .suffix("⌘C")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Cut")
+                .sizes(sizes)
                 .prefix("✂️")
                 .suffix("⌘X")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Paste")
+                .sizes(sizes)
                 .prefix("📌")
                 .suffix("⌘V")
                 .on_select(Message::MenuItemSelected),
@@ -63,44 +75,51 @@ pub fn page() -> Element<'static, Message> {
         .width(Length::Fixed(250.0));
 
     // Menu with labels and dividers
-    let grouped_title = text("Grouped Menu").size(24);
+    let grouped_title = Heading::new("Grouped Menu").sizes(sizes).role(Role::H2);
     let grouped_desc = text("Organize menu items with labels and dividers").size(14);
     let grouped_menu = Menu::new()
-        .push(MenuLabel::new("File Operations"))
+        .sizes(sizes)
+        .push(MenuLabel::new("File Operations").sizes(sizes))
         .push(
             MenuItem::new("New")
+                .sizes(sizes)
                 .prefix("📄")
                 .suffix("⌘N")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Open")
+                .sizes(sizes)
                 .prefix("📁")
                 .suffix("⌘O")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .suffix("⌘S")
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
-        .push(MenuLabel::new("Edit"))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("Edit").sizes(sizes))
         .push(
             MenuItem::new("Cut")
+                .sizes(sizes)
                 .prefix("✂️")
                 .suffix("⌘X")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Copy")
+                .sizes(sizes)
                 .prefix("📋")
                 .suffix("⌘C")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Paste")
+                .sizes(sizes)
                 .prefix("📌")
                 .suffix("⌘V")
                 .on_select(Message::MenuItemSelected),
@@ -108,38 +127,44 @@ pub fn page() -> Element<'static, Message> {
         .width(Length::Fixed(250.0));
 
     // Menu with checkbox items
-    let checkbox_title = text("Menu with Checkboxes").size(24);
+    let checkbox_title = Heading::new("Menu with Checkboxes").sizes(sizes).role(Role::H2);
     let checkbox_desc = text("Use checkbox items for toggleable options").size(14);
     let checkbox_menu = Menu::new()
-        .push(MenuLabel::new("View Options"))
+        .sizes(sizes)
+        .push(MenuLabel::new("View Options").sizes(sizes))
         .push(
             MenuItem::new("Show Line Numbers")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Show Minimap")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Word Wrap")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
-        .push(MenuLabel::new("Editor"))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("Editor").sizes(sizes))
         .push(
             MenuItem::new("Auto Save")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Format on Save")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
@@ -147,33 +172,39 @@ pub fn page() -> Element<'static, Message> {
         .width(Length::Fixed(250.0));
 
     // Menu with disabled items
-    let disabled_title = text("Menu with Disabled Items").size(24);
+    let disabled_title = Heading::new("Menu with Disabled Items").sizes(sizes).role(Role::H2);
     let disabled_desc = text("Show unavailable options in disabled state").size(14);
     let disabled_menu = Menu::new()
+        .sizes(sizes)
         .push(
             MenuItem::new("Available Option")
+                .sizes(sizes)
                 .prefix("✓")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Disabled Option")
+                .sizes(sizes)
                 .prefix("✗")
                 .disabled(true)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Another Available")
+                .sizes(sizes)
                 .prefix("✓")
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
+        .push(Divider::new().sizes(sizes))
         .push(
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Save As")
+                .sizes(sizes)
                 .prefix("💾")
                 .disabled(true)
                 .suffix("Not available")
@@ -182,59 +213,69 @@ pub fn page() -> Element<'static, Message> {
         .width(Length::Fixed(250.0));
 
     // Menu with loading state
-    let loading_title = text("Menu with Loading State").size(24);
+    let loading_title = Heading::new("Menu with Loading State").sizes(sizes).role(Role::H2);
     let loading_desc = text("Show loading state for async operations").size(14);
     let loading_menu = Menu::new()
+        .sizes(sizes)
         .push(
             MenuItem::new("Refresh")
+                .sizes(sizes)
                 .prefix("🔄")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Loading Item")
+                .sizes(sizes)
                 .loading(true)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Another Item")
+                .sizes(sizes)
                 .prefix("📦")
                 .on_select(Message::MenuItemSelected),
         )
         .width(Length::Fixed(250.0));
 
     // Complete example menu
-    let complete_title = text("Complete Menu Example").size(24);
+    let complete_title = Heading::new("Complete Menu Example").sizes(sizes).role(Role::H2);
     let complete_desc =
         text("A comprehensive menu with all features combined").size(14);
     let complete_menu = Menu::new()
-        .push(MenuLabel::new("File Operations").uppercase(true))
+        .sizes(sizes)
+        .push(MenuLabel::new("File Operations").sizes(sizes).uppercase(true))
         .push(
             MenuItem::new("New Document")
+                .sizes(sizes)
                 .prefix("📄")
                 .suffix("⌘N")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Open File")
+                .sizes(sizes)
                 .prefix("📁")
                 .suffix("⌘O")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Open Recent")
+                .sizes(sizes)
                 .prefix("📂")
                 .suffix("⌘⇧O")
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
+        .push(Divider::new().sizes(sizes))
         .push(
             MenuItem::new("Save")
+                .sizes(sizes)
                 .prefix("💾")
                 .suffix("⌘S")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Save As")
+                .sizes(sizes)
                 .prefix("💾")
                 .suffix("⌘⇧S")
                 .disabled(true)
@@ -242,45 +283,52 @@ pub fn page() -> Element<'static, Message> {
         )
         .push(
             MenuItem::new("Save All")
+                .sizes(sizes)
                 .prefix("💾")
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
-        .push(MenuLabel::new("View Options").uppercase(true))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("View Options").sizes(sizes).uppercase(true))
         .push(
             MenuItem::new("Show Sidebar")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Show Status Bar")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(false)
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Show Minimap")
+                .sizes(sizes)
                 .item_type(MenuItemType::Checkbox)
                 .checked(true)
                 .on_select(Message::MenuItemSelected),
         )
-        .push(Divider::new())
-        .push(MenuLabel::new("Actions").uppercase(true))
+        .push(Divider::new().sizes(sizes))
+        .push(MenuLabel::new("Actions").sizes(sizes).uppercase(true))
         .push(
             MenuItem::new("Refresh")
+                .sizes(sizes)
                 .prefix("🔄")
                 .suffix("F5")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Settings")
+                .sizes(sizes)
                 .prefix("⚙️")
                 .suffix("⌘,")
                 .on_select(Message::MenuItemSelected),
         )
         .push(
             MenuItem::new("Exit")
+                .sizes(sizes)
                 .prefix("🚪")
                 .suffix("⌘Q")
                 .on_select(Message::MenuItemSelected),
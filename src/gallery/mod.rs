@@ -1,18 +1,28 @@
 use iced::Length;
 use iced_widget::{Row, column, container, text};
 
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::components::scrollable;
-use crate::theme::Theme;
+use crate::components::toast_stack;
+use crate::theme::{CustomTheme, Theme};
 use crate::theme::button::{ButtonSize, ButtonVariant};
+use crate::theme::pallete::{generate_scale, hsl};
+use crate::theme::sizes::{Density, Sizes};
+use crate::theme::system::Preference;
+use crate::theme::typography::{Fonts, Role};
 use crate::{Element, Message, Page};
 
+mod alerts;
+mod avatars;
 mod badges;
 mod breadcrumbs;
 mod button_groups;
 mod buttons;
 mod cards;
 mod checkboxes;
+mod color_pickers;
+mod details;
 mod dialogs;
 mod dividers;
 mod dropdowns;
@@ -22,39 +32,166 @@ mod menu_labels;
 mod menus;
 mod overview;
 mod popups;
+mod progress;
+mod ranges;
+mod ratings;
 mod scrollables;
+mod skeletons;
+mod split_panels;
+mod tab_groups;
+mod tags;
 mod tooltips;
+mod trees;
 
+pub use alerts::{AlertMessage, AlertState, handle_alert_message};
+pub use checkboxes::{CheckboxMessage, CheckboxState, handle_checkbox_message};
+pub use color_pickers::{ColorPickerMessage, ColorPickerState, handle_color_picker_message};
+pub use details::{DetailsMessage, DetailsState, handle_details_message};
 pub use dialogs::{DialogMessage, DialogState, handle_dialog_message};
 pub use inputs::{InputMessage, InputState, handle_input_message};
+pub use progress::{ProgressMessage, ProgressState, handle_progress_message};
+pub use ranges::{RangeMessage, RangeState, handle_range_message};
+pub use ratings::{RatingMessage, RatingState, handle_rating_message};
+pub use split_panels::{SplitPanelMessage, SplitPanelState, handle_split_panel_message};
+pub use tab_groups::{TabGroupMessage, TabGroupState, handle_tab_group_message};
+pub use tags::{TagMessage, TagState, handle_tag_message};
+pub use trees::{TreeGalleryState, TreeMessage, handle_tree_message};
 
-pub fn view<'a>(current_page: Page, dialog_state: &'a DialogState, input_state: &'a InputState) -> Element<'a, Message> {
+pub fn view<'a>(
+    current_page: Page,
+    density: Density,
+    sizes: Sizes,
+    fonts: Fonts,
+    alert_state: &'a AlertState,
+    checkbox_state: &'a CheckboxState,
+    color_picker_state: &'a ColorPickerState,
+    details_state: &'a DetailsState,
+    dialog_state: &'a DialogState,
+    input_state: &'a InputState,
+    progress_state: &'a ProgressState,
+    range_state: &'a RangeState,
+    rating_state: &'a RatingState,
+    split_panel_state: &'a SplitPanelState,
+    tab_group_state: &'a TabGroupState,
+    tag_state: &'a TagState,
+    tree_state: &'a TreeGalleryState,
+) -> Element<'a, Message> {
     let content = Row::new()
-        .push(navigation_sidebar(current_page))
-        .push(page_content(current_page, dialog_state, input_state));
+        .push(navigation_sidebar(current_page, density, sizes))
+        .push(page_content(
+            current_page,
+            sizes,
+            alert_state,
+            checkbox_state,
+            color_picker_state,
+            details_state,
+            dialog_state,
+            input_state,
+            progress_state,
+            range_state,
+            rating_state,
+            split_panel_state,
+            tab_group_state,
+            tag_state,
+            tree_state,
+        ));
 
-    container(content)
+    let content = container(content)
         .width(Length::Fill)
-        .height(Length::Fill)
-        .into()
+        .height(Length::Fill);
+
+    // Toasts pushed from any page are shown above the whole gallery
+    toast_stack(&alert_state.toasts, content, sizes, fonts, |id| {
+        Message::Alert(AlertMessage::ToastDismissed(id))
+    })
+}
+
+/// A custom theme showing how the semantic scales can be swapped at runtime
+fn brand_theme() -> Theme {
+    let light = Theme::Light.tokens();
+
+    CustomTheme::new("Brand", &Theme::Light)
+        .primary(generate_scale(hsl(262.0, 83.0, 58.0), false))
+        .success(light.emerald)
+        .build()
 }
 
-fn navigation_sidebar(current_page: Page) -> Element<'static, Message> {
-    let title = text("Gallery").size(24).width(Length::Fill);
+fn navigation_sidebar(
+    current_page: Page,
+    density: Density,
+    sizes: Sizes,
+) -> Element<'static, Message> {
+    let title = Heading::new("Gallery").sizes(sizes).role(Role::H2).width(Length::Fill);
 
     let theme_dark = Button::new("Dark")
+        .sizes(sizes)
         .size(ButtonSize::Small)
         .variant(ButtonVariant::Default)
-        .on_press(Message::SwitchTheme(Theme::Dark));
+        .on_press(Message::SwitchTheme(Preference::Fixed(Theme::Dark)));
 
     let theme_light = Button::new("Light")
+        .sizes(sizes)
+        .size(ButtonSize::Small)
+        .variant(ButtonVariant::Default)
+        .on_press(Message::SwitchTheme(Preference::Fixed(Theme::Light)));
+
+    let theme_brand = Button::new("Brand")
+        .sizes(sizes)
+        .size(ButtonSize::Small)
+        .variant(ButtonVariant::Default)
+        .on_press(Message::SwitchTheme(Preference::Fixed(brand_theme())));
+
+    let theme_row = Row::with_children([
+        theme_dark.into(),
+        theme_light.into(),
+        theme_brand.into(),
+    ])
+    .spacing(5);
+
+    let theme_high_contrast_light = Button::new("HC Light")
+        .sizes(sizes)
         .size(ButtonSize::Small)
         .variant(ButtonVariant::Default)
-        .on_press(Message::SwitchTheme(Theme::Light));
+        .on_press(Message::SwitchTheme(Preference::Fixed(Theme::HighContrastLight)));
 
-    let theme_row = Row::with_children([theme_dark.into(), theme_light.into()]).spacing(5);
+    let theme_high_contrast_dark = Button::new("HC Dark")
+        .sizes(sizes)
+        .size(ButtonSize::Small)
+        .variant(ButtonVariant::Default)
+        .on_press(Message::SwitchTheme(Preference::Fixed(Theme::HighContrastDark)));
+
+    let theme_system = Button::new("System")
+        .sizes(sizes)
+        .size(ButtonSize::Small)
+        .variant(ButtonVariant::Default)
+        .on_press(Message::SwitchTheme(Preference::System));
+
+    let contrast_row = Row::with_children([
+        theme_system.into(),
+        theme_high_contrast_light.into(),
+        theme_high_contrast_dark.into(),
+    ])
+    .spacing(5);
+
+    let density_row = Row::with_children(Density::ALL.map(|option| {
+        let variant = if option == density {
+            ButtonVariant::Primary
+        } else {
+            ButtonVariant::Default
+        };
+
+        Button::new(option.name())
+            .sizes(sizes)
+            .size(ButtonSize::Small)
+            .variant(variant)
+            .on_press(Message::SwitchDensity(option))
+            .into()
+    }))
+    .spacing(5);
 
-    let mut nav_buttons = column![title, theme_row].spacing(15).padding(10);
+    let mut nav_buttons = column![title, theme_row, contrast_row, density_row]
+        .spacing(15)
+        .padding(10);
 
     // Add divider
     nav_buttons = nav_buttons.push(text("Pages").size(16));
@@ -68,6 +205,7 @@ fn navigation_sidebar(current_page: Page) -> Element<'static, Message> {
         };
 
         let button = Button::new(page.name())
+            .sizes(sizes)
             .variant(variant)
             .size(ButtonSize::Medium)
             .on_press(Message::NavigateToPage(page));
@@ -76,7 +214,7 @@ fn navigation_sidebar(current_page: Page) -> Element<'static, Message> {
     }
 
     // Wrap in scrollable to allow scrolling through all pages
-    let scrollable_nav = scrollable(nav_buttons)
+    let scrollable_nav = scrollable(nav_buttons, sizes)
         .width(Length::Fill)
         .height(Length::Fill);
 
@@ -87,28 +225,56 @@ fn navigation_sidebar(current_page: Page) -> Element<'static, Message> {
         .into()
 }
 
-fn page_content<'a>(page: Page, dialog_state: &'a DialogState, input_state: &'a InputState) -> Element<'a, Message> {
+fn page_content<'a>(
+    page: Page,
+    sizes: Sizes,
+    alert_state: &'a AlertState,
+    checkbox_state: &'a CheckboxState,
+    color_picker_state: &'a ColorPickerState,
+    details_state: &'a DetailsState,
+    dialog_state: &'a DialogState,
+    input_state: &'a InputState,
+    progress_state: &'a ProgressState,
+    range_state: &'a RangeState,
+    rating_state: &'a RatingState,
+    split_panel_state: &'a SplitPanelState,
+    tab_group_state: &'a TabGroupState,
+    tag_state: &'a TagState,
+    tree_state: &'a TreeGalleryState,
+) -> Element<'a, Message> {
     let content: Element<'a, Message> = match page {
-        Page::Overview => overview::page(),
-        Page::Badges => badges::page(),
-        Page::Breadcrumbs => breadcrumbs::page(),
-        Page::Buttons => buttons::page(),
-        Page::ButtonGroups => button_groups::page(),
-        Page::Cards => cards::page(),
-        Page::Checkboxes => checkboxes::page(),
-        Page::Dialogs => dialogs::view(dialog_state),
-        Page::Dividers => dividers::page(),
-        Page::Dropdowns => dropdowns::page(),
-        Page::Inputs => inputs::view(input_state),
-        Page::MenuItems => menu_items::page(),
-        Page::MenuLabels => menu_labels::page(),
-        Page::Menus => menus::page(),
-        Page::Popups => popups::page(),
-        Page::Scrollables => scrollables::page(),
-        Page::Tooltips => tooltips::page(),
+        Page::Overview => overview::page(sizes),
+        Page::Alerts => alerts::view(alert_state, sizes),
+        Page::Avatars => avatars::page(sizes),
+        Page::Badges => badges::page(sizes),
+        Page::Breadcrumbs => breadcrumbs::page(sizes),
+        Page::Buttons => buttons::page(sizes),
+        Page::ButtonGroups => button_groups::page(sizes),
+        Page::Cards => cards::page(sizes),
+        Page::Checkboxes => checkboxes::view(checkbox_state, sizes),
+        Page::ColorPickers => color_pickers::view(color_picker_state, sizes),
+        Page::Details => details::view(details_state, sizes),
+        Page::Dialogs => dialogs::view(dialog_state, sizes),
+        Page::Dividers => dividers::page(sizes),
+        Page::Dropdowns => dropdowns::page(sizes),
+        Page::Inputs => inputs::view(input_state, sizes),
+        Page::MenuItems => menu_items::page(sizes),
+        Page::MenuLabels => menu_labels::page(sizes),
+        Page::Menus => menus::page(sizes),
+        Page::Popups => popups::page(sizes),
+        Page::Progress => progress::view(progress_state, sizes),
+        Page::Ranges => ranges::view(range_state, sizes),
+        Page::Ratings => ratings::view(rating_state, sizes),
+        Page::Scrollables => scrollables::page(sizes),
+        Page::Skeletons => skeletons::page(sizes),
+        Page::SplitPanels => split_panels::view(split_panel_state, sizes),
+        Page::TabGroups => tab_groups::view(tab_group_state, sizes),
+        Page::Tags => tags::view(tag_state, sizes),
+        Page::Tooltips => tooltips::page(sizes),
+        Page::Trees => trees::view(tree_state, sizes),
     };
 
-    scrollable(content)
+    scrollable(content, sizes)
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
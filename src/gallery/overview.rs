@@ -2,14 +2,17 @@ use iced::alignment;
 use iced_widget::{Row, column, text};
 
 use crate::components::Badge;
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::components::tooltip;
 use crate::theme::badge::BadgeVariant as BadgeVar;
 use crate::theme::button::ButtonVariant;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Shoelace Component Gallery").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Shoelace Component Gallery").sizes(sizes);
     let subtitle = text("A comprehensive showcase of all Shoelace-inspired components").size(16);
 
     let description = text(
@@ -20,13 +23,22 @@ pub fn page() -> Element<'static, Message> {
     .size(14);
 
     // Quick preview of each component
-    let preview_title = text("Quick Preview").size(24);
+    let preview_title = Heading::new("Quick Preview").sizes(sizes).role(Role::H2);
 
     let badge_preview = Row::with_children([
         text("Badges: ").into(),
-        Badge::new("Primary").variant(BadgeVar::Primary).into(),
-        Badge::new("Success").variant(BadgeVar::Success).into(),
-        Badge::new("Danger").variant(BadgeVar::Danger).into(),
+        Badge::new("Primary")
+            .sizes(sizes)
+            .variant(BadgeVar::Primary)
+            .into(),
+        Badge::new("Success")
+            .sizes(sizes)
+            .variant(BadgeVar::Success)
+            .into(),
+        Badge::new("Danger")
+            .sizes(sizes)
+            .variant(BadgeVar::Danger)
+            .into(),
     ])
     .spacing(10)
     .align_y(alignment::Vertical::Center);
@@ -34,10 +46,12 @@ pub fn page() -> Element<'static, Message> {
     let button_preview = Row::with_children([
         text("Buttons: ").into(),
         Button::new("Primary")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Overview Primary".into()))
             .into(),
         Button::new("Success")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Overview Success".into()))
             .into(),
@@ -49,9 +63,11 @@ pub fn page() -> Element<'static, Message> {
         tooltip(
             "Hover for tooltip!",
             Button::new("Hover me")
+                .sizes(sizes)
                 .variant(ButtonVariant::Neutral)
                 .on_press(Message::ButtonPressed("Tooltip Preview".into())),
         )
+        .sizes(sizes)
         .into(),
     ])
     .spacing(10);
@@ -2,25 +2,28 @@ use iced::alignment;
 use iced_widget::{Row, column, container, text};
 
 use crate::components::{PopupPlacement, popup};
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::theme::button::ButtonVariant;
 use crate::theme::container::ContainerStyleClass;
-use crate::theme::sizes::SPACING;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Popups").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Popups").sizes(sizes);
     let description = text(
         "Display floating content relative to an anchor element. Unlike tooltips, popups require manual control."
     )
     .size(14);
 
     // Basic Popups
-    let basic_title = text("Basic Popup").size(24);
+    let basic_title = Heading::new("Basic Popup").sizes(sizes).role(Role::H2);
     let basic_desc = text("A simple popup anchored to a button").size(14);
 
     let basic_popup = popup(
         Button::new("Anchor")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Basic Popup".into())),
         container(
@@ -29,7 +32,7 @@ pub fn page() -> Element<'static, Message> {
                 text("This is a popup positioned relative to the anchor").size(12),
             ]
             .spacing(8)
-            .padding(SPACING.small)
+            .padding(sizes.spacing.small)
         ),
         true, // active
     )
@@ -37,16 +40,17 @@ pub fn page() -> Element<'static, Message> {
     .distance(8.0);
 
     // Placements
-    let placements_title = text("Popup Placements").size(24);
+    let placements_title = Heading::new("Popup Placements").sizes(sizes).role(Role::H2);
     let placements_desc =
         text("Popups support 12 placement options for precise positioning").size(14);
 
     // Top placements
     let popup_top = popup(
         Button::new("Top")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Top".into())),
-        container(text("Top")).padding(SPACING.small),
+        container(text("Top")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Top)
@@ -54,9 +58,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_top_start = popup(
         Button::new("Top Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("TopStart".into())),
-        container(text("Top Start")).padding(SPACING.small),
+        container(text("Top Start")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::TopStart)
@@ -64,9 +69,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_top_end = popup(
         Button::new("Top End")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("TopEnd".into())),
-        container(text("Top End")).padding(SPACING.small),
+        container(text("Top End")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::TopEnd)
@@ -82,9 +88,10 @@ pub fn page() -> Element<'static, Message> {
     // Bottom placements
     let popup_bottom = popup(
         Button::new("Bottom")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Bottom".into())),
-        container(text("Bottom")).padding(SPACING.small),
+        container(text("Bottom")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Bottom)
@@ -92,9 +99,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_bottom_start = popup(
         Button::new("Bottom Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("BottomStart".into())),
-        container(text("Bottom Start")).padding(SPACING.small),
+        container(text("Bottom Start")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::BottomStart)
@@ -102,9 +110,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_bottom_end = popup(
         Button::new("Bottom End")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("BottomEnd".into())),
-        container(text("Bottom End")).padding(SPACING.small),
+        container(text("Bottom End")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::BottomEnd)
@@ -120,9 +129,10 @@ pub fn page() -> Element<'static, Message> {
     // Left placements
     let popup_left = popup(
         Button::new("Left")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .on_press(Message::ButtonPressed("Left".into())),
-        container(text("Left")).padding(SPACING.small),
+        container(text("Left")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Left)
@@ -130,9 +140,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_left_start = popup(
         Button::new("Left Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .on_press(Message::ButtonPressed("LeftStart".into())),
-        container(text("Left Start")).padding(SPACING.small),
+        container(text("Left Start")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::LeftStart)
@@ -140,9 +151,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_left_end = popup(
         Button::new("Left End")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .on_press(Message::ButtonPressed("LeftEnd".into())),
-        container(text("Left End")).padding(SPACING.small),
+        container(text("Left End")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::LeftEnd)
@@ -158,9 +170,10 @@ pub fn page() -> Element<'static, Message> {
     // Right placements
     let popup_right = popup(
         Button::new("Right")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("Right".into())),
-        container(text("Right")).padding(SPACING.small),
+        container(text("Right")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Right)
@@ -168,9 +181,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_right_start = popup(
         Button::new("Right Start")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("RightStart".into())),
-        container(text("Right Start")).padding(SPACING.small),
+        container(text("Right Start")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::RightStart)
@@ -178,9 +192,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_right_end = popup(
         Button::new("Right End")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("RightEnd".into())),
-        container(text("Right End")).padding(SPACING.small),
+        container(text("Right End")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::RightEnd)
@@ -194,23 +209,25 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Custom distance
-    let distance_title = text("Custom Distance").size(24);
+    let distance_title = Heading::new("Custom Distance").sizes(sizes).role(Role::H2);
     let distance_desc = text("Control the gap between popup and anchor").size(14);
 
     let popup_default_distance = popup(
         Button::new("Default (0px)")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Default Distance".into())),
-        container(text("Default distance")).padding(SPACING.small),
+        container(text("Default distance")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Bottom);
 
     let popup_custom_distance = popup(
         Button::new("20px Distance")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Custom Distance".into())),
-        container(text("20px distance")).padding(SPACING.small),
+        container(text("20px distance")).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Bottom)
@@ -223,11 +240,12 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Styled popups
-    let styled_title = text("Styled Popups").size(24);
+    let styled_title = Heading::new("Styled Popups").sizes(sizes).role(Role::H2);
     let styled_desc = text("Popups can use different container styles").size(14);
 
     let popup_card_style = popup(
         Button::new("Card Style")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Card Style".into())),
         container(
@@ -236,7 +254,7 @@ pub fn page() -> Element<'static, Message> {
                 text("White background, border, shadow").size(11),
             ]
             .spacing(5)
-            .padding(SPACING.small)
+            .padding(sizes.spacing.small)
         ),
         true,
     )
@@ -246,9 +264,10 @@ pub fn page() -> Element<'static, Message> {
 
     let popup_tooltip_style = popup(
         Button::new("Tooltip Style")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Tooltip Style".into())),
-        container(text("Dark tooltip style").size(11)).padding(SPACING.small),
+        container(text("Dark tooltip style").size(11)).padding(sizes.spacing.small),
         true,
     )
     .placement(PopupPlacement::Bottom)
@@ -259,28 +278,32 @@ pub fn page() -> Element<'static, Message> {
         .spacing(15);
 
     // Rich content
-    let rich_title = text("Rich Content").size(24);
+    let rich_title = Heading::new("Rich Content").sizes(sizes).role(Role::H2);
     let rich_desc = text("Popups can contain any content including interactive elements").size(14);
 
     let rich_content = column![
         text("User Menu").size(16),
         text("john.doe@example.com").size(11),
         Button::new("Profile")
+            .sizes(sizes)
             .variant(ButtonVariant::Default)
             .on_press(Message::ButtonPressed("Profile".into())),
         Button::new("Settings")
+            .sizes(sizes)
             .variant(ButtonVariant::Default)
             .on_press(Message::ButtonPressed("Settings".into())),
         Button::new("Logout")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("Logout".into())),
     ]
     .spacing(8)
-    .padding(SPACING.small)
+    .padding(sizes.spacing.small)
     .align_x(alignment::Horizontal::Center);
 
     let popup_rich = popup(
         Button::new("User Menu")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("User Menu".into())),
         container(rich_content),
@@ -290,7 +313,7 @@ pub fn page() -> Element<'static, Message> {
     .distance(8.0);
 
     // Usage notes
-    let notes_title = text("Usage Notes").size(24);
+    let notes_title = Heading::new("Usage Notes").sizes(sizes).role(Role::H2);
     let notes = column![
         text("• Popups require manual control via the 'active' property"),
         text("• Use for dropdowns, context menus, and interactive overlays"),
@@ -0,0 +1,156 @@
+use iced::alignment;
+use iced_widget::{Row, column, text};
+
+use crate::components::Heading;
+use crate::components::button::Button;
+use crate::components::{ProgressBar, ProgressLabelPosition, ProgressRing, Spinner};
+use crate::theme::button::ButtonSize;
+use crate::theme::pallete::{ColorToken, ColorValue, ColorVariant};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct ProgressState {
+    pub value: f32,
+}
+
+impl Default for ProgressState {
+    fn default() -> Self {
+        Self { value: 40.0 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ProgressMessage {
+    Decrease,
+    Increase,
+}
+
+pub fn handle_progress_message(state: &mut ProgressState, message: ProgressMessage) {
+    match message {
+        ProgressMessage::Decrease => state.value = (state.value - 10.0).max(0.0),
+        ProgressMessage::Increase => state.value = (state.value + 10.0).min(100.0),
+    }
+}
+
+pub fn view(state: &ProgressState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Progress").sizes(sizes);
+    let description =
+        text("Progress bars, rings and spinners show the status of an ongoing operation").size(14);
+
+    let controls = Row::with_children([
+        Button::new("−")
+            .sizes(sizes)
+            .size(ButtonSize::Small)
+            .on_press(Message::Progress(ProgressMessage::Decrease))
+            .into(),
+        Button::new("+")
+            .sizes(sizes)
+            .size(ButtonSize::Small)
+            .on_press(Message::Progress(ProgressMessage::Increase))
+            .into(),
+    ])
+    .spacing(10)
+    .align_y(alignment::Vertical::Center);
+
+    // Progress bars
+    let bar_title = Heading::new("Progress Bar").sizes(sizes).role(Role::H2);
+    let bar = ProgressBar::new(state.value).sizes(sizes);
+    let bar_labeled = ProgressBar::new(state.value)
+        .sizes(sizes)
+        .label(format!("{:.0}%", state.value));
+    let bar_beside = ProgressBar::new(state.value)
+        .sizes(sizes)
+        .height(6.0)
+        .label(format!("{:.0}%", state.value))
+        .label_position(ProgressLabelPosition::Beside);
+    let bar_custom = ProgressBar::new(state.value)
+        .sizes(sizes)
+        .height(6.0)
+        .indicator_color(ColorToken::new(ColorVariant::Emerald, ColorValue::C500))
+        .track_color(ColorToken::new(ColorVariant::Emerald, ColorValue::C100));
+
+    let bar_indeterminate_title = Heading::new("Indeterminate").sizes(sizes).role(Role::H2);
+    let bar_indeterminate = ProgressBar::new(0.0).sizes(sizes).indeterminate(true);
+
+    // Progress rings
+    let ring_title = Heading::new("Progress Ring").sizes(sizes).role(Role::H2);
+    let rings = Row::with_children([
+        ProgressRing::new(state.value).sizes(sizes).into(),
+        ProgressRing::new(state.value)
+            .sizes(sizes)
+            .label(format!("{:.0}%", state.value))
+            .into(),
+        ProgressRing::new(state.value)
+            .sizes(sizes)
+            .size(64.0)
+            .track_width(2.0)
+            .indicator_width(6.0)
+            .indicator_color(ColorToken::new(ColorVariant::Rose, ColorValue::C500))
+            .into(),
+        ProgressRing::new(state.value)
+            .sizes(sizes)
+            .size(32.0)
+            .label(format!("{:.0}%", state.value))
+            .label_position(ProgressLabelPosition::Beside)
+            .into(),
+    ])
+    .spacing(20)
+    .align_y(alignment::Vertical::Center);
+
+    let ring_indeterminate_title = Heading::new("Indeterminate Ring")
+        .sizes(sizes)
+        .role(Role::H2);
+    let ring_indeterminate = ProgressRing::new(0.0)
+        .sizes(sizes)
+        .indeterminate(true)
+        .size(64.0);
+
+    // Spinners
+    let spinner_title = Heading::new("Spinner").sizes(sizes).role(Role::H2);
+    let spinners = Row::with_children([
+        Spinner::new().sizes(sizes).into(),
+        Spinner::new()
+            .sizes(sizes)
+            .size(sizes.font_size.x_large)
+            .into(),
+        Spinner::new()
+            .sizes(sizes)
+            .size(48.0)
+            .track_width(4.0)
+            .into(),
+        Spinner::new()
+            .sizes(sizes)
+            .size(48.0)
+            .track_width(4.0)
+            .track_color(ColorToken::new(ColorVariant::Rose, ColorValue::C100))
+            .indicator_color(ColorToken::new(ColorVariant::Rose, ColorValue::C500))
+            .into(),
+    ])
+    .spacing(20)
+    .align_y(alignment::Vertical::Center);
+
+    column![
+        title,
+        description,
+        controls,
+        bar_title,
+        bar,
+        bar_labeled,
+        bar_beside,
+        bar_custom,
+        bar_indeterminate_title,
+        bar_indeterminate,
+        ring_title,
+        rings,
+        ring_indeterminate_title,
+        ring_indeterminate,
+        spinner_title,
+        spinners,
+    ]
+    .spacing(20)
+    .padding(20)
+    .max_width(600)
+    .into()
+}
@@ -0,0 +1,126 @@
+use iced_widget::{column, text};
+
+use crate::components::Heading;
+use crate::components::{Range, TooltipPlacement};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct RangeState {
+    pub basic: f32,
+    pub volume: f32,
+    pub ticks: f32,
+    pub cutoff: f32,
+    pub band: (f32, f32),
+}
+
+impl Default for RangeState {
+    fn default() -> Self {
+        Self {
+            basic: 50.0,
+            volume: 0.8,
+            ticks: 40.0,
+            cutoff: 1_000.0,
+            band: (20.0, 80.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RangeMessage {
+    BasicChanged(f32),
+    VolumeChanged(f32),
+    TicksChanged(f32),
+    CutoffChanged(f32),
+    BandChanged(f32, f32),
+}
+
+pub fn handle_range_message(state: &mut RangeState, message: RangeMessage) {
+    match message {
+        RangeMessage::BasicChanged(value) => state.basic = value,
+        RangeMessage::VolumeChanged(value) => state.volume = value,
+        RangeMessage::TicksChanged(value) => state.ticks = value,
+        RangeMessage::CutoffChanged(value) => state.cutoff = value,
+        RangeMessage::BandChanged(lower, upper) => state.band = (lower, upper),
+    }
+}
+
+pub fn view(state: &RangeState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Ranges").sizes(sizes);
+    let description =
+        text("Ranges allow the user to select a value within a span of numbers").size(14);
+
+    // Basic range
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
+    let basic_range = Range::new(0.0, 100.0, state.basic)
+        .sizes(sizes)
+        .on_change(|value| Message::Range(RangeMessage::BasicChanged(value)));
+
+    // Label, help text and formatter
+    let formatter_title = Heading::new("Label and Formatter")
+        .sizes(sizes)
+        .role(Role::H2);
+    let formatter_range = Range::new(0.0, 1.0, state.volume)
+        .sizes(sizes)
+        .step(0.05)
+        .label("Volume")
+        .help_text("Drag the thumb to see the formatted value")
+        .formatter(|value| format!("{:.0}%", value * 100.0))
+        .tooltip(Some(TooltipPlacement::Bottom))
+        .on_change(|value| Message::Range(RangeMessage::VolumeChanged(value)));
+
+    // Tick marks
+    let ticks_title = Heading::new("Tick Marks").sizes(sizes).role(Role::H2);
+    let ticks_range = Range::new(0.0, 100.0, state.ticks)
+        .sizes(sizes)
+        .step(10.0)
+        .ticks(25.0)
+        .on_change(|value| Message::Range(RangeMessage::TicksChanged(value)));
+
+    let cutoff_range = Range::new(20.0, 20_000.0, state.cutoff)
+        .sizes(sizes)
+        .step(10.0)
+        .ticks(5_000.0)
+        .label("Filter cutoff")
+        .formatter(|value| {
+            if value >= 1_000.0 {
+                format!("{:.1} kHz", value / 1_000.0)
+            } else {
+                format!("{value:.0} Hz")
+            }
+        })
+        .on_change(|value| Message::Range(RangeMessage::CutoffChanged(value)));
+
+    // Dual thumb
+    let interval_title = Heading::new("Interval").sizes(sizes).role(Role::H2);
+    let interval_range = Range::interval(0.0, 100.0, state.band)
+        .sizes(sizes)
+        .label("Price")
+        .formatter(|value| format!("${value:.0}"))
+        .on_interval_change(|lower, upper| Message::Range(RangeMessage::BandChanged(lower, upper)));
+
+    // Disabled
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
+    let disabled_range = Range::<Message>::new(0.0, 100.0, 50.0).disabled(true);
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic_range,
+        formatter_title,
+        formatter_range,
+        ticks_title,
+        ticks_range,
+        cutoff_range,
+        interval_title,
+        interval_range,
+        disabled_title,
+        disabled_range,
+    ]
+    .spacing(20)
+    .padding(20)
+    .max_width(600)
+    .into()
+}
@@ -0,0 +1,126 @@
+use iced_widget::{column, text};
+
+use crate::components::Heading;
+use crate::components::Rating;
+use crate::theme::pallete::{ColorToken, ColorValue, ColorVariant};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct RatingState {
+    pub basic: f32,
+    pub half: f32,
+    pub max: f32,
+    pub hearts: f32,
+    pub mood: f32,
+}
+
+impl Default for RatingState {
+    fn default() -> Self {
+        Self {
+            basic: 3.0,
+            half: 2.5,
+            max: 7.0,
+            hearts: 4.0,
+            mood: 3.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RatingMessage {
+    BasicChanged(f32),
+    HalfChanged(f32),
+    MaxChanged(f32),
+    HeartsChanged(f32),
+    MoodChanged(f32),
+}
+
+pub fn handle_rating_message(state: &mut RatingState, message: RatingMessage) {
+    match message {
+        RatingMessage::BasicChanged(value) => state.basic = value,
+        RatingMessage::HalfChanged(value) => state.half = value,
+        RatingMessage::MaxChanged(value) => state.max = value,
+        RatingMessage::HeartsChanged(value) => state.hearts = value,
+        RatingMessage::MoodChanged(value) => state.mood = value,
+    }
+}
+
+pub fn view(state: &RatingState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Ratings").sizes(sizes);
+    let description =
+        text("Ratings give users a way to quickly view and provide feedback").size(14);
+
+    // Basic rating
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
+    let basic_desc = text("Click a star to rate, or focus it and use the arrow keys").size(14);
+    let basic_rating = Rating::new(state.basic)
+        .sizes(sizes)
+        .on_change(|value| Message::Rating(RatingMessage::BasicChanged(value)));
+
+    // Precision
+    let precision_title = Heading::new("Precision").sizes(sizes).role(Role::H2);
+    let precision_rating = Rating::new(state.half)
+        .sizes(sizes)
+        .precision(0.5)
+        .on_change(|value| Message::Rating(RatingMessage::HalfChanged(value)));
+
+    // Max value
+    let max_title = Heading::new("Maximum Value").sizes(sizes).role(Role::H2);
+    let max_rating = Rating::new(state.max)
+        .sizes(sizes)
+        .max(10)
+        .on_change(|value| Message::Rating(RatingMessage::MaxChanged(value)));
+
+    // Custom colors and symbols
+    let custom_title = Heading::new("Custom Symbols").sizes(sizes).role(Role::H2);
+    let hearts_rating = Rating::new(state.hearts)
+        .sizes(sizes)
+        .symbol(|_| "♥".to_string())
+        .active_color(ColorToken::new(ColorVariant::Rose, ColorValue::C500))
+        .on_change(|value| Message::Rating(RatingMessage::HeartsChanged(value)));
+
+    let mood_rating = Rating::new(state.mood)
+        .sizes(sizes)
+        .symbol(|value| {
+            match value {
+                1 => "😞",
+                2 => "😐",
+                3 => "🙂",
+                4 => "😀",
+                _ => "😍",
+            }
+            .to_string()
+        })
+        .on_change(|value| Message::Rating(RatingMessage::MoodChanged(value)));
+
+    // Readonly and disabled
+    let readonly_title = Heading::new("Readonly").sizes(sizes).role(Role::H2);
+    let readonly_rating = Rating::<Message>::new(3.5).precision(0.5).readonly(true);
+
+    let disabled_title = Heading::new("Disabled").sizes(sizes).role(Role::H2);
+    let disabled_rating = Rating::<Message>::new(3.0).disabled(true);
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic_desc,
+        basic_rating,
+        precision_title,
+        precision_rating,
+        max_title,
+        max_rating,
+        custom_title,
+        hearts_rating,
+        mood_rating,
+        readonly_title,
+        readonly_rating,
+        disabled_title,
+        disabled_rating,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
@@ -1,11 +1,14 @@
 use iced_widget::{Row, column, text};
 
+use crate::components::Heading;
 use crate::components::scrollable;
 use crate::theme::ScrollableClass;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Scrollable Styles").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Scrollable Styles").sizes(sizes);
     let description = text("Different scrollable styles using Shoelace tokens").size(14);
 
     // Default scrollable
@@ -25,7 +28,7 @@ pub fn page() -> Element<'static, Message> {
         .spacing(5)
         .padding(10);
 
-        scrollable(content)
+        scrollable(content, sizes)
             .class(ScrollableClass::Default)
             .height(150)
             .width(300)
@@ -48,19 +51,21 @@ pub fn page() -> Element<'static, Message> {
         .spacing(5)
         .padding(10);
 
-        scrollable(content)
+        scrollable(content, sizes)
             .class(ScrollableClass::Subtle)
             .height(150)
             .width(300)
     };
 
     // Comparison row
-    let comparison_title = text("Side by Side Comparison").size(24);
+    let comparison_title = Heading::new("Side by Side Comparison")
+        .sizes(sizes)
+        .role(Role::H2);
     let scrollable_row =
         Row::with_children([default_scrollable.into(), subtle_scrollable.into()]).spacing(40);
 
     // Usage notes
-    let notes_title = text("Usage Notes").size(24);
+    let notes_title = Heading::new("Usage Notes").sizes(sizes).role(Role::H2);
     let notes = column![
         text("• Default scrollable: Best for content areas where scrolling is expected"),
         text("• Subtle scrollable: Best for minimal UI where scrollbars should be unobtrusive"),
@@ -0,0 +1,69 @@
+use iced::alignment;
+use iced_widget::{Row, column, text};
+
+use crate::components::Heading;
+use crate::components::{Skeleton, SkeletonEffect};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Skeletons").sizes(sizes);
+    let description = text("Skeletons are placeholders shown while content is loading").size(14);
+
+    // Shapes
+    let shapes_title = Heading::new("Shapes").sizes(sizes).role(Role::H2);
+    let shapes = Row::with_children([
+        Skeleton::circle(48.0).sizes(sizes).into(),
+        Skeleton::rectangle(96, 48).sizes(sizes).into(),
+        column![
+            Skeleton::text().sizes(sizes).width(200),
+            Skeleton::text().sizes(sizes).width(160),
+            Skeleton::text().sizes(sizes).width(180),
+        ]
+        .spacing(8)
+        .into(),
+    ])
+    .spacing(20)
+    .align_y(alignment::Vertical::Center);
+
+    // Effects
+    let effects_title = Heading::new("Effects").sizes(sizes).role(Role::H2);
+    let effects = column![
+        text("Pulse").size(14),
+        Skeleton::text()
+            .sizes(sizes)
+            .width(300)
+            .effect(SkeletonEffect::Pulse),
+        text("Sheen").size(14),
+        Skeleton::text()
+            .sizes(sizes)
+            .width(300)
+            .effect(SkeletonEffect::Sheen),
+    ]
+    .spacing(8);
+
+    // Card placeholder
+    let card_title = Heading::new("Card Placeholder").sizes(sizes).role(Role::H2);
+    let card = Skeleton::card(SkeletonEffect::Sheen, sizes).width(300);
+
+    // Menu placeholder
+    let menu_title = Heading::new("Menu Placeholder").sizes(sizes).role(Role::H2);
+    let menu = Skeleton::menu_items(4, SkeletonEffect::Pulse, sizes).width(200);
+
+    column![
+        title,
+        description,
+        shapes_title,
+        shapes,
+        effects_title,
+        effects,
+        card_title,
+        card,
+        menu_title,
+        menu,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
@@ -0,0 +1,139 @@
+use iced::{Length, Shadow};
+use iced_widget::{column, container, text};
+
+use crate::components::Heading;
+use crate::components::{SplitOrientation, SplitPane, SplitPanel, SplitPosition};
+use crate::theme::container::ContainerStyleClass;
+use crate::theme::pallete::{ColorToken, ColorValue, ColorVariant};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct SplitPanelState {
+    pub basic: SplitPosition,
+    pub vertical: SplitPosition,
+    pub primary: SplitPosition,
+    pub snapping: SplitPosition,
+}
+
+impl Default for SplitPanelState {
+    fn default() -> Self {
+        Self {
+            basic: SplitPosition::Percent(50.0),
+            vertical: SplitPosition::Percent(40.0),
+            primary: SplitPosition::Pixels(200.0),
+            snapping: SplitPosition::Percent(50.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SplitPanelMessage {
+    Basic(SplitPosition),
+    Vertical(SplitPosition),
+    Primary(SplitPosition),
+    Snapping(SplitPosition),
+}
+
+pub fn handle_split_panel_message(state: &mut SplitPanelState, message: SplitPanelMessage) {
+    match message {
+        SplitPanelMessage::Basic(position) => state.basic = position,
+        SplitPanelMessage::Vertical(position) => state.vertical = position,
+        SplitPanelMessage::Primary(position) => state.primary = position,
+        SplitPanelMessage::Snapping(position) => state.snapping = position,
+    }
+}
+
+fn pane<'a>(label: &'a str) -> Element<'a, Message> {
+    container(text(label).size(14))
+        .center(Length::Fill)
+        .class(ContainerStyleClass::Custom {
+            background: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C50)),
+            text_color: Some(ColorToken::new(ColorVariant::Neutral, ColorValue::C700)),
+            border_color: None,
+            border_width: 0.0,
+            border_radius: 0.0,
+            shadow: Shadow::default(),
+            snap: false,
+        })
+        .into()
+}
+
+fn describe(position: SplitPosition) -> String {
+    match position {
+        SplitPosition::Pixels(pixels) => format!("{pixels:.0}px"),
+        SplitPosition::Percent(percent) => format!("{percent:.0}%"),
+    }
+}
+
+pub fn view(state: &SplitPanelState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Split Panels").sizes(sizes);
+    let description = text(
+        "Split panels show two panes side by side or stacked, separated by a draggable divider",
+    )
+    .size(14);
+
+    // Basic
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
+    let basic_desc = text(format!(
+        "Drag the divider, or click or Tab to it and use the arrow keys. Position: {}",
+        describe(state.basic)
+    ))
+    .size(14);
+    let basic = SplitPanel::new(pane("Start"), pane("End"), state.basic)
+        .height(200)
+        .on_reposition(|position| Message::SplitPanel(SplitPanelMessage::Basic(position)));
+
+    // Vertical
+    let vertical_title = Heading::new("Vertical").sizes(sizes).role(Role::H2);
+    let vertical = SplitPanel::new(pane("Top"), pane("Bottom"), state.vertical)
+        .orientation(SplitOrientation::Vertical)
+        .height(300)
+        .on_reposition(|position| Message::SplitPanel(SplitPanelMessage::Vertical(position)));
+
+    // Primary pane with limits
+    let primary_title = Heading::new("Primary Pane").sizes(sizes).role(Role::H2);
+    let primary_desc = text(format!(
+        "The end pane keeps its size when the window is resized and stays between 100px and 400px. Size: {}",
+        describe(state.primary)
+    ))
+    .size(14);
+    let primary = SplitPanel::new(pane("Start"), pane("Sidebar"), state.primary)
+        .primary(SplitPane::End)
+        .min(100.0)
+        .max(400.0)
+        .height(200)
+        .on_reposition(|position| Message::SplitPanel(SplitPanelMessage::Primary(position)));
+
+    // Snapping
+    let snapping_title = Heading::new("Snapping").sizes(sizes).role(Role::H2);
+    let snapping_desc = text("The divider snaps to 25%, 50% and 75% while dragged").size(14);
+    let snapping = SplitPanel::new(pane("Start"), pane("End"), state.snapping)
+        .snaps([
+            SplitPosition::Percent(25.0),
+            SplitPosition::Percent(50.0),
+            SplitPosition::Percent(75.0),
+        ])
+        .height(200)
+        .on_reposition(|position| Message::SplitPanel(SplitPanelMessage::Snapping(position)));
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic_desc,
+        basic,
+        vertical_title,
+        vertical,
+        primary_title,
+        primary_desc,
+        primary,
+        snapping_title,
+        snapping_desc,
+        snapping,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
@@ -0,0 +1,175 @@
+use iced_widget::{column, text};
+
+use crate::components::Heading;
+use crate::components::{Tab, TabActivation, TabGroup, TabPanel, TabPlacement};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct TabGroupState {
+    pub basic: String,
+    pub placement: String,
+    pub vertical: String,
+    pub closable: String,
+    pub open_tabs: Vec<usize>,
+    pub manual: String,
+}
+
+impl Default for TabGroupState {
+    fn default() -> Self {
+        Self {
+            basic: "general".to_string(),
+            placement: "general".to_string(),
+            vertical: "general".to_string(),
+            closable: "tab-1".to_string(),
+            open_tabs: (1..=12).collect(),
+            manual: "general".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TabGroupMessage {
+    BasicSelected(String),
+    PlacementSelected(String),
+    VerticalSelected(String),
+    ClosableSelected(String),
+    Closed(String),
+    ManualSelected(String),
+}
+
+pub fn handle_tab_group_message(state: &mut TabGroupState, message: TabGroupMessage) {
+    match message {
+        TabGroupMessage::BasicSelected(panel) => state.basic = panel,
+        TabGroupMessage::PlacementSelected(panel) => state.placement = panel,
+        TabGroupMessage::VerticalSelected(panel) => state.vertical = panel,
+        TabGroupMessage::ClosableSelected(panel) => state.closable = panel,
+        TabGroupMessage::Closed(panel) => {
+            let Some(position) = state
+                .open_tabs
+                .iter()
+                .position(|index| closable_panel(*index) == panel)
+            else {
+                return;
+            };
+
+            state.open_tabs.remove(position);
+
+            // Move to a neighbouring tab when the active one is closed
+            if state.closable == panel
+                && let Some(index) = state
+                    .open_tabs
+                    .get(position)
+                    .or_else(|| state.open_tabs.last())
+            {
+                state.closable = closable_panel(*index);
+            }
+        }
+        TabGroupMessage::ManualSelected(panel) => state.manual = panel,
+    }
+}
+
+fn closable_panel(index: usize) -> String {
+    format!("tab-{index}")
+}
+
+/// The general/custom/advanced/disabled set of tabs used by most examples
+fn settings_tabs<'a>(
+    active: &str,
+    on_select: fn(String) -> TabGroupMessage,
+    sizes: Sizes,
+) -> TabGroup<'a, Message> {
+    TabGroup::new(active)
+        .sizes(sizes)
+        .tab(Tab::new("general", "General"))
+        .tab(Tab::new("custom", "Custom"))
+        .tab(Tab::new("advanced", "Advanced"))
+        .tab(Tab::new("disabled", "Disabled").disabled(true))
+        .panel(TabPanel::new(
+            "general",
+            text("This is the general tab panel."),
+        ))
+        .panel(TabPanel::new(
+            "custom",
+            text("This is the custom tab panel."),
+        ))
+        .panel(TabPanel::new(
+            "advanced",
+            text("This is the advanced tab panel."),
+        ))
+        .panel(TabPanel::new(
+            "disabled",
+            text("This is a disabled tab panel."),
+        ))
+        .on_select(move |panel| Message::TabGroup(on_select(panel)))
+}
+
+pub fn view(state: &TabGroupState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Tab Groups").sizes(sizes);
+    let description =
+        text("Tab groups organize content into a container that shows one section at a time")
+            .size(14);
+
+    // Basic tab group
+    let basic_title = Heading::new("Basic").sizes(sizes).role(Role::H2);
+    let basic = settings_tabs(&state.basic, TabGroupMessage::BasicSelected, sizes);
+
+    // Tabs on the bottom
+    let placement_title = Heading::new("Tabs on Bottom").sizes(sizes).role(Role::H2);
+    let placement = settings_tabs(&state.placement, TabGroupMessage::PlacementSelected, sizes)
+        .placement(TabPlacement::Bottom);
+
+    // Tabs on the start
+    let vertical_title = Heading::new("Tabs on Start").sizes(sizes).role(Role::H2);
+    let vertical = settings_tabs(&state.vertical, TabGroupMessage::VerticalSelected, sizes)
+        .placement(TabPlacement::Start);
+
+    // Closable tabs that overflow
+    let closable_title = Heading::new("Closable and Scrolling Tabs")
+        .sizes(sizes)
+        .role(Role::H2);
+    let closable = state.open_tabs.iter().fold(
+        TabGroup::new(&state.closable)
+            .sizes(sizes)
+            .on_select(|panel| Message::TabGroup(TabGroupMessage::ClosableSelected(panel)))
+            .on_close(|panel| Message::TabGroup(TabGroupMessage::Closed(panel))),
+        |group, index| {
+            group
+                .tab(Tab::new(closable_panel(*index), format!("Tab {index}")).closable(true))
+                .panel(TabPanel::new(
+                    closable_panel(*index),
+                    text(format!("Tab panel {index}")),
+                ))
+        },
+    );
+
+    // Manual activation
+    let manual_title = Heading::new("Manual Activation")
+        .sizes(sizes)
+        .role(Role::H2);
+    let manual_description =
+        text("Arrow keys move focus; press Enter or Space to show the focused tab").size(14);
+    let manual = settings_tabs(&state.manual, TabGroupMessage::ManualSelected, sizes)
+        .activation(TabActivation::Manual);
+
+    column![
+        title,
+        description,
+        basic_title,
+        basic,
+        placement_title,
+        placement,
+        vertical_title,
+        vertical,
+        closable_title,
+        closable,
+        manual_title,
+        manual_description,
+        manual,
+    ]
+    .spacing(20)
+    .padding(20)
+    .max_width(600)
+    .into()
+}
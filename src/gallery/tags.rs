@@ -0,0 +1,144 @@
+use iced::alignment;
+use iced_widget::{Row, column, text};
+
+use crate::components::Heading;
+use crate::components::button::Button;
+use crate::components::{Tag, TagSize};
+use crate::theme::badge::BadgeVariant;
+use crate::theme::button::ButtonSize;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+const LANGUAGES: [&str; 5] = ["Rust", "Go", "TypeScript", "Python", "Zig"];
+
+#[derive(Debug, Clone)]
+pub struct TagState {
+    pub languages: Vec<String>,
+}
+
+impl Default for TagState {
+    fn default() -> Self {
+        Self {
+            languages: LANGUAGES
+                .iter()
+                .map(|language| language.to_string())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TagMessage {
+    Removed(String),
+    Reset,
+}
+
+pub fn handle_tag_message(state: &mut TagState, message: TagMessage) {
+    match message {
+        TagMessage::Removed(language) => state.languages.retain(|l| *l != language),
+        TagMessage::Reset => *state = TagState::default(),
+    }
+}
+
+pub fn view(state: &TagState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Tags").sizes(sizes);
+    let description =
+        text("Tags are used as labels to organize things or to indicate a selection").size(14);
+
+    // Variants
+    let variants_title = Heading::new("Variants").sizes(sizes).role(Role::H2);
+    let variants = Row::with_children([
+        Tag::new("Primary")
+            .sizes(sizes)
+            .variant(BadgeVariant::Primary)
+            .into(),
+        Tag::new("Success")
+            .sizes(sizes)
+            .variant(BadgeVariant::Success)
+            .into(),
+        Tag::new("Neutral")
+            .sizes(sizes)
+            .variant(BadgeVariant::Neutral)
+            .into(),
+        Tag::new("Warning")
+            .sizes(sizes)
+            .variant(BadgeVariant::Warning)
+            .into(),
+        Tag::new("Danger")
+            .sizes(sizes)
+            .variant(BadgeVariant::Danger)
+            .into(),
+    ])
+    .spacing(8)
+    .align_y(alignment::Vertical::Center);
+
+    // Sizes
+    let sizes_title = Heading::new("Sizes").sizes(sizes).role(Role::H2);
+    let size_row = Row::with_children([
+        Tag::new("Small").sizes(sizes).size(TagSize::Small).into(),
+        Tag::new("Medium").sizes(sizes).size(TagSize::Medium).into(),
+        Tag::new("Large").sizes(sizes).size(TagSize::Large).into(),
+    ])
+    .spacing(8)
+    .align_y(alignment::Vertical::Center);
+
+    // Pill
+    let pill_title = Heading::new("Pill").sizes(sizes).role(Role::H2);
+    let pills = Row::with_children([
+        Tag::new("Small")
+            .sizes(sizes)
+            .size(TagSize::Small)
+            .pill(true)
+            .into(),
+        Tag::new("Medium")
+            .sizes(sizes)
+            .size(TagSize::Medium)
+            .pill(true)
+            .into(),
+        Tag::new("Large")
+            .sizes(sizes)
+            .size(TagSize::Large)
+            .pill(true)
+            .into(),
+    ])
+    .spacing(8)
+    .align_y(alignment::Vertical::Center);
+
+    // Removable tag list
+    let removable_title = Heading::new("Removable").sizes(sizes).role(Role::H2);
+    let removable_desc = text("Press ✕ to remove a tag from the list").size(14);
+    let removable = Row::with_children(state.languages.iter().map(|language| {
+        Tag::new(language.clone())
+            .sizes(sizes)
+            .variant(BadgeVariant::Primary)
+            .removable(true)
+            .on_remove(Message::Tag(TagMessage::Removed(language.clone())))
+            .into()
+    }))
+    .spacing(8)
+    .align_y(alignment::Vertical::Center);
+
+    let reset = Button::new("Reset")
+        .sizes(sizes)
+        .size(ButtonSize::Small)
+        .on_press(Message::Tag(TagMessage::Reset));
+
+    column![
+        title,
+        description,
+        variants_title,
+        variants,
+        sizes_title,
+        size_row,
+        pill_title,
+        pills,
+        removable_title,
+        removable_desc,
+        removable,
+        reset,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
@@ -1,51 +1,64 @@
 use iced_widget::{Row, column, text};
 
 use crate::components::Badge;
+use crate::components::Heading;
 use crate::components::button::Button;
 use crate::components::{TooltipPlacement, tooltip};
 use crate::theme::badge::BadgeVariant as BadgeVar;
 use crate::theme::button::ButtonVariant;
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
 use crate::{Element, Message};
 
-pub fn page() -> Element<'static, Message> {
-    let title = text("Tooltips").size(32);
+pub fn page(sizes: Sizes) -> Element<'static, Message> {
+    let title = Heading::new("Tooltips").sizes(sizes);
     let description =
         text("Hover over the elements to see tooltips in different positions").size(14);
 
     // Placements
-    let placements_title = text("Tooltip Placements").size(24);
+    let placements_title = Heading::new("Tooltip Placements")
+        .sizes(sizes)
+        .role(Role::H2);
     let placements_desc = text("Tooltips can be positioned on all four sides").size(14);
 
     let tooltip_top = tooltip(
         "This is a tooltip on top!",
         Button::new("Top")
+            .sizes(sizes)
             .variant(ButtonVariant::Primary)
             .on_press(Message::ButtonPressed("Tooltip Top".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Top);
 
     let tooltip_bottom = tooltip(
         "This is a tooltip on bottom",
         Button::new("Bottom")
+            .sizes(sizes)
             .variant(ButtonVariant::Success)
             .on_press(Message::ButtonPressed("Tooltip Bottom".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Bottom);
 
     let tooltip_left = tooltip(
         "This tooltip appears on the left",
         Button::new("Left")
+            .sizes(sizes)
             .variant(ButtonVariant::Warning)
             .on_press(Message::ButtonPressed("Tooltip Left".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Left);
 
     let tooltip_right = tooltip(
         "This tooltip appears on the right",
         Button::new("Right")
+            .sizes(sizes)
             .variant(ButtonVariant::Danger)
             .on_press(Message::ButtonPressed("Tooltip Right".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Right);
 
     let placements_row = Row::with_children([
@@ -57,23 +70,27 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Custom distance
-    let distance_title = text("Custom Distance").size(24);
+    let distance_title = Heading::new("Custom Distance").sizes(sizes).role(Role::H2);
     let distance_desc = text("Control the distance between tooltip and element").size(14);
 
     let tooltip_default_distance = tooltip(
         "Default distance",
         Button::new("Default")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Default Distance".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Top);
 
     let tooltip_custom_distance = tooltip(
         "Custom distance (20px)",
         Button::new("20px Distance")
+            .sizes(sizes)
             .variant(ButtonVariant::Neutral)
             .on_press(Message::ButtonPressed("Custom Distance".into())),
     )
+    .sizes(sizes)
     .placement(TooltipPlacement::Top)
     .distance(20.0);
 
@@ -84,23 +101,27 @@ pub fn page() -> Element<'static, Message> {
     .spacing(15);
 
     // Tooltip on different elements
-    let elements_title = text("Tooltips on Different Elements").size(24);
+    let elements_title = Heading::new("Tooltips on Different Elements")
+        .sizes(sizes)
+        .role(Role::H2);
     let elements_desc = text("Tooltips work with any element").size(14);
 
     let tooltip_text = tooltip(
         "Even text can have tooltips!",
         text("Hover over this text").size(16),
-    );
+    )
+    .sizes(sizes);
 
     let tooltip_badge = tooltip(
         "This is a badge with a tooltip",
-        Badge::new("Badge").variant(BadgeVar::Primary),
-    );
+        Badge::new("Badge").sizes(sizes).variant(BadgeVar::Primary),
+    )
+    .sizes(sizes);
 
     let elements_row = Row::with_children([tooltip_text.into(), tooltip_badge.into()]).spacing(15);
 
     // Usage notes
-    let notes_title = text("Usage Notes").size(24);
+    let notes_title = Heading::new("Usage Notes").sizes(sizes).role(Role::H2);
     let notes = column![
         text("• Tooltips provide contextual information on hover"),
         text("• Choose placement based on available space"),
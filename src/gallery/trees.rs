@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use iced::Task;
+use iced::futures::channel::oneshot;
+use iced_widget::{column, container, text};
+
+use crate::components::Heading;
+use crate::components::{Tree, TreeEvent, TreeNode, TreeSelection, TreeState};
+use crate::theme::sizes::Sizes;
+use crate::theme::typography::Role;
+use crate::{Element, Message};
+
+#[derive(Debug, Clone)]
+pub struct TreeGalleryState {
+    pub files: TreeState<String>,
+    pub settings: TreeState<&'static str>,
+    pub docs: TreeState<&'static str>,
+}
+
+impl Default for TreeGalleryState {
+    fn default() -> Self {
+        let files = TreeState::new([
+            directory("src"),
+            directory("assets"),
+            TreeNode::new("Cargo.toml".to_string(), "Cargo.toml"),
+            TreeNode::new("README.md".to_string(), "README.md"),
+        ]);
+
+        let settings = TreeState::new([
+            TreeNode::new("appearance", "Appearance").children([
+                TreeNode::new("theme", "Theme"),
+                TreeNode::new("font", "Font"),
+                TreeNode::new("animations", "Animations"),
+            ]),
+            TreeNode::new("privacy", "Privacy").children([
+                TreeNode::new("telemetry", "Telemetry"),
+                TreeNode::new("crash-reports", "Crash reports"),
+                TreeNode::new("history", "History").disabled(true),
+            ]),
+            TreeNode::new("updates", "Updates"),
+        ])
+        .selection(TreeSelection::Multiple);
+
+        let docs = TreeState::new([
+            TreeNode::new("getting-started", "Getting Started").children([
+                TreeNode::new("installation", "Installation"),
+                TreeNode::new("usage", "Usage"),
+            ]),
+            TreeNode::new("components", "Components").children([
+                TreeNode::new("button", "Button"),
+                TreeNode::new("card", "Card"),
+                TreeNode::new("tree", "Tree"),
+            ]),
+            TreeNode::new("changelog", "Changelog"),
+        ])
+        .selection(TreeSelection::Leaf);
+
+        Self {
+            files,
+            settings,
+            docs,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TreeMessage {
+    Files(TreeEvent<String>),
+    FilesLoaded(String, Vec<TreeNode<String>>),
+    Settings(TreeEvent<&'static str>),
+    Docs(TreeEvent<&'static str>),
+}
+
+pub fn handle_tree_message(state: &mut TreeGalleryState, message: TreeMessage) -> Task<Message> {
+    match message {
+        TreeMessage::Files(event) => {
+            if let Some(path) = state.files.update(event) {
+                return Task::perform(list_directory(path.clone()), move |children| {
+                    Message::Tree(TreeMessage::FilesLoaded(path.clone(), children))
+                });
+            }
+        }
+        TreeMessage::FilesLoaded(path, children) => state.files.set_children(&path, children),
+        TreeMessage::Settings(event) => {
+            state.settings.update(event);
+        }
+        TreeMessage::Docs(event) => {
+            state.docs.update(event);
+        }
+    }
+
+    Task::none()
+}
+
+fn directory(path: &str) -> TreeNode<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), format!("{name}/")).lazy(true)
+}
+
+fn file(path: &str) -> TreeNode<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    TreeNode::new(path.to_string(), name)
+}
+
+/// Pretends to read a directory from a slow disk
+async fn list_directory(path: String) -> Vec<TreeNode<String>> {
+    let (sender, receiver) = oneshot::channel();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(800));
+
+        let children = match path.as_str() {
+            "src" => vec![
+                directory("src/components"),
+                directory("src/theme"),
+                file("src/main.rs"),
+            ],
+            "src/components" => vec![
+                file("src/components/button.rs"),
+                file("src/components/tree.rs"),
+            ],
+            "src/theme" => vec![file("src/theme/tokens.rs")],
+            "assets" => vec![directory("assets/empty")],
+            _ => Vec::new(),
+        };
+
+        let _ = sender.send(children);
+    });
+
+    receiver.await.unwrap_or_default()
+}
+
+pub fn view(state: &TreeGalleryState, sizes: Sizes) -> Element<'_, Message> {
+    let title = Heading::new("Trees").sizes(sizes);
+    let description = text(
+        "Trees show a hierarchical list of items that can be expanded, collapsed and selected",
+    )
+    .size(14);
+
+    // Lazy file browser
+    let files_title = Heading::new("Lazy Loading").sizes(sizes).role(Role::H2);
+    let files_desc =
+        text("Folders load their contents when first expanded. Use the arrow keys, Home, End or type a name to move around").size(14);
+    let files = container(
+        Tree::new(&state.files)
+            .sizes(sizes)
+            .on_event(|event| Message::Tree(TreeMessage::Files(event))),
+    )
+    .width(300);
+
+    // Multiple selection
+    let settings_title = Heading::new("Multiple Selection")
+        .sizes(sizes)
+        .role(Role::H2);
+    let selected = state.settings.selected().count();
+    let settings = container(
+        Tree::new(&state.settings)
+            .sizes(sizes)
+            .on_event(|event| Message::Tree(TreeMessage::Settings(event))),
+    )
+    .width(300);
+    let settings_summary = text(format!("{selected} selected")).size(14);
+
+    // Leaf selection
+    let docs_title = Heading::new("Leaf Selection").sizes(sizes).role(Role::H2);
+    let docs_desc = text("Only leaves can be selected; pressing a parent expands it").size(14);
+    let docs = container(
+        Tree::new(&state.docs)
+            .sizes(sizes)
+            .on_event(|event| Message::Tree(TreeMessage::Docs(event))),
+    )
+    .width(300);
+
+    column![
+        title,
+        description,
+        files_title,
+        files_desc,
+        files,
+        settings_title,
+        settings,
+        settings_summary,
+        docs_title,
+        docs_desc,
+        docs,
+    ]
+    .spacing(20)
+    .padding(20)
+    .into()
+}
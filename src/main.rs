@@ -1,7 +1,14 @@
-use crate::theme::Theme;
+use crate::theme::{
+    Theme,
+    sizes::Density,
+    system::{self, Preference, SystemTheme},
+    typography::FONTS,
+};
 
 pub mod components;
 pub mod gallery;
+#[cfg(test)]
+mod snapshot;
 pub mod theme;
 pub mod widgets;
 
@@ -10,10 +17,21 @@ type Task<Message> = iced::Task<Message>;
 
 fn main() -> iced::Result {
     iced::application(
-        || (Gallery::default(), Task::none()),
+        || {
+            let mut gallery = Gallery::default();
+            gallery.refresh_theme();
+            let fonts = gallery.theme().fonts().load();
+
+            (
+                gallery,
+                Task::batch([SystemTheme::fetch().map(Message::SystemTheme), fonts]),
+            )
+        },
         Gallery::update,
         Gallery::view,
     )
+    .subscription(Gallery::subscription)
+    .default_font(FONTS.sans)
     .theme(Gallery::theme)
     .title(Gallery::title)
     .run()
@@ -23,12 +41,16 @@ fn main() -> iced::Result {
 pub enum Page {
     #[default]
     Overview,
+    Alerts,
+    Avatars,
     Badges,
     Breadcrumbs,
     Buttons,
     ButtonGroups,
     Cards,
     Checkboxes,
+    ColorPickers,
+    Details,
     Dialogs,
     Dividers,
     Dropdowns,
@@ -37,20 +59,32 @@ pub enum Page {
     MenuLabels,
     Menus,
     Popups,
+    Progress,
+    Ranges,
+    Ratings,
     Scrollables,
+    Skeletons,
+    SplitPanels,
+    TabGroups,
+    Tags,
     Tooltips,
+    Trees,
 }
 
 impl Page {
     pub fn all() -> Vec<Self> {
         vec![
             Self::Overview,
+            Self::Alerts,
+            Self::Avatars,
             Self::Badges,
             Self::Breadcrumbs,
             Self::Buttons,
             Self::ButtonGroups,
             Self::Cards,
             Self::Checkboxes,
+            Self::ColorPickers,
+            Self::Details,
             Self::Dialogs,
             Self::Dividers,
             Self::Dropdowns,
@@ -59,20 +93,32 @@ impl Page {
             Self::MenuLabels,
             Self::Menus,
             Self::Popups,
+            Self::Progress,
+            Self::Ranges,
+            Self::Ratings,
             Self::Scrollables,
+            Self::Skeletons,
+            Self::SplitPanels,
+            Self::TabGroups,
+            Self::Tags,
             Self::Tooltips,
+            Self::Trees,
         ]
     }
 
     pub fn name(&self) -> &str {
         match self {
             Self::Overview => "Overview",
+            Self::Alerts => "Alerts",
+            Self::Avatars => "Avatars",
             Self::Badges => "Badges",
             Self::Breadcrumbs => "Breadcrumbs",
             Self::Buttons => "Buttons",
             Self::ButtonGroups => "Button Groups",
             Self::Cards => "Cards",
             Self::Checkboxes => "Checkboxes",
+            Self::ColorPickers => "Color Pickers",
+            Self::Details => "Details",
             Self::Dialogs => "Dialogs",
             Self::Dividers => "Dividers",
             Self::Dropdowns => "Dropdowns",
@@ -81,18 +127,40 @@ impl Page {
             Self::MenuLabels => "Menu Labels",
             Self::Menus => "Menus",
             Self::Popups => "Popups",
+            Self::Progress => "Progress",
+            Self::Ranges => "Ranges",
+            Self::Ratings => "Ratings",
             Self::Scrollables => "Scrollables",
+            Self::Skeletons => "Skeletons",
+            Self::SplitPanels => "Split Panels",
+            Self::TabGroups => "Tab Groups",
+            Self::Tags => "Tags",
             Self::Tooltips => "Tooltips",
+            Self::Trees => "Trees",
         }
     }
 }
 
 #[derive(Debug, Default)]
 struct Gallery {
-    theme: Theme,
+    theme: SystemTheme,
+    density: Density,
+    /// The system theme with the density applied, rebuilt when either changes
+    current_theme: Theme,
     current_page: Page,
+    alert_state: gallery::AlertState,
+    checkbox_state: gallery::CheckboxState,
+    color_picker_state: gallery::ColorPickerState,
+    details_state: gallery::DetailsState,
     dialog_state: gallery::DialogState,
     input_state: gallery::InputState,
+    progress_state: gallery::ProgressState,
+    range_state: gallery::RangeState,
+    rating_state: gallery::RatingState,
+    split_panel_state: gallery::SplitPanelState,
+    tab_group_state: gallery::TabGroupState,
+    tag_state: gallery::TagState,
+    tree_state: gallery::TreeGalleryState,
 }
 
 #[derive(Debug, Clone)]
@@ -101,10 +169,25 @@ pub enum Message {
     CheckboxChanged(String, bool),
     InputChanged(String, String),
     MenuItemSelected,
-    SwitchTheme(Theme),
+    SwitchTheme(Preference),
+    SystemTheme(system::Event),
+    SwitchDensity(Density),
     NavigateToPage(Page),
+    FocusNext,
+    FocusPrevious,
+    Alert(gallery::AlertMessage),
+    Checkbox(gallery::CheckboxMessage),
+    ColorPicker(gallery::ColorPickerMessage),
+    Details(gallery::DetailsMessage),
     Dialog(gallery::DialogMessage),
     Input(gallery::InputMessage),
+    Progress(gallery::ProgressMessage),
+    Range(gallery::RangeMessage),
+    Rating(gallery::RatingMessage),
+    SplitPanel(gallery::SplitPanelMessage),
+    TabGroup(gallery::TabGroupMessage),
+    Tag(gallery::TagMessage),
+    Tree(gallery::TreeMessage),
 }
 
 impl Gallery {
@@ -131,14 +214,43 @@ impl Gallery {
                 writeln!(stdout, "Menu item selected").ok();
                 Task::none()
             }
-            Message::SwitchTheme(theme) => {
-                self.theme = theme;
+            Message::SwitchTheme(preference) => {
+                self.theme.set_preference(preference);
+                self.refresh_theme();
+                Task::none()
+            }
+            Message::SystemTheme(event) => {
+                self.theme.update(event);
+                self.refresh_theme();
+                Task::none()
+            }
+            Message::SwitchDensity(density) => {
+                self.density = density;
+                self.refresh_theme();
                 Task::none()
             }
             Message::NavigateToPage(page) => {
                 self.current_page = page;
                 Task::none()
             }
+            Message::FocusNext => iced::widget::operation::focus_next(),
+            Message::FocusPrevious => iced::widget::operation::focus_previous(),
+            Message::Alert(msg) => {
+                gallery::handle_alert_message(&mut self.alert_state, msg);
+                Task::none()
+            }
+            Message::Checkbox(msg) => {
+                gallery::handle_checkbox_message(&mut self.checkbox_state, msg);
+                Task::none()
+            }
+            Message::ColorPicker(msg) => {
+                gallery::handle_color_picker_message(&mut self.color_picker_state, msg);
+                Task::none()
+            }
+            Message::Details(msg) => {
+                gallery::handle_details_message(&mut self.details_state, msg);
+                Task::none()
+            }
             Message::Dialog(msg) => {
                 gallery::handle_dialog_message(&mut self.dialog_state, msg);
                 Task::none()
@@ -147,18 +259,92 @@ impl Gallery {
                 gallery::handle_input_message(&mut self.input_state, msg);
                 Task::none()
             }
+            Message::Progress(msg) => {
+                gallery::handle_progress_message(&mut self.progress_state, msg);
+                Task::none()
+            }
+            Message::Range(msg) => {
+                gallery::handle_range_message(&mut self.range_state, msg);
+                Task::none()
+            }
+            Message::Rating(msg) => {
+                gallery::handle_rating_message(&mut self.rating_state, msg);
+                Task::none()
+            }
+            Message::SplitPanel(msg) => {
+                gallery::handle_split_panel_message(&mut self.split_panel_state, msg);
+                Task::none()
+            }
+            Message::TabGroup(msg) => {
+                gallery::handle_tab_group_message(&mut self.tab_group_state, msg);
+                Task::none()
+            }
+            Message::Tag(msg) => {
+                gallery::handle_tag_message(&mut self.tag_state, msg);
+                Task::none()
+            }
+            Message::Tree(msg) => gallery::handle_tree_message(&mut self.tree_state, msg),
         }
     }
 
     fn view(&self) -> Element<'_, Message> {
-        gallery::view(self.current_page, &self.dialog_state, &self.input_state)
+        gallery::view(
+            self.current_page,
+            self.density,
+            self.current_theme.sizes(),
+            self.current_theme.fonts(),
+            &self.alert_state,
+            &self.checkbox_state,
+            &self.color_picker_state,
+            &self.details_state,
+            &self.dialog_state,
+            &self.input_state,
+            &self.progress_state,
+            &self.range_state,
+            &self.rating_state,
+            &self.split_panel_state,
+            &self.tab_group_state,
+            &self.tag_state,
+            &self.tree_state,
+        )
+    }
+
+    fn subscription(&self) -> iced::Subscription<Message> {
+        iced::Subscription::batch([
+            self.theme.subscription().map(Message::SystemTheme),
+            iced::event::listen_with(focus_key),
+        ])
     }
 
     fn theme(&self) -> Theme {
-        self.theme
+        self.current_theme.clone()
+    }
+
+    fn refresh_theme(&mut self) {
+        self.current_theme = self.theme.theme().with_density(self.density);
     }
 
     fn title(&self) -> String {
         format!("Gallery - {}", self.current_page.name())
     }
 }
+
+/// Moves the keyboard focus with Tab and Shift+Tab, unless a widget used the key
+fn focus_key(
+    event: iced::Event,
+    status: iced::event::Status,
+    _window: iced::window::Id,
+) -> Option<Message> {
+    match event {
+        iced::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+            key: iced::keyboard::Key::Named(iced::keyboard::key::Named::Tab),
+            modifiers,
+            ..
+        }) if status == iced::event::Status::Ignored => Some(if modifiers.shift() {
+            Message::FocusPrevious
+        } else {
+            Message::FocusNext
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,242 @@
+//! Headless snapshot tests of the components
+//!
+//! [`assert_snapshot`] renders a view with the software renderer, once in
+//! [`Theme::Light`] and once in [`Theme::Dark`], and compares the pixels with the
+//! PNGs stored in `snapshots/`. Text is drawn with the bundled [`FONTS`], never
+//! with the fonts of the host, and small differences are tolerated, since text
+//! rasterization still varies slightly between platforms.
+//!
+//! When a snapshot differs, the rendered image and a diff image (changed pixels in
+//! red over a faded copy of the stored snapshot) are written to
+//! `target/snapshots/`. A missing snapshot fails too, so a test cannot pass without
+//! its reference; run with `UPDATE_SNAPSHOTS=1` to record new snapshots and
+//! re-record the ones that changed on purpose.
+use std::{
+    borrow::Cow,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use iced::{
+    Pixels, Size,
+    advanced::{
+        graphics::text::font_system,
+        renderer::{self, Headless},
+    },
+    mouse,
+    theme::Base,
+};
+use iced_runtime::user_interface::{self, UserInterface};
+use image::{Rgba, RgbaImage};
+
+use crate::{
+    Element,
+    theme::{Theme, typography::FONTS},
+};
+
+/// How much a rendering may differ from its snapshot
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance {
+    /// The largest difference of a channel for a pixel to count as unchanged
+    pub channel: u8,
+    /// The fraction of the pixels that may change
+    pub pixels: f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channel: 16,
+            pixels: 0.002,
+        }
+    }
+}
+
+/// The themes every snapshot is rendered in
+const THEMES: [(&str, Theme); 2] = [("light", Theme::Light), ("dark", Theme::Dark)];
+
+/// Renders `view` in the light and dark themes and compares it with its snapshots
+///
+/// # Panics
+///
+/// Panics if any rendering differs from its snapshot beyond the default tolerance.
+pub fn assert_snapshot<'a>(name: &str, size: impl Into<Size>, view: impl Fn() -> Element<'a, ()>) {
+    assert_snapshot_with(name, size, Tolerance::default(), view);
+}
+
+/// Like [`assert_snapshot`], with a custom tolerance
+pub fn assert_snapshot_with<'a>(
+    name: &str,
+    size: impl Into<Size>,
+    tolerance: Tolerance,
+    view: impl Fn() -> Element<'a, ()>,
+) {
+    let size = size.into();
+    let mut failures = Vec::new();
+
+    for (suffix, theme) in THEMES {
+        let image = render(view(), &theme, size);
+        let name = format!("{name}-{suffix}");
+
+        if let Err(failure) = compare(&name, &image, tolerance) {
+            failures.push(failure);
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Draws an element with the software renderer, in the bundled fonts
+pub fn render(element: Element<'_, ()>, theme: &Theme, size: Size) -> RgbaImage {
+    load_fonts();
+
+    let mut renderer = futures::executor::block_on(<iced::Renderer as Headless>::new(
+        FONTS.sans,
+        Pixels(16.0),
+        Some("tiny-skia"),
+    ))
+    .expect("the tiny-skia renderer is always available");
+
+    let base = theme.base();
+    let mut interface = UserInterface::build(
+        element,
+        size,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    interface.draw(
+        &mut renderer,
+        theme,
+        &renderer::Style {
+            text_color: base.text_color,
+        },
+        mouse::Cursor::Unavailable,
+    );
+
+    let width = size.width.ceil() as u32;
+    let height = size.height.ceil() as u32;
+    let pixels = renderer.screenshot(Size::new(width, height), 1.0, base.background_color);
+
+    RgbaImage::from_raw(width, height, pixels).expect("a screenshot has one RGBA pixel per point")
+}
+
+/// Adds the bundled fonts to the font system shared by every renderer
+///
+/// Fonts loaded before are skipped, so this is cheap to call for every rendering.
+fn load_fonts() {
+    let mut fonts = font_system()
+        .write()
+        .expect("the font system is not poisoned");
+
+    for &bytes in FONTS.bundled {
+        fonts.load_font(Cow::Borrowed(bytes));
+    }
+}
+
+/// Compares a rendering with its stored snapshot, or records it with `UPDATE_SNAPSHOTS=1`
+fn compare(name: &str, image: &RgbaImage, tolerance: Tolerance) -> Result<(), String> {
+    let path = snapshots_dir().join(format!("{name}.png"));
+
+    if env::var("UPDATE_SNAPSHOTS").is_ok_and(|update| update == "1") {
+        save(&path, image);
+        return Ok(());
+    }
+
+    if !path.exists() {
+        let actual = save_failure(name, "actual", image);
+
+        return Err(format!(
+            "{name}: {} is missing, see {} and run with UPDATE_SNAPSHOTS=1 to record it",
+            path.display(),
+            actual.display()
+        ));
+    }
+
+    let expected = image::open(&path)
+        .map_err(|error| format!("{name}: cannot read {}: {error}", path.display()))?
+        .into_rgba8();
+
+    if expected.dimensions() != image.dimensions() {
+        let actual = save_failure(name, "actual", image);
+
+        return Err(format!(
+            "{name}: rendered {:?} but the snapshot is {:?}, see {}",
+            image.dimensions(),
+            expected.dimensions(),
+            actual.display()
+        ));
+    }
+
+    let (diff, changed) = diff(&expected, image, tolerance.channel);
+    let total = (image.width() * image.height()).max(1);
+    let ratio = changed as f32 / total as f32;
+
+    if ratio <= tolerance.pixels {
+        return Ok(());
+    }
+
+    let actual = save_failure(name, "actual", image);
+    let diff = save_failure(name, "diff", &diff);
+
+    Err(format!(
+        "{name}: {changed} of {total} pixels differ ({:.2}% > {:.2}%), see {} and {}",
+        ratio * 100.0,
+        tolerance.pixels * 100.0,
+        actual.display(),
+        diff.display()
+    ))
+}
+
+/// Marks the changed pixels in red over a faded copy of the expected image
+fn diff(expected: &RgbaImage, actual: &RgbaImage, channel: u8) -> (RgbaImage, u32) {
+    let mut changed = 0;
+
+    let image = RgbaImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let before = expected.get_pixel(x, y);
+        let after = actual.get_pixel(x, y);
+
+        let differs = before
+            .0
+            .iter()
+            .zip(after.0)
+            .any(|(a, b)| a.abs_diff(b) > channel);
+
+        if differs {
+            changed += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let [r, g, b, _] = before.0;
+            let gray = ((r as u32 + g as u32 + b as u32) / 3) as u8;
+            let faded = 255 - (255 - gray) / 4;
+
+            Rgba([faded, faded, faded, 255])
+        }
+    });
+
+    (image, changed)
+}
+
+fn snapshots_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+fn save_failure(name: &str, kind: &str, image: &RgbaImage) -> PathBuf {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("snapshots")
+        .join(format!("{name}.{kind}.png"));
+
+    save(&path, image);
+    path
+}
+
+fn save(path: &Path, image: &RgbaImage) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("create the snapshot directory");
+    }
+
+    image
+        .save(path)
+        .unwrap_or_else(|error| panic!("cannot write {}: {error}", path.display()));
+}
@@ -0,0 +1,39 @@
+use crate::{
+    theme::{Theme, badge::BadgeVariant},
+    widgets::countdown::{Catalog, Style},
+};
+
+/// Style class for the countdown bar of auto-dismissing alerts
+///
+/// Shoelace alert countdown styling reference:
+/// - Track: neutral-200
+/// - Bar: the alert variant's 600 shade
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlertStyleClass {
+    pub variant: BadgeVariant,
+}
+
+impl Catalog for Theme {
+    type Class<'a> = AlertStyleClass;
+
+    fn default<'a>() -> Self::Class<'a> {
+        AlertStyleClass::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        let tokens = self.tokens();
+
+        let bar = match class.variant {
+            BadgeVariant::Primary => tokens.primary.c600,
+            BadgeVariant::Success => tokens.success.c600,
+            BadgeVariant::Neutral => tokens.neutral.c600,
+            BadgeVariant::Warning => tokens.warning.c600,
+            BadgeVariant::Danger => tokens.danger.c600,
+        };
+
+        Style {
+            track: tokens.neutral.c200,
+            bar,
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use iced::{Background, border::Radius};
 use iced_widget::button;
 
-use crate::theme::Theme;
+use crate::theme::{
+    Theme,
+    parts::{Part, Status},
+};
 
 /// Button variant types matching Shoelace design system
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -32,6 +35,10 @@ pub struct ButtonStyleClass {
     pub outline: bool,
     pub border_radius: Radius,
     pub disabled: bool,
+    /// Overrides of the button itself
+    pub base: Part,
+    /// Overrides of the label; only its text color applies
+    pub label: Part,
 }
 
 // Implement button Catalog trait
@@ -44,6 +51,8 @@ impl button::Catalog for Theme {
             outline: false,
             border_radius: Radius::from(4.0),
             disabled: false,
+            base: Part::default(),
+            label: Part::default(),
         }
     }
 
@@ -160,7 +169,7 @@ impl button::Catalog for Theme {
             }
         };
 
-        button::Style {
+        let style = button::Style {
             background: Some(Background::Color(background)),
             text_color,
             border: iced::Border {
@@ -174,6 +183,18 @@ impl button::Catalog for Theme {
             },
             shadow,
             snap: false,
-        }
+        };
+
+        let status = if is_disabled {
+            Status::Disabled
+        } else {
+            Status::from(status)
+        };
+        let part = class
+            .base
+            .get(status)
+            .merge(class.label.get(status).text_only());
+
+        part.button(style, tokens)
     }
 }
@@ -1,6 +1,9 @@
 use iced_widget::checkbox;
 
-use crate::theme::Theme;
+use crate::theme::{
+    Theme,
+    parts::{Part, Status},
+};
 
 /// Style class for custom checkbox styling
 #[derive(Debug, Clone, Copy, Default)]
@@ -8,6 +11,12 @@ pub struct CheckboxStyleClass {
     pub is_checked: bool,
     pub is_indeterminate: bool,
     pub is_disabled: bool,
+    /// Overrides of the box
+    pub control: Part,
+    /// Overrides of the check mark; only its text color applies
+    pub icon: Part,
+    /// Overrides of the label; only its text color applies
+    pub label: Part,
 }
 
 // Implement checkbox Catalog trait
@@ -19,6 +28,9 @@ impl checkbox::Catalog for Theme {
             is_checked: false,
             is_indeterminate: false,
             is_disabled: false,
+            control: Part::default(),
+            icon: Part::default(),
+            label: Part::default(),
         }
     }
 
@@ -52,7 +64,7 @@ impl checkbox::Catalog for Theme {
             (bg, border, tokens.neutral_0)
         };
 
-        checkbox::Style {
+        let style = checkbox::Style {
             background: iced::Background::Color(background),
             icon_color,
             border: iced::Border {
@@ -61,6 +73,19 @@ impl checkbox::Catalog for Theme {
                 radius: 2.0.into(),
             },
             text_color: None,
-        }
+        };
+
+        let status = if class.is_disabled {
+            Status::Disabled
+        } else {
+            Status::from(status)
+        };
+        let part = class
+            .control
+            .get(status)
+            .merge(class.icon.get(status).text_only());
+        let style = part.checkbox(style, tokens);
+
+        class.label.get(status).checkbox_label(style, tokens)
     }
 }
@@ -0,0 +1,398 @@
+//! Conversions between `Color` and the hex, RGB, HSL and HSV color models
+//!
+//! Hue is always in degrees (0-360) and saturation, lightness and value are
+//! percentages (0-100), matching [`hsl`]. Alpha stays in the 0-1 range used by `Color`.
+//! OKLCH is the exception: its lightness is 0-1 and its chroma is unbounded, though
+//! colors inside sRGB stay below about 0.37.
+//!
+//! [`contrast`] measures legibility as defined by WCAG 2, to be compared against
+//! [`AA`], [`AA_LARGE`] or [`AAA`].
+use iced::Color;
+
+pub use crate::theme::pallete::hsl;
+
+/// The text notations a color can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `#0ea5e9`, or `#0ea5e980` with alpha
+    #[default]
+    Hex,
+    /// `rgb(14, 165, 233)`, or `rgba(14, 165, 233, 0.5)` with alpha
+    Rgb,
+    /// `hsl(199, 89%, 48%)`, or `hsla(199, 89%, 48%, 0.5)` with alpha
+    Hsl,
+}
+
+/// Converts HSL color values and an alpha to a Color
+pub fn hsla(h: f32, s: f32, l: f32, a: f32) -> Color {
+    Color {
+        a: a.clamp(0.0, 1.0),
+        ..hsl(
+            h.rem_euclid(360.0),
+            s.clamp(0.0, 100.0),
+            l.clamp(0.0, 100.0),
+        )
+    }
+}
+
+/// Converts a Color to HSL values `(hue, saturation, lightness)`
+///
+/// Grays have no hue and report 0.
+pub fn to_hsl(color: Color) -> (f32, f32, f32) {
+    let (hue, max, min) = hue_max_min(color);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+
+    let s = if delta == 0.0 {
+        0.0
+    } else {
+        delta / (1.0 - (2.0 * l - 1.0).abs())
+    };
+
+    (hue, s * 100.0, l * 100.0)
+}
+
+/// Converts HSV color values to an opaque Color
+/// h: hue in degrees (0-360)
+/// s: saturation as percentage (0-100)
+/// v: value as percentage (0-100)
+pub fn hsv(h: f32, s: f32, v: f32) -> Color {
+    let s = s.clamp(0.0, 100.0) / 100.0;
+    let v = v.clamp(0.0, 100.0) / 100.0;
+
+    // HSV and HSL share the hue, so convert through HSL
+    let l = v * (1.0 - s / 2.0);
+    let s = if l == 0.0 || l == 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+
+    hsl(h.rem_euclid(360.0), s * 100.0, l * 100.0)
+}
+
+/// Converts a Color to HSV values `(hue, saturation, value)`
+///
+/// Grays have no hue and report 0.
+pub fn to_hsv(color: Color) -> (f32, f32, f32) {
+    let (hue, max, min) = hue_max_min(color);
+    let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+    (hue, s * 100.0, max * 100.0)
+}
+
+/// Converts OKLCH color values to an opaque Color
+/// l: perceptual lightness (0-1)
+/// c: chroma (0 for grays)
+/// h: hue in degrees (0-360)
+///
+/// Colors outside sRGB are clipped channel by channel; use [`in_gamut`] to check first.
+pub fn oklch(l: f32, c: f32, h: f32) -> Color {
+    let [r, g, b] = oklch_to_linear(l, c, h).map(|channel| gamma(channel.clamp(0.0, 1.0)));
+
+    Color::from_rgb(r, g, b)
+}
+
+/// Converts a Color to OKLCH values `(lightness, chroma, hue)`
+///
+/// Grays have no hue and report 0.
+pub fn to_oklch(color: Color) -> (f32, f32, f32) {
+    let [r, g, b] = [color.r, color.g, color.b].map(linear);
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+    let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+    let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+    let chroma = a.hypot(b);
+    let hue = if chroma < 1e-4 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+
+    (lightness, chroma, hue)
+}
+
+/// Whether the OKLCH color can be shown in sRGB without clipping
+pub fn in_gamut(l: f32, c: f32, h: f32) -> bool {
+    oklch_to_linear(l, c, h)
+        .iter()
+        .all(|channel| (-1e-4..=1.0 + 1e-4).contains(channel))
+}
+
+/// The distance between two colors in the OKLab space
+///
+/// About 0.02 is the smallest difference most people notice.
+pub fn difference(a: Color, b: Color) -> f32 {
+    let lab = |color: Color| {
+        let (l, c, h) = to_oklch(color);
+        let h = h.to_radians();
+
+        [l, c * h.cos(), c * h.sin()]
+    };
+
+    let ([l1, a1, b1], [l2, a2, b2]) = (lab(a), lab(b));
+
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+/// The WCAG AA minimum contrast for normal text
+pub const AA: f32 = 4.5;
+
+/// The WCAG AA minimum contrast for large text, icons and other graphics
+pub const AA_LARGE: f32 = 3.0;
+
+/// The WCAG AAA minimum contrast for normal text
+pub const AAA: f32 = 7.0;
+
+/// The relative luminance of a color as defined by WCAG, from 0 (black) to 1 (white)
+///
+/// Alpha is ignored; use [`blend`] first for translucent colors.
+pub fn luminance(color: Color) -> f32 {
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// The WCAG contrast ratio of text drawn over a background, from 1 to 21
+///
+/// A translucent foreground is blended over the background first. The background
+/// is treated as opaque.
+pub fn contrast(foreground: Color, background: Color) -> f32 {
+    let background = Color {
+        a: 1.0,
+        ..background
+    };
+    let foreground = luminance(blend(foreground, background));
+    let background = luminance(background);
+
+    (foreground.max(background) + 0.05) / (foreground.min(background) + 0.05)
+}
+
+/// Draws `top` over an opaque `bottom` with the alpha of `top`
+pub fn blend(top: Color, bottom: Color) -> Color {
+    let mix = |top_channel: f32, bottom_channel: f32| {
+        top_channel * top.a + bottom_channel * (1.0 - top.a)
+    };
+
+    Color::from_rgb(
+        mix(top.r, bottom.r),
+        mix(top.g, bottom.g),
+        mix(top.b, bottom.b),
+    )
+}
+
+/// Interpolates between two colors channel by channel, as a cross-fade would
+///
+/// `amount` goes from 0 (`from`) to 1 (`to`).
+pub fn mix(from: Color, to: Color, amount: f32) -> Color {
+    let amount = amount.clamp(0.0, 1.0);
+    let mix = |from: f32, to: f32| from + (to - from) * amount;
+
+    Color {
+        r: mix(from.r, to.r),
+        g: mix(from.g, to.g),
+        b: mix(from.b, to.b),
+        a: mix(from.a, to.a),
+    }
+}
+
+/// Formats a Color as `#rrggbb`, or `#rrggbbaa` when it is translucent
+pub fn to_hex(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with or without the `#`
+pub fn from_hex(hex: &str) -> Option<Color> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+    let short = |index: usize| channel(index..index + 1).map(|value| value * 17);
+
+    let [r, g, b, a] = match hex.len() {
+        3 => [short(0)?, short(1)?, short(2)?, u8::MAX],
+        4 => [short(0)?, short(1)?, short(2)?, short(3)?],
+        6 => [channel(0..2)?, channel(2..4)?, channel(4..6)?, u8::MAX],
+        8 => [
+            channel(0..2)?,
+            channel(2..4)?,
+            channel(4..6)?,
+            channel(6..8)?,
+        ],
+        _ => return None,
+    };
+
+    Some(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+}
+
+/// Formats a Color in the given notation
+pub fn format(color: Color, format: Format) -> String {
+    let alpha = round(color.a, 100.0);
+
+    match format {
+        Format::Hex => to_hex(color),
+        Format::Rgb => {
+            let [r, g, b, _] = color.into_rgba8();
+
+            if color.a < 1.0 {
+                format!("rgba({r}, {g}, {b}, {alpha})")
+            } else {
+                format!("rgb({r}, {g}, {b})")
+            }
+        }
+        Format::Hsl => {
+            let (h, s, l) = to_hsl(color);
+            let (h, s, l) = (h.round(), s.round(), l.round());
+
+            if color.a < 1.0 {
+                format!("hsla({h}, {s}%, {l}%, {alpha})")
+            } else {
+                format!("hsl({h}, {s}%, {l}%)")
+            }
+        }
+    }
+}
+
+/// Parses a color written as hex, `rgb()`/`rgba()` or `hsl()`/`hsla()`
+///
+/// Function arguments may be separated by commas or spaces, and alpha may be
+/// given as a fourth argument or after a `/`, as a number or a percentage.
+pub fn parse(input: &str) -> Option<Color> {
+    let input = input.trim().to_ascii_lowercase();
+
+    let Some((name, arguments)) = input
+        .strip_suffix(')')
+        .and_then(|input| input.split_once('('))
+    else {
+        return from_hex(&input);
+    };
+
+    let arguments: Vec<&str> = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|argument| !argument.is_empty())
+        .collect();
+
+    let alpha = match arguments.get(3) {
+        Some(alpha) => fraction(alpha, 1.0)?,
+        None => 1.0,
+    };
+
+    if !(3..=4).contains(&arguments.len()) || !(0.0..=1.0).contains(&alpha) {
+        return None;
+    }
+
+    match name.trim() {
+        "rgb" | "rgba" => {
+            let channel = |argument: &str| {
+                fraction(argument, 255.0).filter(|channel| (0.0..=1.0).contains(channel))
+            };
+
+            Some(Color::from_rgba(
+                channel(arguments[0])?,
+                channel(arguments[1])?,
+                channel(arguments[2])?,
+                alpha,
+            ))
+        }
+        "hsl" | "hsla" => {
+            let hue = arguments[0].strip_suffix("deg").unwrap_or(arguments[0]);
+            let percent = |argument: &str| {
+                argument
+                    .strip_suffix('%')
+                    .unwrap_or(argument)
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|percent| (0.0..=100.0).contains(percent))
+            };
+
+            Some(hsla(
+                hue.parse().ok()?,
+                percent(arguments[1])?,
+                percent(arguments[2])?,
+                alpha,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Parses a number, or a percentage, as a fraction of `scale`
+fn fraction(argument: &str, scale: f32) -> Option<f32> {
+    let value = match argument.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None => argument.parse::<f32>().ok()? / scale,
+    };
+
+    value.is_finite().then_some(value)
+}
+
+/// Returns the hue in degrees and the largest and smallest RGB channels
+fn hue_max_min(color: Color) -> (f32, f32, f32) {
+    let Color { r, g, b, .. } = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (hue, max, min)
+}
+
+/// Converts OKLCH to linear sRGB channels, which may fall outside 0-1
+fn oklch_to_linear(l: f32, c: f32, h: f32) -> [f32; 3] {
+    let h = h.to_radians();
+    let (a, b) = (c * h.cos(), c * h.sin());
+
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    [
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    ]
+}
+
+/// Converts an sRGB channel to linear light
+fn linear(channel: f32) -> f32 {
+    if channel <= 0.040_45 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel to sRGB
+fn gamma(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn round(value: f32, precision: f32) -> f32 {
+    (value * precision).round() / precision
+}
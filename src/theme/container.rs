@@ -4,7 +4,7 @@ use crate::theme::{
     Theme,
     badge::BadgeVariant,
     pallete::{ColorToken, ColorValue, ColorVariant},
-    sizes::BORDER_RADIUS,
+    parts::PartStyle,
 };
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -19,6 +19,7 @@ pub enum ContainerStyleClass {
         variant: BadgeVariant,
         border_radius: f32,
         pulse: bool,
+        part: PartStyle,
     },
     /// Card container - white background with border, shadow, and rounded corners
     Card,
@@ -36,6 +37,15 @@ pub enum ContainerStyleClass {
     DialogHeader,
     /// Dialog footer - transparent background with no border (separation via padding)
     DialogFooter,
+    /// Alert panel - neutral background with border; toasts are raised with a large shadow
+    Alert { toast: bool },
+    /// Alert icon - text colored with the alert variant
+    AlertIcon { variant: BadgeVariant },
+    /// Tag - light variant background with a matching border
+    Tag {
+        variant: BadgeVariant,
+        border_radius: f32,
+    },
     Custom {
         background: Option<ColorToken>,
         text_color: Option<ColorToken>,
@@ -45,6 +55,11 @@ pub enum ContainerStyleClass {
         shadow: Shadow,
         snap: bool,
     },
+    /// A transparent container with a part style override, see [`PartStyle`]
+    ///
+    /// Unlike `Custom`, only the fields set in the style are drawn, so it can wrap
+    /// labels and icons that otherwise keep the colors of their parent.
+    Part(PartStyle),
 }
 
 // Implement container Catalog trait
@@ -57,17 +72,17 @@ impl container::Catalog for Theme {
 
     fn style(&self, class: &Self::Class<'_>) -> container::Style {
         let tokens = self.tokens();
+        let sizes = self.sizes();
 
         match class {
             ContainerStyleClass::Default => {
                 // Default container background
                 // Light theme: light gray (neutral-50) for subtle contrast with white cards
                 // Dark theme: darkest (neutral-0) for maximum contrast with lighter cards
-                let background = match self {
-                    crate::theme::Theme::Light => {
-                        ColorToken::new(ColorVariant::Neutral, ColorValue::C50).get_color(tokens)
-                    }
-                    crate::theme::Theme::Dark => tokens.neutral_0,
+                let background = if self.is_dark() {
+                    tokens.neutral_0
+                } else {
+                    ColorToken::new(ColorVariant::Neutral, ColorValue::C50).get_color(tokens)
                 };
 
                 container::Style {
@@ -85,7 +100,7 @@ impl container::Catalog for Theme {
                     background: Some(Background::Color(tokens.neutral.c800)),
                     text_color: Some(tokens.neutral_0),
                     border: Border {
-                        radius: BORDER_RADIUS.medium.into(),
+                        radius: sizes.border_radius.medium.into(),
                         ..Default::default()
                     },
                     shadow: Shadow {
@@ -111,6 +126,7 @@ impl container::Catalog for Theme {
                 variant,
                 border_radius,
                 pulse: _pulse,
+                part,
             } => {
                 // Badge styling matching Shoelace design
                 // Note: pulse animation is not implemented in the static style
@@ -122,7 +138,7 @@ impl container::Catalog for Theme {
                     BadgeVariant::Danger => (tokens.danger.c600, tokens.neutral_0),
                 };
 
-                container::Style {
+                let style = container::Style {
                     background: Some(Background::Color(background)),
                     text_color: Some(text_color),
                     border: Border {
@@ -132,7 +148,9 @@ impl container::Catalog for Theme {
                     },
                     shadow: Shadow::default(),
                     snap: false,
-                }
+                };
+
+                part.container(style, tokens)
             }
             ContainerStyleClass::Card => {
                 // Card styling matching Shoelace design
@@ -151,7 +169,7 @@ impl container::Catalog for Theme {
                     border: Border {
                         color: border_color,
                         width: 1.0,
-                        radius: BORDER_RADIUS.medium.into(),
+                        radius: sizes.border_radius.medium.into(),
                     },
                     shadow: Shadow {
                         color: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
@@ -219,7 +237,7 @@ impl container::Catalog for Theme {
                     border: Border {
                         color: border_color,
                         width: 1.,
-                        radius: BORDER_RADIUS.medium.into(),
+                        radius: sizes.border_radius.medium.into(),
                     },
                     shadow: Shadow {
                         // Shoelace's x-large shadow: more prominent and softer than cards
@@ -253,6 +271,80 @@ impl container::Catalog for Theme {
                     snap: false,
                 }
             }
+            ContainerStyleClass::Alert { toast } => {
+                // Alert styling matching Shoelace design
+                // Uses --sl-panel-background-color, --sl-panel-border-color (neutral-200)
+                // and --sl-border-radius-medium; toasts add --sl-shadow-large
+                // See: https://github.com/shoelace-style/shoelace/blob/next/src/components/alert/alert.styles.ts
+                let text_color =
+                    ColorToken::new(ColorVariant::Neutral, ColorValue::C700).get_color(tokens);
+                let border_color =
+                    ColorToken::new(ColorVariant::Neutral, ColorValue::C200).get_color(tokens);
+
+                let shadow = if *toast {
+                    Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
+                        offset: iced::Vector::new(0.0, 8.0),
+                        blur_radius: 16.0,
+                    }
+                } else {
+                    Shadow::default()
+                };
+
+                container::Style {
+                    background: Some(Background::Color(tokens.neutral_0)),
+                    text_color: Some(text_color),
+                    border: Border {
+                        color: border_color,
+                        width: 1.0,
+                        radius: sizes.border_radius.medium.into(),
+                    },
+                    shadow,
+                    snap: false,
+                }
+            }
+            ContainerStyleClass::AlertIcon { variant } => {
+                let text_color = match variant {
+                    BadgeVariant::Primary => tokens.primary.c600,
+                    BadgeVariant::Success => tokens.success.c600,
+                    BadgeVariant::Neutral => tokens.neutral.c600,
+                    BadgeVariant::Warning => tokens.warning.c600,
+                    BadgeVariant::Danger => tokens.danger.c600,
+                };
+
+                container::Style {
+                    background: None,
+                    text_color: Some(text_color),
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                }
+            }
+            ContainerStyleClass::Tag {
+                variant,
+                border_radius,
+            } => {
+                // Shoelace tags use the variant's 50 background, 200 border and 800 text
+                let scale = match variant {
+                    BadgeVariant::Primary => tokens.primary,
+                    BadgeVariant::Success => tokens.success,
+                    BadgeVariant::Neutral => tokens.neutral,
+                    BadgeVariant::Warning => tokens.warning,
+                    BadgeVariant::Danger => tokens.danger,
+                };
+
+                container::Style {
+                    background: Some(Background::Color(scale.c50)),
+                    text_color: Some(scale.c800),
+                    border: Border {
+                        color: scale.c200,
+                        width: 1.0,
+                        radius: (*border_radius).into(),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                }
+            }
             ContainerStyleClass::Custom {
                 background,
                 text_color,
@@ -282,6 +374,17 @@ impl container::Catalog for Theme {
                     snap: *snap,
                 }
             }
+            ContainerStyleClass::Part(part) => {
+                let style = container::Style {
+                    background: None,
+                    text_color: None,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                };
+
+                part.container(style, tokens)
+            }
         }
     }
 }
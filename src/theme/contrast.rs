@@ -0,0 +1,556 @@
+//! Checks that the text drawn by every built-in style is legible
+//!
+//! Each test walks the `Catalog` styles of a theme for every variant and status and
+//! measures the text against the background it is drawn on. Transparent backgrounds
+//! are measured against the `neutral-0` surface. Disabled states are skipped, as
+//! WCAG exempts inactive components.
+//!
+//! Graphics that identify a control, such as check marks, slider thumbs, scrollers
+//! and focus rings, are held to the large text minimum. Rules and split panel
+//! dividers only separate content, so they are left out, and the split panel is
+//! measured by its focus ring instead.
+use iced::{
+    Background, Color,
+    border::Radius,
+    widget::{button, checkbox, container, scrollable, text, text_input},
+};
+
+use crate::{
+    components::input::InputSize,
+    theme::{
+        ScrollableClass, Theme,
+        alert::AlertStyleClass,
+        badge::BadgeVariant,
+        button::{ButtonStyleClass, ButtonVariant},
+        checkbox::CheckboxStyleClass,
+        color::{self, AA, AA_LARGE},
+        container::ContainerStyleClass,
+        input::InputStyleClass,
+        pallete::{ColorToken, ColorValue, ColorVariant},
+        parts::{Part, PartStyle},
+        range::RangeStyleClass,
+        split_panel::SplitPanelStyleClass,
+        tab_group::TabGroupStyleClass,
+        text::TextStyleClass,
+    },
+    widgets::{countdown, range, split, tab_indicator},
+};
+
+const BUTTON_VARIANTS: [ButtonVariant; 7] = [
+    ButtonVariant::Default,
+    ButtonVariant::Primary,
+    ButtonVariant::Success,
+    ButtonVariant::Neutral,
+    ButtonVariant::Warning,
+    ButtonVariant::Danger,
+    ButtonVariant::Text,
+];
+
+const BADGE_VARIANTS: [BadgeVariant; 5] = [
+    BadgeVariant::Primary,
+    BadgeVariant::Success,
+    BadgeVariant::Neutral,
+    BadgeVariant::Warning,
+    BadgeVariant::Danger,
+];
+
+/// The text colors components pass to the text catalog, drawn over the surface
+const TEXT_COLORS: [(&str, ColorVariant, ColorValue); 5] = [
+    // Tree chevrons and hints
+    ("neutral-500", ColorVariant::Neutral, ColorValue::C500),
+    // Captions and inactive tabs
+    ("neutral-600", ColorVariant::Neutral, ColorValue::C600),
+    // Labels and tree items
+    ("neutral-700", ColorVariant::Neutral, ColorValue::C700),
+    // The active tab
+    ("primary-600", ColorVariant::Primary, ColorValue::C600),
+    // Validation errors
+    ("danger-600", ColorVariant::Danger, ColorValue::C600),
+];
+
+/// The failures the Shoelace themes inherit, as `(themes, combinations, reason)`
+///
+/// Each combination is the start of a report line. Fixing one makes
+/// `built_in_themes_fail_only_known_combinations` fail until it is removed from this list.
+const KNOWN_FAILURES: [(&[&str], &[&str], &str); 7] = [
+    (
+        &["light"],
+        &[
+            "button Primary ",
+            "button Text ",
+            "container Badge { variant: Primary",
+            "text primary-600",
+        ],
+        "Shoelace's light primary-600 (sky) reaches 4.1:1 against white",
+    ),
+    (
+        &["light"],
+        &[
+            "button Success ",
+            "button Warning ",
+            "container Badge { variant: Success",
+            "container Badge { variant: Warning",
+            "countdown Success",
+            "countdown Warning",
+        ],
+        "Shoelace's light success-600 and warning-600 (green, amber) reach 3.2-3.3:1 against white",
+    ),
+    (
+        &["light", "dark"],
+        &[
+            "button Neutral outline=false Hovered",
+            "button Danger outline=false Hovered",
+            "button Danger outline=true Hovered",
+            "checkbox Hovered",
+            "range Hovered",
+            "range Dragged",
+        ],
+        "Hovered controls lighten to the 500 step, or to a 50 background for outlines, as in Shoelace",
+    ),
+    (
+        &["dark"],
+        &["button Text outline=false Pressed"],
+        "Pressed text buttons keep Shoelace's primary-600 label over a primary-100 background",
+    ),
+    (
+        &["light"],
+        &["input placeholder filled=true"],
+        "Shoelace's neutral-500 placeholder reaches 4.4:1 on hovered and focused filled inputs",
+    ),
+    (
+        &["dark"],
+        &["input placeholder", "text neutral-500"],
+        "Shoelace's dark neutral-500, used for placeholders and hints, reaches 2.7-3.3:1",
+    ),
+    (
+        &["dark"],
+        &["scrollable Default Active"],
+        "The idle scroller reaches 3.3:1 against the page but only 2.96:1 against its rail",
+    ),
+];
+
+/// The combinations of a theme that fall below their minimum contrast
+struct Report {
+    surface: Color,
+    checked: usize,
+    failures: Vec<String>,
+}
+
+impl Report {
+    fn new(theme: &Theme) -> Self {
+        let mut report = Self {
+            surface: theme.tokens().neutral_0,
+            checked: 0,
+            failures: Vec::new(),
+        };
+
+        report.buttons(theme);
+        report.inputs(theme);
+        report.containers(theme);
+        report.checkboxes(theme);
+        report.texts(theme);
+        report.countdowns(theme);
+        report.ranges(theme);
+        report.scrollables(theme);
+        report.split_panels(theme);
+        report.tab_groups(theme);
+        report
+    }
+
+    fn check(
+        &mut self,
+        name: impl FnOnce() -> String,
+        foreground: Color,
+        background: Option<Background>,
+        minimum: f32,
+    ) {
+        let background = match background {
+            Some(Background::Color(background)) => color::blend(background, self.surface),
+            Some(Background::Gradient(_)) => return,
+            None => self.surface,
+        };
+
+        let ratio = color::contrast(foreground, background);
+        self.checked += 1;
+
+        if ratio < minimum {
+            self.failures.push(format!(
+                "{}: {ratio:.2} < {minimum} ({} on {})",
+                name(),
+                color::to_hex(foreground),
+                color::to_hex(background)
+            ));
+        }
+    }
+
+    fn buttons(&mut self, theme: &Theme) {
+        let statuses = [
+            button::Status::Active,
+            button::Status::Hovered,
+            button::Status::Pressed,
+        ];
+
+        for variant in BUTTON_VARIANTS {
+            for outline in [false, true] {
+                for status in statuses {
+                    let class = ButtonStyleClass {
+                        variant,
+                        outline,
+                        border_radius: Radius::from(4.0),
+                        disabled: false,
+                        base: Part::default(),
+                        label: Part::default(),
+                    };
+                    let style = <Theme as button::Catalog>::style(theme, &class, status);
+
+                    self.check(
+                        || format!("button {variant:?} outline={outline} {status:?}"),
+                        style.text_color,
+                        style.background,
+                        AA,
+                    );
+                }
+            }
+        }
+    }
+
+    fn inputs(&mut self, theme: &Theme) {
+        let statuses = [
+            text_input::Status::Active,
+            text_input::Status::Hovered,
+            text_input::Status::Focused { is_hovered: false },
+        ];
+
+        for filled in [false, true] {
+            for status in statuses {
+                let class = InputStyleClass {
+                    size: InputSize::Medium,
+                    disabled: false,
+                    filled,
+                    pill: false,
+                    base: Part::default(),
+                    input: Part::default(),
+                };
+                let style = <Theme as text_input::Catalog>::style(theme, &class, status);
+                let background = Some(style.background);
+
+                // Selected text is drawn over the selection, over the input background
+                let selection = match style.background {
+                    Background::Color(background) => color::blend(style.selection, background),
+                    Background::Gradient(_) => style.selection,
+                };
+
+                self.check(
+                    || format!("input value filled={filled} {status:?}"),
+                    style.value,
+                    background,
+                    AA,
+                );
+                self.check(
+                    || format!("input placeholder filled={filled} {status:?}"),
+                    style.placeholder,
+                    background,
+                    AA,
+                );
+                self.check(
+                    || format!("input selection filled={filled} {status:?}"),
+                    style.value,
+                    Some(Background::Color(selection)),
+                    AA,
+                );
+            }
+        }
+    }
+
+    fn containers(&mut self, theme: &Theme) {
+        let mut classes = vec![
+            (ContainerStyleClass::Default, AA),
+            (ContainerStyleClass::Tooltip, AA),
+            (ContainerStyleClass::Card, AA),
+            (ContainerStyleClass::Dialog, AA),
+            (ContainerStyleClass::Alert { toast: false }, AA),
+            (ContainerStyleClass::Alert { toast: true }, AA),
+        ];
+
+        for variant in BADGE_VARIANTS {
+            classes.push((
+                ContainerStyleClass::Badge {
+                    variant,
+                    border_radius: 4.0,
+                    pulse: false,
+                    part: PartStyle::default(),
+                },
+                AA,
+            ));
+            classes.push((
+                ContainerStyleClass::Tag {
+                    variant,
+                    border_radius: 4.0,
+                },
+                AA,
+            ));
+            // Alert icons are graphics, which need less contrast than text
+            classes.push((ContainerStyleClass::AlertIcon { variant }, AA_LARGE));
+        }
+
+        for (class, minimum) in classes {
+            let style = <Theme as container::Catalog>::style(theme, &class);
+
+            // Containers without a text color keep the color of their parent
+            if let Some(text_color) = style.text_color {
+                self.check(
+                    || format!("container {class:?}"),
+                    text_color,
+                    style.background,
+                    minimum,
+                );
+            }
+        }
+    }
+
+    fn checkboxes(&mut self, theme: &Theme) {
+        let statuses = [
+            checkbox::Status::Active { is_checked: true },
+            checkbox::Status::Hovered { is_checked: true },
+        ];
+
+        // Unchecked boxes draw no icon
+        for (checked, indeterminate) in [(true, false), (false, true)] {
+            for status in statuses {
+                let class = CheckboxStyleClass {
+                    is_checked: checked,
+                    is_indeterminate: indeterminate,
+                    is_disabled: false,
+                    control: Part::default(),
+                    icon: Part::default(),
+                    label: Part::default(),
+                };
+                let style = <Theme as checkbox::Catalog>::style(theme, &class, status);
+
+                self.check(
+                    || {
+                        format!(
+                            "checkbox {status:?} checked={checked} indeterminate={indeterminate}"
+                        )
+                    },
+                    style.icon_color,
+                    Some(style.background),
+                    AA_LARGE,
+                );
+            }
+        }
+    }
+
+    fn texts(&mut self, theme: &Theme) {
+        for (name, variant, value) in TEXT_COLORS {
+            let class = TextStyleClass {
+                color: Some(ColorToken::new(variant, value)),
+            };
+            let style = <Theme as text::Catalog>::style(theme, &class);
+
+            if let Some(color) = style.color {
+                self.check(|| format!("text {name}"), color, None, AA);
+            }
+        }
+    }
+
+    fn countdowns(&mut self, theme: &Theme) {
+        for variant in BADGE_VARIANTS {
+            let style = <Theme as countdown::Catalog>::style(theme, &AlertStyleClass { variant });
+
+            // The remaining time is only shown by the bar
+            self.check(
+                || format!("countdown {variant:?}"),
+                style.bar,
+                Some(Background::Color(style.track)),
+                AA_LARGE,
+            );
+        }
+    }
+
+    fn ranges(&mut self, theme: &Theme) {
+        let statuses = [
+            range::Status::Active,
+            range::Status::Hovered,
+            range::Status::Dragged,
+        ];
+
+        for status in statuses {
+            let style = <Theme as range::Catalog>::style(theme, &RangeStyleClass::Default, status);
+
+            self.check(
+                || format!("range {status:?} thumb"),
+                style.thumb,
+                Some(Background::Color(style.thumb_border)),
+                AA_LARGE,
+            );
+            self.check(
+                || format!("range {status:?} track"),
+                style.rail_active,
+                Some(Background::Color(style.rail)),
+                AA_LARGE,
+            );
+        }
+    }
+
+    fn scrollables(&mut self, theme: &Theme) {
+        let statuses = [
+            scrollable::Status::Active {
+                is_horizontal_scrollbar_disabled: false,
+                is_vertical_scrollbar_disabled: false,
+            },
+            scrollable::Status::Hovered {
+                is_horizontal_scrollbar_hovered: false,
+                is_vertical_scrollbar_hovered: true,
+                is_horizontal_scrollbar_disabled: false,
+                is_vertical_scrollbar_disabled: false,
+            },
+            scrollable::Status::Dragged {
+                is_horizontal_scrollbar_dragged: false,
+                is_vertical_scrollbar_dragged: true,
+                is_horizontal_scrollbar_disabled: false,
+                is_vertical_scrollbar_disabled: false,
+            },
+        ];
+
+        for class in [ScrollableClass::Default, ScrollableClass::Subtle] {
+            for status in statuses {
+                let style = <Theme as scrollable::Catalog>::style(theme, &class, status);
+                let rail = style.vertical_rail;
+
+                self.check(
+                    || format!("scrollable {class:?} {status:?}"),
+                    rail.scroller.color,
+                    rail.background,
+                    AA_LARGE,
+                );
+            }
+        }
+    }
+
+    fn split_panels(&mut self, theme: &Theme) {
+        let style = <Theme as split::Catalog>::style(
+            theme,
+            &SplitPanelStyleClass::Default,
+            split::Status::Active,
+        );
+
+        self.check(
+            || "split panel focus ring".to_owned(),
+            style.focus,
+            None,
+            AA_LARGE,
+        );
+    }
+
+    fn tab_groups(&mut self, theme: &Theme) {
+        let style = <Theme as tab_indicator::Catalog>::style(theme, &TabGroupStyleClass::Default);
+
+        // The indicator marks the active tab, drawn over the track
+        self.check(
+            || "tab group indicator".to_owned(),
+            style.indicator,
+            Some(Background::Color(style.track)),
+            AA_LARGE,
+        );
+    }
+
+    fn print(&self, name: &str) {
+        println!(
+            "{name}: {} of {} combinations below WCAG AA",
+            self.failures.len(),
+            self.checked
+        );
+
+        for failure in &self.failures {
+            println!("  {failure}");
+        }
+    }
+}
+
+#[test]
+fn contrast_ratio_bounds() {
+    let close = |ratio: f32, expected: f32| (ratio - expected).abs() < 1e-3;
+
+    assert!(close(color::contrast(Color::BLACK, Color::WHITE), 21.0));
+    assert!(close(color::contrast(Color::WHITE, Color::BLACK), 21.0));
+    assert!(close(color::contrast(Color::WHITE, Color::WHITE), 1.0));
+
+    // A fully transparent foreground disappears into the background
+    assert!(close(
+        color::contrast(Color::TRANSPARENT, Color::BLACK),
+        1.0
+    ));
+}
+
+#[test]
+fn high_contrast_themes_meet_aa() {
+    for (name, theme) in [
+        ("high contrast light", Theme::HighContrastLight),
+        ("high contrast dark", Theme::HighContrastDark),
+    ] {
+        let report = Report::new(&theme);
+        report.print(name);
+
+        assert!(report.checked > 0);
+        assert!(
+            report.failures.is_empty(),
+            "{name} fails WCAG AA:\n{}",
+            report.failures.join("\n")
+        );
+    }
+}
+
+/// Checks that the Shoelace themes fail only the combinations in [`KNOWN_FAILURES`]
+///
+/// The built-in themes follow Shoelace, which does not meet AA everywhere. Any other
+/// failure is a regression, and a listed combination that passes must be removed.
+/// Run with `--nocapture` to see the reports.
+#[test]
+fn built_in_themes_fail_only_known_combinations() {
+    let reports = [("light", Theme::Light), ("dark", Theme::Dark)].map(|(name, theme)| {
+        let report = Report::new(&theme);
+        report.print(name);
+
+        assert!(report.checked > 0);
+        (name, report)
+    });
+
+    for (name, report) in &reports {
+        let known: Vec<&str> = KNOWN_FAILURES
+            .iter()
+            .filter(|(themes, _, _)| themes.contains(name))
+            .flat_map(|(_, combinations, _)| combinations.iter().copied())
+            .collect();
+        let unexpected: Vec<&str> = report
+            .failures
+            .iter()
+            .map(String::as_str)
+            .filter(|failure| !known.iter().any(|known| failure.starts_with(*known)))
+            .collect();
+
+        assert!(
+            unexpected.is_empty(),
+            "{name} fails WCAG AA outside the known failures:\n{}",
+            unexpected.join("\n")
+        );
+    }
+
+    for (themes, combinations, reason) in KNOWN_FAILURES {
+        for &combination in combinations {
+            let fails = reports.iter().any(|(name, report)| {
+                themes.contains(name)
+                    && report
+                        .failures
+                        .iter()
+                        .any(|failure| failure.starts_with(combination))
+            });
+
+            assert!(
+                fails,
+                "`{combination}` now meets WCAG AA in {themes:?}; remove it from the known \
+                 failures ({reason})"
+            );
+        }
+    }
+}
@@ -0,0 +1,697 @@
+//! Importing Shoelace theme stylesheets
+//!
+//! Shoelace themes are CSS files declaring custom properties such as
+//! `--sl-color-primary-600: hsl(200.4 98% 39.4%)` or `--sl-spacing-medium: 1rem`.
+//! [`Stylesheet`] reads such a file, whether it is the light, the dark or a custom
+//! theme, and produces the same [`ThemeFile`] as a JSON or TOML theme file.
+//!
+//! Only one theme is read from a stylesheet. By default it is the first rule that
+//! declares `--sl-` custom properties, such as `:root, :host, .sl-theme-light` in
+//! Shoelace's `light.css`. [`Stylesheet::selector`] picks the rules of another
+//! selector instead, e.g. `.sl-theme-dark` from a stylesheet bundling both themes.
+//! Within those rules declarations are read in order, so later declarations win.
+//!
+//! `var(--sl-...)` references, with or without a fallback, are resolved against the
+//! same rules. Lengths are converted to pixels, with `rem` multiplied by the root
+//! font size. `em` lengths depend on the font size of the element they are used on,
+//! so they are reported rather than guessed. Anything the stylesheet leaves out is
+//! taken from the built-in theme of the same mode, which is dark when the rules
+//! declare `color-scheme: dark`.
+//!
+//! Custom properties without a matching token, such as shadows, transitions or
+//! `--sl-input-*` colors, and values that cannot be converted are collected in
+//! [`Import::unsupported`] rather than failing the import.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use iced_shoelace::theme::css::Stylesheet;
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let import = Stylesheet::new()
+//!     .root_size(16.0)
+//!     .selector(".sl-theme-dark")
+//!     .load("themes/dark.css")?;
+//!
+//! for unsupported in &import.unsupported {
+//!     eprintln!("{unsupported}");
+//! }
+//!
+//! let theme = import.theme.theme();
+//! # Ok(())
+//! # }
+//! ```
+use std::{fmt, path::Path};
+
+use iced::Color;
+
+use crate::theme::{
+    Theme, color,
+    file::ThemeFile,
+    pallete::{ColorValue, ColorVariant},
+    sizes::SIZES,
+    tokens::Tokens,
+    typography::FONTS,
+};
+
+/// The prefix every Shoelace custom property starts with
+const PREFIX: &str = "--sl-";
+
+/// How deeply `var()` references may refer to each other
+const MAX_DEPTH: usize = 16;
+
+/// Reads Shoelace theme stylesheets
+#[derive(Debug, Clone)]
+pub struct Stylesheet {
+    root_size: f32,
+    dark: Option<bool>,
+    selector: Option<String>,
+}
+
+impl Default for Stylesheet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stylesheet {
+    pub fn new() -> Self {
+        Self {
+            root_size: 16.0,
+            dark: None,
+            selector: None,
+        }
+    }
+
+    /// Sets the size of `1rem` in pixels. Default: 16
+    pub fn root_size(mut self, root_size: f32) -> Self {
+        self.root_size = root_size;
+        self
+    }
+
+    /// Sets whether the theme is dark instead of reading `color-scheme`
+    pub fn dark(mut self, dark: bool) -> Self {
+        self.dark = Some(dark);
+        self
+    }
+
+    /// Reads the rules of `selector`, e.g. `.sl-theme-dark`, instead of the first
+    /// rule declaring `--sl-` custom properties
+    ///
+    /// A rule matches when any of its comma separated selectors is `selector`.
+    pub fn selector(mut self, selector: impl Into<String>) -> Self {
+        self.selector = Some(selector.into());
+        self
+    }
+
+    /// Reads a stylesheet from a file
+    pub fn load(&self, path: impl AsRef<Path>) -> std::io::Result<Import> {
+        Ok(self.parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Reads a stylesheet
+    pub fn parse(&self, css: &str) -> Import {
+        let declarations = self.declarations(css);
+
+        let dark = self.dark.unwrap_or_else(|| {
+            declarations
+                .iter()
+                .rev()
+                .find(|(name, _)| name == "color-scheme")
+                .is_some_and(|(_, value)| value.split_whitespace().next() == Some("dark"))
+        });
+
+        let base = if dark { Theme::Dark } else { Theme::Light };
+        let mut theme = ThemeFile {
+            name: None,
+            dark,
+            tokens: base.tokens(),
+            sizes: SIZES,
+            fonts: FONTS,
+        };
+        let mut unsupported = Vec::new();
+
+        for (name, value) in &declarations {
+            if !name.starts_with(PREFIX) {
+                continue;
+            }
+
+            let reason = match resolve(value, &declarations, 0) {
+                Some(value) => self.apply(&mut theme, name, &value).err(),
+                None => Some(Reason::UnresolvedReference),
+            };
+
+            if let Some(reason) = reason {
+                unsupported.push(Unsupported {
+                    name: name.clone(),
+                    value: value.clone(),
+                    reason,
+                });
+            }
+        }
+
+        Import { theme, unsupported }
+    }
+
+    /// Writes a resolved custom property into its token
+    fn apply(&self, theme: &mut ThemeFile, name: &str, value: &str) -> Result<(), Reason> {
+        let name = &name[PREFIX.len()..];
+
+        if let Some(color) = name.strip_prefix("color-") {
+            let target = color_token(&mut theme.tokens, color).ok_or(Reason::UnknownProperty)?;
+            *target = color::parse(value).ok_or(Reason::InvalidValue)?;
+            return Ok(());
+        }
+
+        let sizes = &mut theme.sizes;
+
+        if let Some(weight) = name.strip_prefix("font-weight-") {
+            let target = match weight {
+                "light" => &mut sizes.font_weight.light,
+                "normal" => &mut sizes.font_weight.normal,
+                "semibold" => &mut sizes.font_weight.semibold,
+                "bold" => &mut sizes.font_weight.bold,
+                _ => return Err(Reason::UnknownProperty),
+            };
+            *target = match value {
+                "normal" => 400,
+                "bold" => 700,
+                _ => value.parse().map_err(|_| Reason::InvalidValue)?,
+            };
+            return Ok(());
+        }
+
+        if let Some(height) = name.strip_prefix("line-height-") {
+            let target = match height {
+                "denser" => &mut sizes.line_height.denser,
+                "dense" => &mut sizes.line_height.dense,
+                "normal" => &mut sizes.line_height.normal,
+                "loose" => &mut sizes.line_height.loose,
+                "looser" => &mut sizes.line_height.looser,
+                _ => return Err(Reason::UnknownProperty),
+            };
+            *target = number(value).ok_or(Reason::InvalidValue)?;
+            return Ok(());
+        }
+
+        let target = if let Some(radius) = name.strip_prefix("border-radius-") {
+            match radius {
+                "small" => &mut sizes.border_radius.small,
+                "medium" => &mut sizes.border_radius.medium,
+                "large" => &mut sizes.border_radius.large,
+                "x-large" => &mut sizes.border_radius.x_large,
+                _ => return Err(Reason::UnknownProperty),
+            }
+        } else if let Some(spacing) = name.strip_prefix("spacing-") {
+            match spacing {
+                "3x-small" => &mut sizes.spacing.x3_small,
+                "2x-small" => &mut sizes.spacing.x2_small,
+                "x-small" => &mut sizes.spacing.x_small,
+                "small" => &mut sizes.spacing.small,
+                "medium" => &mut sizes.spacing.medium,
+                "large" => &mut sizes.spacing.large,
+                "x-large" => &mut sizes.spacing.x_large,
+                "2x-large" => &mut sizes.spacing.x2_large,
+                "3x-large" => &mut sizes.spacing.x3_large,
+                "4x-large" => &mut sizes.spacing.x4_large,
+                _ => return Err(Reason::UnknownProperty),
+            }
+        } else if let Some(size) = name.strip_prefix("font-size-") {
+            match size {
+                "2x-small" => &mut sizes.font_size.x2_small,
+                "x-small" => &mut sizes.font_size.x_small,
+                "small" => &mut sizes.font_size.small,
+                "medium" => &mut sizes.font_size.medium,
+                "large" => &mut sizes.font_size.large,
+                "x-large" => &mut sizes.font_size.x_large,
+                "2x-large" => &mut sizes.font_size.x2_large,
+                "3x-large" => &mut sizes.font_size.x3_large,
+                "4x-large" => &mut sizes.font_size.x4_large,
+                _ => return Err(Reason::UnknownProperty),
+            }
+        } else if let Some(height) = name.strip_prefix("input-height-") {
+            match height {
+                "small" => &mut sizes.input_height.small,
+                "medium" => &mut sizes.input_height.medium,
+                "large" => &mut sizes.input_height.large,
+                _ => return Err(Reason::UnknownProperty),
+            }
+        } else if let Some(size) = name.strip_prefix("toggle-size-") {
+            match size {
+                "small" => &mut sizes.toggle_size.small,
+                "medium" => &mut sizes.toggle_size.medium,
+                "large" => &mut sizes.toggle_size.large,
+                _ => return Err(Reason::UnknownProperty),
+            }
+        } else {
+            return Err(Reason::UnknownProperty);
+        };
+
+        *target = self.length(value)?;
+        Ok(())
+    }
+
+    /// The declarations of the theme to read, in stylesheet order
+    fn declarations(&self, css: &str) -> Vec<(String, String)> {
+        let rules = rules(css);
+
+        match &self.selector {
+            Some(selector) => rules
+                .into_iter()
+                .filter(|rule| rule.selectors.iter().any(|candidate| candidate == selector))
+                .flat_map(|rule| rule.declarations)
+                .collect(),
+            None => rules
+                .into_iter()
+                .find(|rule| {
+                    rule.declarations
+                        .iter()
+                        .any(|(name, _)| name.starts_with(PREFIX))
+                })
+                .map(|rule| rule.declarations)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Converts a CSS length to pixels
+    fn length(&self, value: &str) -> Result<f32, Reason> {
+        let pixels = if let Some(rem) = value.strip_suffix("rem") {
+            number(rem).map(|rem| rem * self.root_size)
+        } else if value.ends_with("em") {
+            return Err(Reason::RelativeLength);
+        } else if let Some(px) = value.strip_suffix("px") {
+            number(px)
+        } else {
+            // Only a unitless zero is a valid length
+            number(value).filter(|value| *value == 0.0)
+        };
+
+        pixels
+            .filter(|pixels| *pixels >= 0.0)
+            .ok_or(Reason::InvalidValue)
+    }
+}
+
+/// The result of reading a stylesheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    /// The tokens of the stylesheet, completed from the built-in theme
+    pub theme: ThemeFile,
+    /// The custom properties that were skipped, in stylesheet order
+    pub unsupported: Vec<Unsupported>,
+}
+
+/// A custom property that could not be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsupported {
+    /// The property, e.g. `--sl-shadow-small`
+    pub name: String,
+    /// The value as written in the stylesheet
+    pub value: String,
+    pub reason: Reason,
+}
+
+/// Why a custom property could not be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The property has no matching token
+    UnknownProperty,
+    /// The value is not a color, length or number the token accepts
+    InvalidValue,
+    /// The value refers to an undeclared property without a fallback, or to itself
+    UnresolvedReference,
+    /// The value is an `em` length, which depends on the font size of the element
+    RelativeLength,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.reason {
+            Reason::UnknownProperty => "no matching token",
+            Reason::InvalidValue => "unsupported value",
+            Reason::UnresolvedReference => "unresolved var() reference",
+            Reason::RelativeLength => "em lengths depend on the element, use rem or px",
+        };
+
+        write!(f, "{}: {} ({reason})", self.name, self.value)
+    }
+}
+
+const COLOR_VARIANTS: [(&str, ColorVariant); 23] = [
+    ("gray", ColorVariant::Gray),
+    ("red", ColorVariant::Red),
+    ("orange", ColorVariant::Orange),
+    ("amber", ColorVariant::Amber),
+    ("yellow", ColorVariant::Yellow),
+    ("lime", ColorVariant::Lime),
+    ("green", ColorVariant::Green),
+    ("emerald", ColorVariant::Emerald),
+    ("teal", ColorVariant::Teal),
+    ("cyan", ColorVariant::Cyan),
+    ("sky", ColorVariant::Sky),
+    ("blue", ColorVariant::Blue),
+    ("indigo", ColorVariant::Indigo),
+    ("violet", ColorVariant::Violet),
+    ("purple", ColorVariant::Purple),
+    ("fuchsia", ColorVariant::Fuchsia),
+    ("pink", ColorVariant::Pink),
+    ("rose", ColorVariant::Rose),
+    ("primary", ColorVariant::Primary),
+    ("success", ColorVariant::Success),
+    ("warning", ColorVariant::Warning),
+    ("danger", ColorVariant::Danger),
+    ("neutral", ColorVariant::Neutral),
+];
+
+const COLOR_VALUES: [(&str, ColorValue); 11] = [
+    ("50", ColorValue::C50),
+    ("100", ColorValue::C100),
+    ("200", ColorValue::C200),
+    ("300", ColorValue::C300),
+    ("400", ColorValue::C400),
+    ("500", ColorValue::C500),
+    ("600", ColorValue::C600),
+    ("700", ColorValue::C700),
+    ("800", ColorValue::C800),
+    ("900", ColorValue::C900),
+    ("950", ColorValue::C950),
+];
+
+/// Finds the color a `--sl-color-<scale>-<step>` property sets
+fn color_token<'a>(tokens: &'a mut Tokens, name: &str) -> Option<&'a mut Color> {
+    match name {
+        "neutral-0" => return Some(&mut tokens.neutral_0),
+        "neutral-1000" => return Some(&mut tokens.neutral_1000),
+        _ => {}
+    }
+
+    let (scale, step) = name.rsplit_once('-')?;
+    let (_, variant) = COLOR_VARIANTS.iter().find(|(key, _)| *key == scale)?;
+    let (_, value) = COLOR_VALUES.iter().find(|(key, _)| *key == step)?;
+
+    Some(variant.get_color_mut(tokens, *value))
+}
+
+/// A block of a stylesheet and the `property: value` declarations directly inside it
+struct Rule {
+    /// The comma separated selectors, or the at-rule, before the block
+    selectors: Vec<String>,
+    declarations: Vec<(String, String)>,
+}
+
+/// Splits a stylesheet into its rules, in the order their blocks open
+///
+/// Rules nested in at-rules such as `@media` are listed on their own, after the
+/// at-rule. Declarations outside of any block are dropped.
+fn rules(css: &str) -> Vec<Rule> {
+    let css = strip_comments(css);
+    let mut rules: Vec<Rule> = Vec::new();
+    // The rules whose blocks are open, innermost last
+    let mut open = Vec::new();
+    let mut start = 0;
+
+    for (index, c) in css.char_indices() {
+        if !matches!(c, '{' | '}' | ';') {
+            continue;
+        }
+
+        let text = &css[start..index];
+        start = index + 1;
+
+        // The text before an opening brace is a selector or an at-rule
+        if c == '{' {
+            open.push(rules.len());
+            rules.push(Rule {
+                selectors: text
+                    .split(',')
+                    .map(|selector| selector.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .collect(),
+                declarations: Vec::new(),
+            });
+            continue;
+        }
+
+        if let Some(&rule) = open.last()
+            && let Some((name, value)) = text.split_once(':')
+        {
+            let value = value.trim();
+            let value = value.strip_suffix("!important").unwrap_or(value).trim();
+
+            rules[rule]
+                .declarations
+                .push((name.trim().to_owned(), value.to_owned()));
+        }
+
+        if c == '}' {
+            open.pop();
+        }
+    }
+
+    rules
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Replaces every `var()` in `value` with the value it refers to
+fn resolve(value: &str, declarations: &[(String, String)], depth: usize) -> Option<String> {
+    let Some(start) = value.find("var(") else {
+        return Some(value.to_owned());
+    };
+
+    if depth >= MAX_DEPTH {
+        return None;
+    }
+
+    let end = closing_parenthesis(value, start + 4)?;
+    let (name, fallback) = match value[start + 4..end].split_once(',') {
+        Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+        None => (value[start + 4..end].trim(), None),
+    };
+
+    let referenced = declarations
+        .iter()
+        .rev()
+        .find(|(declared, _)| declared == name)
+        .map(|(_, value)| value.as_str())
+        .or(fallback)?;
+
+    let replaced = format!("{}{}{}", &value[..start], referenced, &value[end + 1..]);
+
+    resolve(&replaced, declarations, depth + 1)
+}
+
+/// Returns the index of the parenthesis closing the one opened before `start`
+fn closing_parenthesis(value: &str, start: usize) -> Option<usize> {
+    let mut open = 1;
+
+    for (index, c) in value[start..].char_indices() {
+        match c {
+            '(' => open += 1,
+            ')' => {
+                open -= 1;
+                if open == 0 {
+                    return Some(start + index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn number(value: &str) -> Option<f32> {
+    value
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIGHT: &str = include_str!("../../fixtures/shoelace/light.css");
+    const DARK: &str = include_str!("../../fixtures/shoelace/dark.css");
+
+    /// The scales the fixtures declare
+    const SCALES: [ColorVariant; 6] = [
+        ColorVariant::Gray,
+        ColorVariant::Red,
+        ColorVariant::Sky,
+        ColorVariant::Primary,
+        ColorVariant::Danger,
+        ColorVariant::Neutral,
+    ];
+
+    /// Checks the fixture colors against the built-in theme they were taken from
+    fn assert_tokens(imported: Tokens, builtin: &Theme) {
+        let builtin = builtin.tokens();
+
+        for variant in SCALES {
+            for (step, value) in COLOR_VALUES {
+                let difference = color::difference(
+                    variant.get_color(imported, value),
+                    variant.get_color(builtin, value),
+                );
+
+                assert!(
+                    difference < 0.005,
+                    "{variant:?} {step} is {difference} away"
+                );
+            }
+        }
+
+        assert!(color::difference(imported.neutral_0, builtin.neutral_0) < 0.005);
+        assert!(color::difference(imported.neutral_1000, builtin.neutral_1000) < 0.005);
+    }
+
+    fn unsupported(import: &Import) -> Vec<(&str, Reason)> {
+        import
+            .unsupported
+            .iter()
+            .map(|unsupported| (unsupported.name.as_str(), unsupported.reason))
+            .collect()
+    }
+
+    /// The fixture properties without a matching token
+    const UNKNOWN: [&str; 11] = [
+        "--sl-border-radius-circle",
+        "--sl-border-radius-pill",
+        "--sl-shadow-x-small",
+        "--sl-shadow-small",
+        "--sl-transition-fast",
+        "--sl-transition-medium",
+        "--sl-font-mono",
+        "--sl-font-sans",
+        "--sl-focus-ring-color",
+        "--sl-focus-ring-width",
+        "--sl-input-background-color",
+    ];
+
+    #[test]
+    fn imports_light_css() {
+        let import = Stylesheet::new().parse(LIGHT);
+        let sizes = import.theme.sizes;
+
+        assert!(!import.theme.dark);
+        assert_tokens(import.theme.tokens, &Theme::Light);
+
+        assert_eq!(sizes.border_radius.small, 3.0);
+        assert_eq!(sizes.spacing.small, 12.0);
+        assert_eq!(sizes.font_size.medium, 16.0);
+        assert_eq!(sizes.font_weight.semibold, 500);
+        assert_eq!(sizes.line_height.dense, 1.4);
+        assert_eq!(sizes.input_height.medium, 40.0);
+        assert_eq!(sizes.toggle_size.large, 22.0);
+
+        assert_eq!(
+            unsupported(&import),
+            UNKNOWN.map(|name| (name, Reason::UnknownProperty))
+        );
+    }
+
+    #[test]
+    fn imports_dark_css() {
+        let import = Stylesheet::new().parse(DARK);
+
+        assert!(import.theme.dark);
+        assert_tokens(import.theme.tokens, &Theme::Dark);
+        assert_eq!(
+            import.theme.sizes,
+            Stylesheet::new().parse(LIGHT).theme.sizes
+        );
+    }
+
+    #[test]
+    fn reads_one_theme_of_a_bundle() {
+        let bundle = format!("{LIGHT}\n{DARK}");
+
+        let first = Stylesheet::new().parse(&bundle);
+        assert!(!first.theme.dark);
+        assert_tokens(first.theme.tokens, &Theme::Light);
+
+        let dark = Stylesheet::new().selector(".sl-theme-dark").parse(&bundle);
+        assert!(dark.theme.dark);
+        assert_tokens(dark.theme.tokens, &Theme::Dark);
+
+        let light = Stylesheet::new().selector(":root").parse(&bundle);
+        assert_eq!(light, first);
+    }
+
+    #[test]
+    fn unknown_selector_imports_nothing() {
+        let import = Stylesheet::new().selector(".sl-theme-brand").parse(LIGHT);
+
+        assert_eq!(import.theme.tokens, Theme::Light.tokens());
+        assert_eq!(import.theme.sizes, SIZES);
+        assert!(import.unsupported.is_empty());
+    }
+
+    #[test]
+    fn selector_rules_are_read_in_order() {
+        let import = Stylesheet::new().selector(".brand").parse(
+            ".brand { --sl-spacing-small: 4px; }
+             .other { --sl-spacing-small: 6px; }
+             .base, .brand { --sl-spacing-medium: 2rem; --sl-spacing-small: 10px; }",
+        );
+
+        assert_eq!(import.theme.sizes.spacing.small, 10.0);
+        assert_eq!(import.theme.sizes.spacing.medium, 32.0);
+    }
+
+    #[test]
+    fn em_lengths_are_reported() {
+        let import = Stylesheet::new().parse(":root { --sl-spacing-small: 0.75em; }");
+
+        assert_eq!(import.theme.sizes.spacing.small, SIZES.spacing.small);
+        assert_eq!(
+            unsupported(&import),
+            [("--sl-spacing-small", Reason::RelativeLength)]
+        );
+    }
+
+    #[test]
+    fn lengths_follow_the_root_size() {
+        let import = Stylesheet::new()
+            .root_size(12.0)
+            .parse(":root { --sl-spacing-small: 0.5rem; --sl-spacing-medium: 10px; }");
+
+        assert_eq!(import.theme.sizes.spacing.small, 6.0);
+        assert_eq!(import.theme.sizes.spacing.medium, 10.0);
+    }
+
+    #[test]
+    fn unresolved_references_are_reported() {
+        let import = Stylesheet::new().parse(
+            ":root {
+                --sl-color-primary-600: var(--sl-color-brand-600);
+                --sl-color-primary-700: var(--sl-color-brand-700, #6d28d9);
+            }",
+        );
+
+        assert_eq!(
+            import.theme.tokens.primary.c700,
+            color::from_hex("#6d28d9").unwrap()
+        );
+        assert_eq!(
+            unsupported(&import),
+            [("--sl-color-primary-600", Reason::UnresolvedReference)]
+        );
+    }
+}
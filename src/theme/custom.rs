@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use iced::Color;
+
+use crate::theme::{
+    Theme,
+    pallete::{ColorValue, ColorVariant},
+    sizes::{Density, SIZES, Sizes},
+    tokens::{ColorScale, Tokens},
+    typography::{FONTS, Fonts},
+};
+
+/// A theme created at runtime from an owned set of tokens
+///
+/// A custom theme starts as a copy of a built-in theme and is then derived from it
+/// by swapping whole semantic scales or overriding single colors. Every style class
+/// reads its colors from [`Theme::tokens`], so all components follow the custom
+/// tokens. The size tokens can be swapped the same way, see [`CustomTheme::density`].
+///
+/// ## Example
+///
+/// ```rust
+/// use iced::Color;
+/// use iced_shoelace::theme::{CustomTheme, Theme};
+/// use iced_shoelace::theme::pallete::{ColorValue, ColorVariant};
+///
+/// let light = Theme::Light.tokens();
+/// let brand = CustomTheme::new("Brand", &Theme::Light)
+///     .primary(light.violet)
+///     .neutral(light.gray)
+///     .color(ColorVariant::Primary, ColorValue::C600, Color::from_rgb8(0x6d, 0x28, 0xd9))
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomTheme {
+    name: String,
+    dark: bool,
+    tokens: Tokens,
+    sizes: Sizes,
+    fonts: Fonts,
+}
+
+impl CustomTheme {
+    /// Creates a custom theme with the tokens, sizes, fonts and light or dark mode of `base`
+    pub fn new(name: impl Into<String>, base: &Theme) -> Self {
+        Self {
+            name: name.into(),
+            dark: base.is_dark(),
+            tokens: base.tokens(),
+            sizes: base.sizes(),
+            fonts: base.fonts(),
+        }
+    }
+
+    /// Creates a custom theme from a complete set of tokens, with the built-in sizes and fonts
+    pub fn from_tokens(name: impl Into<String>, dark: bool, tokens: Tokens) -> Self {
+        Self {
+            name: name.into(),
+            dark,
+            tokens,
+            sizes: SIZES,
+            fonts: FONTS,
+        }
+    }
+
+    /// Sets the scale used for primary actions and focus rings
+    pub fn primary(mut self, scale: ColorScale) -> Self {
+        self.tokens.primary = scale;
+        self
+    }
+
+    /// Sets the scale used for success states
+    pub fn success(mut self, scale: ColorScale) -> Self {
+        self.tokens.success = scale;
+        self
+    }
+
+    /// Sets the scale used for warning states
+    pub fn warning(mut self, scale: ColorScale) -> Self {
+        self.tokens.warning = scale;
+        self
+    }
+
+    /// Sets the scale used for danger states
+    pub fn danger(mut self, scale: ColorScale) -> Self {
+        self.tokens.danger = scale;
+        self
+    }
+
+    /// Sets the scale used for text, borders and surfaces
+    pub fn neutral(mut self, scale: ColorScale) -> Self {
+        self.tokens.neutral = scale;
+        self
+    }
+
+    /// Overrides a single color
+    ///
+    /// The value is ignored for `NeutralBase` and `NeutralDark`, which are single colors.
+    pub fn color(mut self, variant: ColorVariant, value: ColorValue, color: Color) -> Self {
+        *variant.get_color_mut(&mut self.tokens, value) = color;
+        self
+    }
+
+    /// Sets the size tokens
+    pub fn sizes(mut self, sizes: Sizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Sets the size tokens to a built-in preset
+    pub fn density(self, density: Density) -> Self {
+        self.sizes(density.sizes())
+    }
+
+    /// Sets the sans and mono font families
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// The name of the theme
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the theme is dark
+    pub fn is_dark(&self) -> bool {
+        self.dark
+    }
+
+    /// The tokens of the theme
+    pub fn tokens(&self) -> &Tokens {
+        &self.tokens
+    }
+
+    /// The size tokens of the theme
+    pub fn size_tokens(&self) -> &Sizes {
+        &self.sizes
+    }
+
+    /// The font families of the theme
+    pub fn font_families(&self) -> &Fonts {
+        &self.fonts
+    }
+
+    /// Wraps the custom theme in a [`Theme`]
+    pub fn build(self) -> Theme {
+        Theme::Custom(Arc::new(self))
+    }
+}
+
+impl From<CustomTheme> for Theme {
+    fn from(custom: CustomTheme) -> Self {
+        custom.build()
+    }
+}
@@ -0,0 +1,658 @@
+//! Reading and writing theme tokens as JSON or TOML files
+//!
+//! A theme file holds the color tokens and the size tokens of a theme. Every key is
+//! optional: whatever a file leaves out is taken from the built-in dark or light
+//! theme it names, so a file can be as small as a single brand color. Unknown keys
+//! are rejected, so a misspelled key is reported instead of silently ignored.
+//!
+//! Fonts are not part of the file format, since a theme's fonts bundle font files
+//! that a text file can't carry. [`ThemeFile::builtin`] keeps the theme's fonts in
+//! [`ThemeFile::fonts`] and [`ThemeFile::theme`] applies them again, but a file read
+//! from disk uses the built-in fonts until `fonts` is set.
+//!
+//! # Format
+//!
+//! ```toml
+//! # Optional display name
+//! name = "Brand"
+//! # Whether the theme is dark; omitted values come from the built-in dark theme
+//! # when true and the built-in light theme otherwise. Default: false
+//! dark = false
+//!
+//! [colors]
+//! # Colors accept #rgb, #rrggbb, #rrggbbaa, rgb(...), rgba(...), hsl(...) and hsla(...)
+//! neutral_0 = "#ffffff"
+//! neutral_1000 = "#000000"
+//!
+//! # One table per scale: gray, red, orange, amber, yellow, lime, green, emerald,
+//! # teal, cyan, sky, blue, indigo, violet, purple, fuchsia, pink, rose and the
+//! # semantic primary, success, warning, danger and neutral scales.
+//! # Steps are 50, 100, 200, ..., 900 and 950.
+//! [colors.primary]
+//! 500 = "#8b5cf6"
+//! 600 = "hsl(262, 83%, 58%)"
+//!
+//! # Sizes are in pixels, line heights are multiples of the font size
+//! [spacing]      # x3_small, x2_small, x_small, small, medium, large,
+//! small = 8      # x_large, x2_large, x3_large, x4_large
+//! [font_size]    # x2_small, x_small, small, medium, large, x_large,
+//! medium = 13    # x2_large, x3_large, x4_large
+//! [font_weight]  # light, normal, semibold, bold
+//! [border_radius] # small, medium, large, x_large
+//! [line_height]  # denser, dense, normal, loose, looser
+//! [input_height] # small, medium, large
+//! [toggle_size]  # small, medium, large
+//! ```
+//!
+//! JSON files use the same keys, e.g. `{ "colors": { "primary": { "600": "#7c3aed" } } }`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use iced_shoelace::theme::{Theme, file::ThemeFile};
+//!
+//! # fn main() -> Result<(), iced_shoelace::theme::file::Error> {
+//! let file = ThemeFile::load("brand.toml")?;
+//! let theme = file.theme();
+//!
+//! // Write out every token of the built-in light theme as a starting point
+//! ThemeFile::builtin(&Theme::Light).save("light.json")?;
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use iced::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::theme::{
+    CustomTheme, Theme, color,
+    sizes::{
+        BorderRadius, FontSize, FontWeight, InputHeight, LineHeight, SIZES, Sizes, Spacing,
+        ToggleSize,
+    },
+    tokens::{ColorScale, Tokens},
+    typography::{FONTS, Fonts},
+};
+
+/// The tokens of a theme, as stored in a theme file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeFile {
+    pub name: Option<String>,
+    pub dark: bool,
+    pub tokens: Tokens,
+    pub sizes: Sizes,
+    /// Not written to or read from files; files read from disk use the built-in fonts
+    pub fonts: Fonts,
+}
+
+impl ThemeFile {
+    /// Captures every token and the fonts of `theme`
+    pub fn builtin(theme: &Theme) -> Self {
+        Self {
+            name: match theme {
+                Theme::Custom(custom) => Some(custom.name().to_owned()),
+                _ => None,
+            },
+            dark: theme.is_dark(),
+            tokens: theme.tokens(),
+            sizes: theme.sizes(),
+            fonts: theme.fonts(),
+        }
+    }
+
+    /// Parses a theme file written as JSON
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str::<Document>(json)
+            .map_err(Error::Json)?
+            .resolve())
+    }
+
+    /// Parses a theme file written as TOML
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        Ok(toml::from_str::<Document>(toml)
+            .map_err(Error::TomlRead)?
+            .resolve())
+    }
+
+    /// Writes every token as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(&Document::capture(self)).map_err(Error::Json)
+    }
+
+    /// Writes every token as TOML
+    pub fn to_toml(&self) -> Result<String, Error> {
+        toml::to_string_pretty(&Document::capture(self)).map_err(Error::TomlWrite)
+    }
+
+    /// Reads a `.json` or `.toml` theme file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let format = Format::of(path)?;
+        let contents =
+            std::fs::read_to_string(path).map_err(|error| Error::Io(path.to_owned(), error))?;
+
+        match format {
+            Format::Json => Self::from_json(&contents),
+            Format::Toml => Self::from_toml(&contents),
+        }
+    }
+
+    /// Writes a `.json` or `.toml` theme file, depending on the extension of `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        let contents = match Format::of(path)? {
+            Format::Json => self.to_json()?,
+            Format::Toml => self.to_toml()?,
+        };
+
+        std::fs::write(path, contents).map_err(|error| Error::Io(path.to_owned(), error))
+    }
+
+    /// Creates a custom theme from the color and size tokens and the fonts
+    pub fn theme(&self) -> Theme {
+        CustomTheme::from_tokens(
+            self.name.clone().unwrap_or_else(|| "Custom".to_owned()),
+            self.dark,
+            self.tokens,
+        )
+        .sizes(self.sizes)
+        .fonts(self.fonts)
+        .build()
+    }
+}
+
+/// An error reading or writing a theme file
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read or written
+    Io(PathBuf, io::Error),
+    /// The file extension is neither `.json` nor `.toml`
+    UnknownFormat(PathBuf),
+    /// The JSON is malformed, has an unknown key or an invalid value
+    Json(serde_json::Error),
+    /// The TOML is malformed, has an unknown key or an invalid value
+    TomlRead(toml::de::Error),
+    /// The tokens could not be written as TOML
+    TomlWrite(toml::ser::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            Error::UnknownFormat(path) => write!(
+                f,
+                "{}: theme files must have a .json or .toml extension",
+                path.display()
+            ),
+            Error::Json(error) => write!(f, "invalid theme file: {error}"),
+            Error::TomlRead(error) => write!(f, "invalid theme file: {error}"),
+            Error::TomlWrite(error) => write!(f, "could not write theme file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, error) => Some(error),
+            Error::UnknownFormat(_) => None,
+            Error::Json(error) => Some(error),
+            Error::TomlRead(error) => Some(error),
+            Error::TomlWrite(error) => Some(error),
+        }
+    }
+}
+
+enum Format {
+    Json,
+    Toml,
+}
+
+impl Format {
+    fn of(path: &Path) -> Result<Self, Error> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Ok(Format::Json),
+            Some(extension) if extension.eq_ignore_ascii_case("toml") => Ok(Format::Toml),
+            _ => Err(Error::UnknownFormat(path.to_owned())),
+        }
+    }
+}
+
+/// A value read from a theme file that replaces its built-in counterpart
+trait Field<T> {
+    /// Writes the value over `target`, keeping anything the file left out
+    fn apply(self, target: &mut T);
+
+    /// Captures a complete value for writing
+    fn capture(value: &T) -> Self;
+}
+
+impl Field<f32> for f32 {
+    fn apply(self, target: &mut f32) {
+        *target = self;
+    }
+
+    fn capture(value: &f32) -> Self {
+        *value
+    }
+}
+
+impl Field<u16> for u16 {
+    fn apply(self, target: &mut u16) {
+        *target = self;
+    }
+
+    fn capture(value: &u16) -> Self {
+        *value
+    }
+}
+
+/// A color written as text, in any notation [`color::parse`] accepts
+#[derive(Debug, Clone, Copy)]
+struct FileColor(Color);
+
+impl Field<Color> for FileColor {
+    fn apply(self, target: &mut Color) {
+        *target = self.0;
+    }
+
+    fn capture(value: &Color) -> Self {
+        FileColor(*value)
+    }
+}
+
+impl Serialize for FileColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color::to_hex(self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for FileColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        color::parse(&value).map(FileColor).ok_or_else(|| {
+            de::Error::custom(format!(
+                "invalid color `{value}`, expected #rrggbb, rgb(...) or hsl(...)"
+            ))
+        })
+    }
+}
+
+/// Declares a table of a theme file mirroring a token struct, with every key optional
+macro_rules! table {
+    ($name:ident: $target:ty { $($field:ident $(as $key:literal)?: $value:ty),* $(,)? }) => {
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        #[serde(default, deny_unknown_fields)]
+        struct $name {
+            $(
+                $(#[serde(rename = $key)])?
+                #[serde(skip_serializing_if = "Option::is_none")]
+                $field: Option<$value>,
+            )*
+        }
+
+        impl Field<$target> for $name {
+            fn apply(self, target: &mut $target) {
+                $(
+                    if let Some(value) = self.$field {
+                        value.apply(&mut target.$field);
+                    }
+                )*
+            }
+
+            fn capture(value: &$target) -> Self {
+                Self {
+                    $($field: Some(Field::capture(&value.$field)),)*
+                }
+            }
+        }
+    };
+}
+
+table!(ScaleTable: ColorScale {
+    c50 as "50": FileColor,
+    c100 as "100": FileColor,
+    c200 as "200": FileColor,
+    c300 as "300": FileColor,
+    c400 as "400": FileColor,
+    c500 as "500": FileColor,
+    c600 as "600": FileColor,
+    c700 as "700": FileColor,
+    c800 as "800": FileColor,
+    c900 as "900": FileColor,
+    c950 as "950": FileColor,
+});
+
+// Single colors come first, as TOML requires plain values before tables
+table!(ColorsTable: Tokens {
+    neutral_0: FileColor,
+    neutral_1000: FileColor,
+    gray: ScaleTable,
+    red: ScaleTable,
+    orange: ScaleTable,
+    amber: ScaleTable,
+    yellow: ScaleTable,
+    lime: ScaleTable,
+    green: ScaleTable,
+    emerald: ScaleTable,
+    teal: ScaleTable,
+    cyan: ScaleTable,
+    sky: ScaleTable,
+    blue: ScaleTable,
+    indigo: ScaleTable,
+    violet: ScaleTable,
+    purple: ScaleTable,
+    fuchsia: ScaleTable,
+    pink: ScaleTable,
+    rose: ScaleTable,
+    primary: ScaleTable,
+    success: ScaleTable,
+    warning: ScaleTable,
+    danger: ScaleTable,
+    neutral: ScaleTable,
+});
+
+table!(BorderRadiusTable: BorderRadius {
+    small: f32,
+    medium: f32,
+    large: f32,
+    x_large: f32,
+});
+
+table!(SpacingTable: Spacing {
+    x3_small: f32,
+    x2_small: f32,
+    x_small: f32,
+    small: f32,
+    medium: f32,
+    large: f32,
+    x_large: f32,
+    x2_large: f32,
+    x3_large: f32,
+    x4_large: f32,
+});
+
+table!(FontSizeTable: FontSize {
+    x2_small: f32,
+    x_small: f32,
+    small: f32,
+    medium: f32,
+    large: f32,
+    x_large: f32,
+    x2_large: f32,
+    x3_large: f32,
+    x4_large: f32,
+});
+
+table!(FontWeightTable: FontWeight {
+    light: u16,
+    normal: u16,
+    semibold: u16,
+    bold: u16,
+});
+
+table!(LineHeightTable: LineHeight {
+    denser: f32,
+    dense: f32,
+    normal: f32,
+    loose: f32,
+    looser: f32,
+});
+
+table!(InputHeightTable: InputHeight {
+    small: f32,
+    medium: f32,
+    large: f32,
+});
+
+table!(ToggleSizeTable: ToggleSize {
+    small: f32,
+    medium: f32,
+    large: f32,
+});
+
+/// The whole file
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Document {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dark: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<ColorsTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spacing: Option<SpacingTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_size: Option<FontSizeTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font_weight: Option<FontWeightTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_radius: Option<BorderRadiusTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_height: Option<LineHeightTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_height: Option<InputHeightTable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    toggle_size: Option<ToggleSizeTable>,
+}
+
+impl Document {
+    /// Fills everything the file left out from the built-in theme
+    fn resolve(self) -> ThemeFile {
+        let dark = self.dark.unwrap_or(false);
+        let base = if dark { Theme::Dark } else { Theme::Light };
+
+        let mut tokens = base.tokens();
+        if let Some(colors) = self.colors {
+            colors.apply(&mut tokens);
+        }
+
+        let mut sizes = SIZES;
+        if let Some(spacing) = self.spacing {
+            spacing.apply(&mut sizes.spacing);
+        }
+        if let Some(font_size) = self.font_size {
+            font_size.apply(&mut sizes.font_size);
+        }
+        if let Some(font_weight) = self.font_weight {
+            font_weight.apply(&mut sizes.font_weight);
+        }
+        if let Some(border_radius) = self.border_radius {
+            border_radius.apply(&mut sizes.border_radius);
+        }
+        if let Some(line_height) = self.line_height {
+            line_height.apply(&mut sizes.line_height);
+        }
+        if let Some(input_height) = self.input_height {
+            input_height.apply(&mut sizes.input_height);
+        }
+        if let Some(toggle_size) = self.toggle_size {
+            toggle_size.apply(&mut sizes.toggle_size);
+        }
+
+        ThemeFile {
+            name: self.name,
+            dark,
+            tokens,
+            sizes,
+            fonts: FONTS,
+        }
+    }
+
+    fn capture(file: &ThemeFile) -> Self {
+        let sizes = &file.sizes;
+
+        Self {
+            name: file.name.clone(),
+            dark: Some(file.dark),
+            colors: Some(ColorsTable::capture(&file.tokens)),
+            spacing: Some(SpacingTable::capture(&sizes.spacing)),
+            font_size: Some(FontSizeTable::capture(&sizes.font_size)),
+            font_weight: Some(FontWeightTable::capture(&sizes.font_weight)),
+            border_radius: Some(BorderRadiusTable::capture(&sizes.border_radius)),
+            line_height: Some(LineHeightTable::capture(&sizes.line_height)),
+            input_height: Some(InputHeightTable::capture(&sizes.input_height)),
+            toggle_size: Some(ToggleSizeTable::capture(&sizes.toggle_size)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::sizes::COMPACT;
+
+    /// Writes a file out and reads it back, once colors are rounded to hex
+    fn round_trip(
+        file: &ThemeFile,
+        write: impl Fn(&ThemeFile) -> Result<String, Error>,
+        read: impl Fn(&str) -> Result<ThemeFile, Error>,
+    ) {
+        let written = read(&write(file).unwrap()).unwrap();
+
+        assert_eq!(written.name, file.name);
+        assert_eq!(written.dark, file.dark);
+        assert_eq!(written.sizes, file.sizes);
+        assert!(color::difference(written.tokens.primary.c600, file.tokens.primary.c600) < 0.005);
+
+        // Colors are already rounded to hex, so nothing changes the second time
+        assert_eq!(read(&write(&written).unwrap()).unwrap(), written);
+    }
+
+    fn brand() -> ThemeFile {
+        ThemeFile {
+            name: Some("Brand".to_owned()),
+            sizes: COMPACT,
+            ..ThemeFile::builtin(&Theme::Dark)
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        round_trip(
+            &ThemeFile::builtin(&Theme::Light),
+            ThemeFile::to_json,
+            ThemeFile::from_json,
+        );
+        round_trip(&brand(), ThemeFile::to_json, ThemeFile::from_json);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        round_trip(
+            &ThemeFile::builtin(&Theme::Light),
+            ThemeFile::to_toml,
+            ThemeFile::from_toml,
+        );
+        round_trip(&brand(), ThemeFile::to_toml, ThemeFile::from_toml);
+    }
+
+    #[test]
+    fn missing_keys_come_from_the_builtin_theme() {
+        let empty = ThemeFile::from_toml("").unwrap();
+        assert_eq!(empty.name, None);
+        assert!(!empty.dark);
+        assert_eq!(empty.tokens, Theme::Light.tokens());
+        assert_eq!(empty.sizes, SIZES);
+
+        let dark = ThemeFile::from_json(r#"{ "dark": true }"#).unwrap();
+        assert_eq!(dark.tokens, Theme::Dark.tokens());
+        assert_eq!(dark.sizes, SIZES);
+        assert_eq!(dark.fonts, FONTS);
+    }
+
+    #[test]
+    fn builtin_keeps_the_fonts_of_the_theme() {
+        let fonts = Fonts::named("Brand Sans", "Brand Mono");
+        let theme = CustomTheme::new("Brand", &Theme::Light)
+            .fonts(fonts)
+            .build();
+
+        let file = ThemeFile::builtin(&theme);
+        assert_eq!(file.fonts, fonts);
+        assert_eq!(file.theme().fonts(), fonts);
+    }
+
+    #[test]
+    fn partial_tables_only_replace_their_keys() {
+        let file = ThemeFile::from_toml(
+            r##"
+            [colors.primary]
+            500 = "#8b5cf6"
+
+            [spacing]
+            small = 10
+            "##,
+        )
+        .unwrap();
+
+        let mut tokens = Theme::Light.tokens();
+        tokens.primary.c500 = color::from_hex("#8b5cf6").unwrap();
+        assert_eq!(file.tokens, tokens);
+
+        let mut sizes = SIZES;
+        sizes.spacing.small = 10.0;
+        assert_eq!(file.sizes, sizes);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        for toml in [
+            "nmae = \"Brand\"",
+            "[spacng]\nsmall = 8",
+            "[colors.primary]\n550 = \"#ffffff\"",
+            "[colors.primray]\n500 = \"#ffffff\"",
+        ] {
+            assert!(
+                matches!(ThemeFile::from_toml(toml), Err(Error::TomlRead(_))),
+                "{toml:?} was accepted"
+            );
+        }
+
+        assert!(matches!(
+            ThemeFile::from_json(r#"{ "font_size": { "huge": 40 } }"#),
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_values_are_rejected() {
+        for toml in [
+            "[colors]\nneutral_0 = \"not a color\"",
+            "[spacing]\nsmall = \"8px\"",
+            "dark = \"yes\"",
+            "[colors",
+        ] {
+            assert!(
+                matches!(ThemeFile::from_toml(toml), Err(Error::TomlRead(_))),
+                "{toml:?} was accepted"
+            );
+        }
+
+        assert!(matches!(
+            ThemeFile::from_json(r#"{ "colors": { "neutral_0": 0 } }"#),
+            Err(Error::Json(_))
+        ));
+        assert!(matches!(ThemeFile::from_json("{"), Err(Error::Json(_))));
+    }
+
+    #[test]
+    fn files_need_a_known_extension() {
+        assert!(matches!(
+            ThemeFile::load("brand.yaml"),
+            Err(Error::UnknownFormat(_))
+        ));
+        assert!(matches!(
+            ThemeFile::builtin(&Theme::Light).save("brand"),
+            Err(Error::UnknownFormat(_))
+        ));
+    }
+}
@@ -1,9 +1,11 @@
 use iced::{Background, border::Radius};
 use iced_widget::text_input;
 
-use super::sizes::BORDER_RADIUS;
 use crate::components::input::InputSize;
-use crate::theme::Theme;
+use crate::theme::{
+    Theme,
+    parts::{Part, Status},
+};
 
 /// Style class for custom input styling matching Shoelace design system
 ///
@@ -20,6 +22,10 @@ pub struct InputStyleClass {
     pub disabled: bool,
     pub filled: bool,
     pub pill: bool,
+    /// Overrides of the field's background and border
+    pub base: Part,
+    /// Overrides of the entered value
+    pub input: Part,
 }
 
 // Implement text_input Catalog trait
@@ -32,11 +38,14 @@ impl text_input::Catalog for Theme {
             disabled: false,
             filled: false,
             pill: false,
+            base: Part::default(),
+            input: Part::default(),
         }
     }
 
     fn style(&self, class: &Self::Class<'_>, status: text_input::Status) -> text_input::Style {
         let tokens = self.tokens();
+        let sizes = self.sizes();
         let is_focused = matches!(status, text_input::Status::Focused { .. });
         let is_hovered = matches!(status, text_input::Status::Hovered);
         let is_disabled = class.disabled;
@@ -48,9 +57,9 @@ impl text_input::Catalog for Theme {
         } else {
             // Standard border radius based on size (Shoelace uses medium=4px by default)
             match class.size {
-                InputSize::Small => Radius::from(BORDER_RADIUS.small),   // 3px
-                InputSize::Medium => Radius::from(BORDER_RADIUS.medium), // 4px
-                InputSize::Large => Radius::from(BORDER_RADIUS.large),   // 8px
+                InputSize::Small => Radius::from(sizes.border_radius.small),   // 3px
+                InputSize::Medium => Radius::from(sizes.border_radius.medium), // 4px
+                InputSize::Large => Radius::from(sizes.border_radius.large),   // 8px
             }
         };
 
@@ -110,7 +119,7 @@ impl text_input::Catalog for Theme {
         // Selection color - light primary for text selection
         let selection_color = tokens.primary.c100;
 
-        text_input::Style {
+        let style = text_input::Style {
             background: Background::Color(background),
             border: iced::Border {
                 color: border_color,
@@ -121,6 +130,15 @@ impl text_input::Catalog for Theme {
             placeholder: placeholder_color,
             value: text_color,
             selection: selection_color,
-        }
+        };
+
+        let status = if is_disabled {
+            Status::Disabled
+        } else {
+            Status::from(status)
+        };
+        let part = class.base.get(status).merge(class.input.get(status).text_only());
+
+        part.text_input(style, tokens)
     }
 }
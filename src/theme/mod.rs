@@ -1,26 +1,53 @@
+use std::sync::Arc;
+
 use iced::theme;
 
-use crate::theme::tokens::Tokens;
+use crate::theme::{
+    custom::CustomTheme,
+    sizes::{Density, SIZES, Sizes},
+    tokens::Tokens,
+    typography::{FONTS, Fonts},
+};
 
+pub mod alert;
 pub mod badge;
 pub mod button;
 pub mod checkbox;
+pub mod color;
 pub mod container;
+#[cfg(test)]
+mod contrast;
+pub mod css;
+pub mod custom;
+pub mod file;
 pub mod input;
 pub mod pallete;
+pub mod parts;
+pub mod range;
 pub mod rule;
 pub mod scrollable;
 pub mod sizes;
+pub mod split_panel;
+pub mod system;
+pub mod tab_group;
 pub mod text;
 pub mod tokens;
+pub mod typography;
 
+pub use custom::CustomTheme;
 pub use scrollable::ScrollableClass;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum Theme {
     #[default]
     Dark,
     Light,
+    /// The light theme with text and fill colors meeting WCAG AA contrast
+    HighContrastLight,
+    /// The dark theme with text and fill colors meeting WCAG AA contrast, on black
+    HighContrastDark,
+    /// A theme with its own tokens, see [`CustomTheme`]
+    Custom(Arc<CustomTheme>),
 }
 
 impl Theme {
@@ -28,8 +55,64 @@ impl Theme {
         match self {
             Theme::Dark => crate::theme::pallete::DARK,
             Theme::Light => crate::theme::pallete::LIGHT,
+            Theme::HighContrastLight => crate::theme::pallete::HIGH_CONTRAST_LIGHT,
+            Theme::HighContrastDark => crate::theme::pallete::HIGH_CONTRAST_DARK,
+            Theme::Custom(custom) => *custom.tokens(),
+        }
+    }
+
+    /// Whether the theme uses light text on dark surfaces
+    pub fn is_dark(&self) -> bool {
+        match self {
+            Theme::Dark | Theme::HighContrastDark => true,
+            Theme::Light | Theme::HighContrastLight => false,
+            Theme::Custom(custom) => custom.is_dark(),
         }
     }
+
+    /// The size and spacing tokens of the theme
+    pub fn sizes(&self) -> Sizes {
+        match self {
+            Theme::Dark | Theme::Light | Theme::HighContrastLight | Theme::HighContrastDark => {
+                SIZES
+            }
+            Theme::Custom(custom) => *custom.size_tokens(),
+        }
+    }
+
+    /// The font families of the theme
+    pub fn fonts(&self) -> Fonts {
+        match self {
+            Theme::Dark | Theme::Light | Theme::HighContrastLight | Theme::HighContrastDark => {
+                FONTS
+            }
+            Theme::Custom(custom) => *custom.font_families(),
+        }
+    }
+
+    /// Returns the theme with its size tokens replaced by a built-in preset
+    pub fn with_density(self, density: Density) -> Theme {
+        self.with_sizes(density.sizes())
+    }
+
+    /// Returns the theme with its size tokens replaced
+    ///
+    /// Built-in themes become a custom theme with the same colors.
+    pub fn with_sizes(self, sizes: Sizes) -> Theme {
+        if self.sizes() == sizes {
+            return self;
+        }
+
+        let name = match &self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::HighContrastLight => "High Contrast Light",
+            Theme::HighContrastDark => "High Contrast Dark",
+            Theme::Custom(custom) => custom.name(),
+        };
+
+        CustomTheme::new(name, &self).sizes(sizes).build()
+    }
 }
 
 impl theme::Base for Theme {
@@ -42,23 +125,24 @@ impl theme::Base for Theme {
     }
 
     fn mode(&self) -> theme::Mode {
-        match self {
-            Theme::Light => theme::Mode::Light,
-            Theme::Dark => theme::Mode::Dark,
+        if self.is_dark() {
+            theme::Mode::Dark
+        } else {
+            theme::Mode::Light
         }
     }
 
     fn base(&self) -> theme::Style {
         theme::Style {
             background_color: self.tokens().neutral_0,
-            text_color: self.tokens().gray.c900,
+            text_color: self.tokens().neutral.c900,
         }
     }
 
     fn palette(&self) -> Option<theme::Palette> {
         Some(theme::Palette {
             background: self.tokens().neutral_0,
-            text: self.tokens().gray.c900,
+            text: self.tokens().neutral.c900,
             primary: self.tokens().primary.c500,
             success: self.tokens().success.c500,
             warning: self.tokens().warning.c500,
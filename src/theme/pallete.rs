@@ -1,11 +1,15 @@
 use iced::Color;
 
-use crate::theme::tokens::{ColorScale, Tokens};
+use crate::theme::{
+    color,
+    tokens::{ColorScale, Tokens},
+};
+
 /// Converts HSL color values to RGB Color
 /// h: hue in degrees (0-360)
 /// s: saturation as percentage (0-100)
 /// l: lightness as percentage (0-100)
-const fn hsl(h: f32, s: f32, l: f32) -> Color {
+pub const fn hsl(h: f32, s: f32, l: f32) -> Color {
     let h = h / 360.0;
     let s = s / 100.0;
     let l = l / 100.0;
@@ -607,6 +611,20 @@ pub enum ColorValue {
 }
 
 impl ColorValue {
+    pub const ALL: [ColorValue; 11] = [
+        ColorValue::C50,
+        ColorValue::C100,
+        ColorValue::C200,
+        ColorValue::C300,
+        ColorValue::C400,
+        ColorValue::C500,
+        ColorValue::C600,
+        ColorValue::C700,
+        ColorValue::C800,
+        ColorValue::C900,
+        ColorValue::C950,
+    ];
+
     pub fn get_color(self, color: &ColorScale) -> Color {
         match self {
             ColorValue::C50 => color.c50,
@@ -622,6 +640,22 @@ impl ColorValue {
             ColorValue::C950 => color.c950,
         }
     }
+
+    pub fn get_color_mut(self, color: &mut ColorScale) -> &mut Color {
+        match self {
+            ColorValue::C50 => &mut color.c50,
+            ColorValue::C100 => &mut color.c100,
+            ColorValue::C200 => &mut color.c200,
+            ColorValue::C300 => &mut color.c300,
+            ColorValue::C400 => &mut color.c400,
+            ColorValue::C500 => &mut color.c500,
+            ColorValue::C600 => &mut color.c600,
+            ColorValue::C700 => &mut color.c700,
+            ColorValue::C800 => &mut color.c800,
+            ColorValue::C900 => &mut color.c900,
+            ColorValue::C950 => &mut color.c950,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -644,12 +678,43 @@ pub enum ColorVariant {
     Fuchsia,
     Pink,
     Rose,
+    Primary,
+    Success,
+    Warning,
+    Danger,
     Neutral,
     NeutralBase,
     NeutralDark,
 }
 
 impl ColorVariant {
+    /// Every variant with a full scale, leaving out `NeutralBase` and `NeutralDark`
+    pub const SCALES: [ColorVariant; 23] = [
+        ColorVariant::Gray,
+        ColorVariant::Red,
+        ColorVariant::Orange,
+        ColorVariant::Amber,
+        ColorVariant::Yellow,
+        ColorVariant::Lime,
+        ColorVariant::Green,
+        ColorVariant::Emerald,
+        ColorVariant::Teal,
+        ColorVariant::Cyan,
+        ColorVariant::Sky,
+        ColorVariant::Blue,
+        ColorVariant::Indigo,
+        ColorVariant::Violet,
+        ColorVariant::Purple,
+        ColorVariant::Fuchsia,
+        ColorVariant::Pink,
+        ColorVariant::Rose,
+        ColorVariant::Primary,
+        ColorVariant::Success,
+        ColorVariant::Warning,
+        ColorVariant::Danger,
+        ColorVariant::Neutral,
+    ];
+
     pub fn get_color(self, color: Tokens, value: ColorValue) -> Color {
         let color_scale = match self {
             ColorVariant::Gray => color.gray,
@@ -670,6 +735,10 @@ impl ColorVariant {
             ColorVariant::Fuchsia => color.fuchsia,
             ColorVariant::Pink => color.pink,
             ColorVariant::Rose => color.rose,
+            ColorVariant::Primary => color.primary,
+            ColorVariant::Success => color.success,
+            ColorVariant::Warning => color.warning,
+            ColorVariant::Danger => color.danger,
             ColorVariant::Neutral => color.neutral,
             ColorVariant::NeutralBase => return color.neutral_0,
             ColorVariant::NeutralDark => return color.neutral_1000,
@@ -677,6 +746,39 @@ impl ColorVariant {
 
         value.get_color(&color_scale)
     }
+
+    /// Returns the color this variant and value refer to in `tokens`, for overriding it
+    pub fn get_color_mut(self, tokens: &mut Tokens, value: ColorValue) -> &mut Color {
+        let color_scale = match self {
+            ColorVariant::Gray => &mut tokens.gray,
+            ColorVariant::Red => &mut tokens.red,
+            ColorVariant::Orange => &mut tokens.orange,
+            ColorVariant::Amber => &mut tokens.amber,
+            ColorVariant::Yellow => &mut tokens.yellow,
+            ColorVariant::Lime => &mut tokens.lime,
+            ColorVariant::Green => &mut tokens.green,
+            ColorVariant::Emerald => &mut tokens.emerald,
+            ColorVariant::Teal => &mut tokens.teal,
+            ColorVariant::Cyan => &mut tokens.cyan,
+            ColorVariant::Sky => &mut tokens.sky,
+            ColorVariant::Blue => &mut tokens.blue,
+            ColorVariant::Indigo => &mut tokens.indigo,
+            ColorVariant::Violet => &mut tokens.violet,
+            ColorVariant::Purple => &mut tokens.purple,
+            ColorVariant::Fuchsia => &mut tokens.fuchsia,
+            ColorVariant::Pink => &mut tokens.pink,
+            ColorVariant::Rose => &mut tokens.rose,
+            ColorVariant::Primary => &mut tokens.primary,
+            ColorVariant::Success => &mut tokens.success,
+            ColorVariant::Warning => &mut tokens.warning,
+            ColorVariant::Danger => &mut tokens.danger,
+            ColorVariant::Neutral => &mut tokens.neutral,
+            ColorVariant::NeutralBase => return &mut tokens.neutral_0,
+            ColorVariant::NeutralDark => return &mut tokens.neutral_1000,
+        };
+
+        value.get_color_mut(color_scale)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -760,3 +862,200 @@ pub(crate) const LIGHT: Tokens = Tokens {
     neutral_0: light::NEUTRAL_0,
     neutral_1000: light::NEUTRAL_1000,
 };
+
+pub(crate) const HIGH_CONTRAST_LIGHT: Tokens = Tokens {
+    // Semantic colors
+    primary: high_contrast(light::SKY),
+    success: high_contrast(light::GREEN),
+    warning: high_contrast(light::AMBER),
+    danger: high_contrast(light::RED),
+    neutral: high_contrast(light::GRAY),
+
+    ..LIGHT
+};
+
+pub(crate) const HIGH_CONTRAST_DARK: Tokens = Tokens {
+    // Semantic colors
+    primary: high_contrast(dark::SKY),
+    success: high_contrast(dark::GREEN),
+    warning: high_contrast(dark::AMBER),
+    danger: high_contrast(dark::RED),
+    neutral: high_contrast(dark::GRAY),
+
+    // Special neutral colors
+    neutral_0: hsl(0.0, 0.0, 0.0),
+
+    ..DARK
+};
+
+/// Shifts the upper steps of a scale toward its strongest colors
+///
+/// Components draw text and filled backgrounds with the 500-900 steps and surfaces
+/// with the 50-200 steps. Moving the former two to four steps away from the surfaces
+/// keeps every pair the built-in styles use above WCAG AA, in light and dark scales.
+const fn high_contrast(scale: ColorScale) -> ColorScale {
+    ColorScale {
+        c50: scale.c50,
+        c100: scale.c100,
+        c200: scale.c200,
+        c300: scale.c400,
+        c400: scale.c500,
+        c500: scale.c700,
+        c600: scale.c800,
+        c700: scale.c900,
+        c800: scale.c950,
+        c900: scale.c950,
+        c950: scale.c950,
+    }
+}
+
+/// OKLCH lightness of each step, averaged over the shipped light scales
+const LIGHT_LIGHTNESS: [f32; 11] = [
+    0.977, 0.950, 0.906, 0.840, 0.761, 0.683, 0.598, 0.515, 0.446, 0.395, 0.284,
+];
+
+/// OKLCH chroma of each step relative to the 500 step, averaged over the shipped light scales
+const LIGHT_CHROMA: [f32; 11] = [
+    0.098, 0.233, 0.437, 0.683, 0.896, 1.0, 0.983, 0.876, 0.735, 0.611, 0.388,
+];
+
+/// OKLCH lightness of each step, averaged over the shipped dark scales
+const DARK_LIGHTNESS: [f32; 11] = [
+    0.326, 0.425, 0.461, 0.531, 0.613, 0.655, 0.735, 0.819, 0.885, 0.929, 0.971,
+];
+
+/// OKLCH chroma of each step relative to the 500 step, averaged over the shipped dark scales
+const DARK_CHROMA: [f32; 11] = [
+    0.419, 0.619, 0.712, 0.851, 0.959, 1.0, 1.005, 0.809, 0.575, 0.380, 0.159,
+];
+
+/// Generates a 50-950 color scale around a single seed color
+///
+/// The seed becomes the 500 step. The other steps keep its OKLCH hue and follow the
+/// lightness and chroma curves of the shipped scales, so generated scales are as
+/// perceptually even as the hand-written ones. Lightness is stretched between the
+/// seed and white for the lighter steps and between the seed and black for the
+/// darker ones, and chroma is reduced where a step would leave sRGB.
+///
+/// Pass `dark` to get the reversed scale used by dark themes, where 50 is the darkest step.
+pub fn generate_scale(seed: Color, dark: bool) -> ColorScale {
+    let (lightness, chroma) = if dark {
+        (DARK_LIGHTNESS, DARK_CHROMA)
+    } else {
+        (LIGHT_LIGHTNESS, LIGHT_CHROMA)
+    };
+
+    let (seed_lightness, seed_chroma, hue) = color::to_oklch(seed);
+    let anchor = lightness[5];
+
+    let [
+        c50,
+        c100,
+        c200,
+        c300,
+        c400,
+        c500,
+        c600,
+        c700,
+        c800,
+        c900,
+        c950,
+    ] = std::array::from_fn(|step| {
+        let l = if lightness[step] >= anchor {
+            1.0 - (1.0 - lightness[step]) * (1.0 - seed_lightness) / (1.0 - anchor)
+        } else {
+            lightness[step] * seed_lightness / anchor
+        };
+
+        // Largest chroma up to the target that stays in sRGB
+        let target = seed_chroma * chroma[step];
+        let (mut low, mut high) = (0.0, target);
+
+        if !color::in_gamut(l, high, hue) {
+            for _ in 0..16 {
+                let middle = (low + high) / 2.0;
+
+                if color::in_gamut(l, middle, hue) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+
+            high = low;
+        }
+
+        color::oklch(l, high, hue)
+    });
+
+    ColorScale {
+        c50,
+        c100,
+        c200,
+        c300,
+        c400,
+        c500,
+        c600,
+        c700,
+        c800,
+        c900,
+        c950,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(scale: &ColorScale) -> [Color; 11] {
+        [
+            scale.c50, scale.c100, scale.c200, scale.c300, scale.c400, scale.c500, scale.c600,
+            scale.c700, scale.c800, scale.c900, scale.c950,
+        ]
+    }
+
+    fn assert_close(generated: &ColorScale, shipped: &ColorScale, tolerance: f32) {
+        for (step, (generated, shipped)) in
+            steps(generated).into_iter().zip(steps(shipped)).enumerate()
+        {
+            let difference = color::difference(generated, shipped);
+
+            assert!(
+                difference < tolerance,
+                "step {step} is {difference} away from the shipped color"
+            );
+        }
+    }
+
+    #[test]
+    fn sky_500_reproduces_light_sky() {
+        assert_close(&generate_scale(light::SKY.c500, false), &light::SKY, 0.05);
+    }
+
+    #[test]
+    fn sky_500_reproduces_dark_sky() {
+        assert_close(&generate_scale(light::SKY.c500, true), &dark::SKY, 0.05);
+    }
+
+    #[test]
+    fn seed_is_the_500_step() {
+        let seed = hsl(262.0, 83.0, 58.0);
+
+        assert!(color::difference(generate_scale(seed, false).c500, seed) < 0.005);
+    }
+
+    #[test]
+    fn gray_seed_gives_gray_scale() {
+        for step in steps(&generate_scale(hsl(0.0, 0.0, 50.0), false)) {
+            assert!(color::to_oklch(step).1 < 0.001);
+        }
+    }
+
+    #[test]
+    fn oklch_round_trips() {
+        let sky = light::SKY.c500;
+        let (l, c, h) = color::to_oklch(sky);
+
+        assert!(color::difference(color::oklch(l, c, h), sky) < 0.001);
+    }
+}
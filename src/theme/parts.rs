@@ -0,0 +1,457 @@
+//! Style overrides for the parts of a component
+//!
+//! Mirrors Shoelace's CSS parts: every component names the parts it draws (its
+//! base, label, prefix, suffix, ...) and accepts a [`PartStyle`] for each of them.
+//! Only the fields set in the override replace the theme's style, so a brand
+//! button can change its background and keep everything else.
+//!
+//! Overrides can depend on the [`Status`] of the widget through [`Part::with`].
+//! Parts drawn by interactive widgets (a button's base, an input's field, a
+//! checkbox's control) see every status. Passive parts such as labels and help
+//! text only ever see [`Status::Active`] or [`Status::Disabled`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use iced::Color;
+//! use iced_shoelace::components::button::{Button, ButtonPart};
+//! use iced_shoelace::theme::parts::{Part, PartStyle, Status};
+//!
+//! const BRAND: Color = Color::from_rgb(0.45, 0.2, 0.85);
+//! const BRAND_DARK: Color = Color::from_rgb(0.35, 0.12, 0.7);
+//!
+//! let button = Button::<()>::new("Upgrade")
+//!     .part(
+//!         ButtonPart::Base,
+//!         Part::with(|status| {
+//!             let background = match status {
+//!                 Status::Hovered | Status::Pressed => BRAND_DARK,
+//!                 _ => BRAND,
+//!             };
+//!
+//!             PartStyle::new().background(background).border_color(background)
+//!         }),
+//!     )
+//!     .part(ButtonPart::Label, PartStyle::new().text_color(Color::WHITE));
+//! ```
+use iced::{
+    Background, Border, Color, Shadow,
+    border::Radius,
+    widget::{button, checkbox, container, text_input},
+};
+
+use crate::theme::{pallete::ColorToken, tokens::Tokens};
+
+/// The interaction status a part is drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    #[default]
+    Active,
+    Hovered,
+    Pressed,
+    Focused,
+    Disabled,
+}
+
+impl Status {
+    pub const ALL: [Status; 5] = [
+        Status::Active,
+        Status::Hovered,
+        Status::Pressed,
+        Status::Focused,
+        Status::Disabled,
+    ];
+
+    /// The status of a part drawn by a passive widget
+    pub fn of(disabled: bool) -> Self {
+        if disabled {
+            Status::Disabled
+        } else {
+            Status::Active
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<button::Status> for Status {
+    fn from(status: button::Status) -> Self {
+        match status {
+            button::Status::Active => Status::Active,
+            button::Status::Hovered => Status::Hovered,
+            button::Status::Pressed => Status::Pressed,
+            button::Status::Disabled => Status::Disabled,
+        }
+    }
+}
+
+impl From<text_input::Status> for Status {
+    fn from(status: text_input::Status) -> Self {
+        match status {
+            text_input::Status::Active => Status::Active,
+            text_input::Status::Hovered => Status::Hovered,
+            text_input::Status::Focused { .. } => Status::Focused,
+            text_input::Status::Disabled => Status::Disabled,
+        }
+    }
+}
+
+impl From<checkbox::Status> for Status {
+    fn from(status: checkbox::Status) -> Self {
+        match status {
+            checkbox::Status::Active { .. } => Status::Active,
+            checkbox::Status::Hovered { .. } => Status::Hovered,
+            checkbox::Status::Disabled { .. } => Status::Disabled,
+        }
+    }
+}
+
+/// A color of a part style, either a theme token or a fixed color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartColor {
+    /// Follows the active theme
+    Token(ColorToken),
+    /// The same in every theme
+    Fixed(Color),
+}
+
+impl PartColor {
+    pub fn get_color(self, tokens: Tokens) -> Color {
+        match self {
+            PartColor::Token(token) => token.get_color(tokens),
+            PartColor::Fixed(color) => color,
+        }
+    }
+}
+
+impl From<ColorToken> for PartColor {
+    fn from(token: ColorToken) -> Self {
+        PartColor::Token(token)
+    }
+}
+
+impl From<Color> for PartColor {
+    fn from(color: Color) -> Self {
+        PartColor::Fixed(color)
+    }
+}
+
+/// A partial style, replacing only the fields that are set
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PartStyle {
+    pub background: Option<PartColor>,
+    pub text_color: Option<PartColor>,
+    pub border_color: Option<PartColor>,
+    pub border_width: Option<f32>,
+    pub border_radius: Option<Radius>,
+    pub shadow: Option<Shadow>,
+}
+
+impl PartStyle {
+    /// Creates a style that overrides nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the background color
+    pub fn background(mut self, color: impl Into<PartColor>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Sets the text (and icon) color
+    pub fn text_color(mut self, color: impl Into<PartColor>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the border color
+    pub fn border_color(mut self, color: impl Into<PartColor>) -> Self {
+        self.border_color = Some(color.into());
+        self
+    }
+
+    /// Sets the border width
+    pub fn border_width(mut self, width: f32) -> Self {
+        self.border_width = Some(width);
+        self
+    }
+
+    /// Sets the border radius
+    pub fn border_radius(mut self, radius: impl Into<Radius>) -> Self {
+        self.border_radius = Some(radius.into());
+        self
+    }
+
+    /// Sets the shadow
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Whether the style overrides nothing
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Combines two styles, the fields set in `other` taking precedence
+    pub fn merge(self, other: PartStyle) -> Self {
+        Self {
+            background: other.background.or(self.background),
+            text_color: other.text_color.or(self.text_color),
+            border_color: other.border_color.or(self.border_color),
+            border_width: other.border_width.or(self.border_width),
+            border_radius: other.border_radius.or(self.border_radius),
+            shadow: other.shadow.or(self.shadow),
+        }
+    }
+
+    /// Keeps only the text color, for parts that can only change their text
+    pub fn text_only(self) -> Self {
+        Self {
+            text_color: self.text_color,
+            ..Self::default()
+        }
+    }
+
+    fn border(&self, border: Border, tokens: Tokens) -> Border {
+        Border {
+            color: self
+                .border_color
+                .map_or(border.color, |color| color.get_color(tokens)),
+            width: self.border_width.unwrap_or(border.width),
+            radius: self.border_radius.unwrap_or(border.radius),
+        }
+    }
+
+    /// Applies the style over a container style
+    pub fn container(&self, style: container::Style, tokens: Tokens) -> container::Style {
+        container::Style {
+            background: self
+                .background
+                .map(|color| Background::Color(color.get_color(tokens)))
+                .or(style.background),
+            text_color: self
+                .text_color
+                .map(|color| color.get_color(tokens))
+                .or(style.text_color),
+            border: self.border(style.border, tokens),
+            shadow: self.shadow.unwrap_or(style.shadow),
+            ..style
+        }
+    }
+
+    /// Applies the style over a button style
+    pub fn button(&self, style: button::Style, tokens: Tokens) -> button::Style {
+        button::Style {
+            background: self
+                .background
+                .map(|color| Background::Color(color.get_color(tokens)))
+                .or(style.background),
+            text_color: self
+                .text_color
+                .map_or(style.text_color, |color| color.get_color(tokens)),
+            border: self.border(style.border, tokens),
+            shadow: self.shadow.unwrap_or(style.shadow),
+            ..style
+        }
+    }
+
+    /// Applies the style over a text input style
+    ///
+    /// The text color replaces the color of the value and icon, not the placeholder.
+    pub fn text_input(&self, style: text_input::Style, tokens: Tokens) -> text_input::Style {
+        let text_color = self.text_color.map(|color| color.get_color(tokens));
+
+        text_input::Style {
+            background: self.background.map_or(style.background, |color| {
+                Background::Color(color.get_color(tokens))
+            }),
+            border: self.border(style.border, tokens),
+            icon: text_color.unwrap_or(style.icon),
+            value: text_color.unwrap_or(style.value),
+            ..style
+        }
+    }
+
+    /// Applies the style over a checkbox style
+    ///
+    /// The text color replaces the color of the check mark; the label is styled by
+    /// [`PartStyle::checkbox_label`].
+    pub fn checkbox(&self, style: checkbox::Style, tokens: Tokens) -> checkbox::Style {
+        checkbox::Style {
+            background: self.background.map_or(style.background, |color| {
+                Background::Color(color.get_color(tokens))
+            }),
+            icon_color: self
+                .text_color
+                .map_or(style.icon_color, |color| color.get_color(tokens)),
+            border: self.border(style.border, tokens),
+            ..style
+        }
+    }
+
+    /// Applies the text color of the style to the label of a checkbox
+    pub fn checkbox_label(&self, style: checkbox::Style, tokens: Tokens) -> checkbox::Style {
+        checkbox::Style {
+            text_color: self
+                .text_color
+                .map(|color| color.get_color(tokens))
+                .or(style.text_color),
+            ..style
+        }
+    }
+}
+
+/// The overrides of one part, for every status
+///
+/// Status dependent overrides are evaluated once when the part is set, so the
+/// result can be carried by the `Copy` style classes of the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Part {
+    styles: [PartStyle; Status::ALL.len()],
+}
+
+impl Part {
+    /// Overrides the part with the same style in every status
+    pub fn new(style: PartStyle) -> Self {
+        Self {
+            styles: [style; Status::ALL.len()],
+        }
+    }
+
+    /// Overrides the part with a style computed from the status
+    pub fn with(style: impl Fn(Status) -> PartStyle) -> Self {
+        Self {
+            styles: Status::ALL.map(style),
+        }
+    }
+
+    /// The override in the given status
+    pub fn get(&self, status: Status) -> PartStyle {
+        self.styles[status.index()]
+    }
+
+    /// Combines two overrides, the fields set in `other` taking precedence
+    pub fn merge(self, other: Part) -> Self {
+        Self {
+            styles: Status::ALL.map(|status| self.get(status).merge(other.get(status))),
+        }
+    }
+}
+
+impl From<PartStyle> for Part {
+    fn from(style: PartStyle) -> Self {
+        Part::new(style)
+    }
+}
+
+/// The overrides of the parts of a component, keyed by its part enum
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parts<P> {
+    parts: Vec<(P, Part)>,
+}
+
+impl<P> Default for Parts<P> {
+    fn default() -> Self {
+        Self { parts: Vec::new() }
+    }
+}
+
+impl<P: Copy + PartialEq> Parts<P> {
+    /// Creates an empty set of overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an override, merging it over any previous override of the part
+    pub fn set(&mut self, part: P, style: Part) {
+        match self.parts.iter_mut().find(|(name, _)| *name == part) {
+            Some((_, current)) => *current = current.merge(style),
+            None => self.parts.push((part, style)),
+        }
+    }
+
+    /// The override of a part, empty if none was set
+    pub fn get(&self, part: P) -> Part {
+        self.parts
+            .iter()
+            .find(|(name, _)| *name == part)
+            .map(|(_, style)| *style)
+            .unwrap_or_default()
+    }
+
+    /// Whether the part was overridden
+    pub fn contains(&self, part: P) -> bool {
+        self.parts.iter().any(|(name, _)| *name == part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+
+    #[test]
+    fn merge_keeps_unset_fields() {
+        let base = PartStyle::new().background(Color::BLACK).border_width(2.0);
+        let over = PartStyle::new().background(Color::WHITE);
+
+        let merged = base.merge(over);
+
+        assert_eq!(merged.background, Some(PartColor::Fixed(Color::WHITE)));
+        assert_eq!(merged.border_width, Some(2.0));
+        assert_eq!(merged.text_color, None);
+    }
+
+    #[test]
+    fn part_follows_status() {
+        let part = Part::with(|status| match status {
+            Status::Hovered => PartStyle::new().text_color(Color::WHITE),
+            _ => PartStyle::new(),
+        });
+
+        assert!(part.get(Status::Active).is_empty());
+        assert_eq!(
+            part.get(Status::Hovered).text_color,
+            Some(PartColor::Fixed(Color::WHITE))
+        );
+    }
+
+    #[test]
+    fn parts_merge_repeated_overrides() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Name {
+            Base,
+            Label,
+        }
+
+        let mut parts = Parts::new();
+        parts.set(Name::Base, PartStyle::new().border_width(0.0).into());
+        parts.set(
+            Name::Base,
+            PartStyle::new().shadow(Shadow::default()).into(),
+        );
+
+        let base = parts.get(Name::Base).get(Status::Active);
+        assert_eq!(base.border_width, Some(0.0));
+        assert_eq!(base.shadow, Some(Shadow::default()));
+
+        assert!(!parts.contains(Name::Label));
+        assert!(parts.get(Name::Label).get(Status::Active).is_empty());
+    }
+
+    #[test]
+    fn empty_style_changes_nothing() {
+        let theme = Theme::Light;
+        let style = <Theme as button::Catalog>::style(
+            &theme,
+            &<Theme as button::Catalog>::default(),
+            button::Status::Hovered,
+        );
+
+        assert_eq!(PartStyle::new().button(style, theme.tokens()), style);
+    }
+}
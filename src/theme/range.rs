@@ -0,0 +1,44 @@
+use crate::{
+    theme::Theme,
+    widgets::range::{Catalog, Status, Style},
+};
+
+/// Style class for range sliders
+///
+/// Shoelace range styling reference:
+/// - Track: neutral-200, active track: primary-600
+/// - Thumb: primary-600 fill with a neutral-0 border
+/// - Hover/drag: thumb lightens to primary-500
+/// - Disabled: neutral-300 thumb and active track
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RangeStyleClass {
+    #[default]
+    Default,
+}
+
+impl Catalog for Theme {
+    type Class<'a> = RangeStyleClass;
+
+    fn default<'a>() -> Self::Class<'a> {
+        RangeStyleClass::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let tokens = self.tokens();
+
+        let (rail_active, thumb) = match status {
+            Status::Active => (tokens.primary.c600, tokens.primary.c600),
+            Status::Hovered | Status::Dragged => (tokens.primary.c600, tokens.primary.c500),
+            Status::Disabled => (tokens.neutral.c300, tokens.neutral.c300),
+        };
+
+        Style {
+            rail: tokens.neutral.c200,
+            rail_active,
+            thumb,
+            thumb_border: tokens.neutral_0,
+            thumb_border_width: 2.0,
+            tick: tokens.neutral.c300,
+        }
+    }
+}
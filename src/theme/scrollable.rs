@@ -1,6 +1,6 @@
 use iced_widget::{container, scrollable};
 
-use crate::theme::{Theme, sizes::BORDER_RADIUS};
+use crate::theme::Theme;
 
 /// Scrollbar style class
 #[derive(Debug, Clone, Copy, Default)]
@@ -20,6 +20,7 @@ impl scrollable::Catalog for Theme {
 
     fn style(&self, class: &Self::Class<'_>, status: scrollable::Status) -> scrollable::Style {
         let tokens = self.tokens();
+        let sizes = self.sizes();
 
         let is_active = matches!(
             status,
@@ -49,7 +50,7 @@ impl scrollable::Catalog for Theme {
                 let scroller_color = if is_active {
                     tokens.neutral.c600
                 } else {
-                    tokens.neutral.c400
+                    tokens.neutral.c500
                 };
 
                 (
@@ -58,7 +59,7 @@ impl scrollable::Catalog for Theme {
                     iced::Border {
                         color: iced::Color::TRANSPARENT,
                         width,
-                        radius: BORDER_RADIUS.x_large.into(),
+                        radius: sizes.border_radius.x_large.into(),
                     },
                 )
             }
@@ -78,7 +79,7 @@ impl scrollable::Catalog for Theme {
                     iced::Border {
                         color: iced::Color::TRANSPARENT,
                         width,
-                        radius: BORDER_RADIUS.x_large.into(),
+                        radius: sizes.border_radius.x_large.into(),
                     },
                 )
             }
@@ -93,7 +94,7 @@ impl scrollable::Catalog for Theme {
                 border: iced::Border {
                     color: iced::Color::TRANSPARENT,
                     width,
-                    radius: BORDER_RADIUS.x_large.into(),
+                    radius: sizes.border_radius.x_large.into(),
                 },
                 scroller: scrollable::Scroller {
                     color: scroller_color,
@@ -105,7 +106,7 @@ impl scrollable::Catalog for Theme {
                 border: iced::Border {
                     color: iced::Color::TRANSPARENT,
                     width,
-                    radius: BORDER_RADIUS.x_large.into(),
+                    radius: sizes.border_radius.x_large.into(),
                 },
                 scroller: scrollable::Scroller {
                     color: scroller_color,
@@ -1,5 +1,5 @@
 /// Border radius tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BorderRadius {
     pub small: f32,
     pub medium: f32,
@@ -8,7 +8,7 @@ pub struct BorderRadius {
 }
 
 /// Spacing tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Spacing {
     pub x3_small: f32,
     pub x2_small: f32,
@@ -23,7 +23,7 @@ pub struct Spacing {
 }
 
 /// Font size tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct FontSize {
     pub x2_small: f32,
     pub x_small: f32,
@@ -37,7 +37,7 @@ pub struct FontSize {
 }
 
 /// Font weight tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct FontWeight {
     pub light: u16,
     pub normal: u16,
@@ -46,7 +46,7 @@ pub struct FontWeight {
 }
 
 /// Line height tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LineHeight {
     pub denser: f32,
     pub dense: f32,
@@ -56,7 +56,7 @@ pub struct LineHeight {
 }
 
 /// Input height tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct InputHeight {
     pub small: f32,
     pub medium: f32,
@@ -64,7 +64,7 @@ pub struct InputHeight {
 }
 
 /// Toggle size tokens
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ToggleSize {
     pub small: f32,
     pub medium: f32,
@@ -138,3 +138,150 @@ pub const TOGGLE_SIZE: ToggleSize = ToggleSize {
     medium: 14.0, // 1.1667rem
     large: 16.0,  // 1.3333rem
 };
+
+/// The complete set of size tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sizes {
+    pub border_radius: BorderRadius,
+    pub spacing: Spacing,
+    pub font_size: FontSize,
+    pub font_weight: FontWeight,
+    pub line_height: LineHeight,
+    pub input_height: InputHeight,
+    pub toggle_size: ToggleSize,
+}
+
+// The built-in size tokens, matching Shoelace
+pub const SIZES: Sizes = Sizes {
+    border_radius: BORDER_RADIUS,
+    spacing: SPACING,
+    font_size: FONT_SIZE,
+    font_weight: FONT_WEIGHT,
+    line_height: LINE_HEIGHT,
+    input_height: INPUT_HEIGHT,
+    toggle_size: TOGGLE_SIZE,
+};
+
+// Compact sizes for data-dense screens: tighter spacing, smaller inputs and text
+pub const COMPACT: Sizes = Sizes {
+    border_radius: BORDER_RADIUS,
+    spacing: Spacing {
+        x3_small: 1.0,
+        x2_small: 2.0,
+        x_small: 3.0,
+        small: 6.0,
+        medium: 12.0,
+        large: 16.0,
+        x_large: 20.0,
+        x2_large: 28.0,
+        x3_large: 36.0,
+        x4_large: 54.0,
+    },
+    font_size: FontSize {
+        x2_small: 7.0,
+        x_small: 8.0,
+        small: 10.0,
+        medium: 11.0,
+        large: 13.0,
+        x_large: 16.0,
+        x2_large: 24.0,
+        x3_large: 32.0,
+        x4_large: 48.0,
+    },
+    font_weight: FONT_WEIGHT,
+    line_height: LineHeight {
+        denser: 1.0,
+        dense: 1.3,
+        normal: 1.6,
+        loose: 2.0,
+        looser: 2.4,
+    },
+    input_height: InputHeight {
+        small: 20.0,
+        medium: 26.0,
+        large: 32.0,
+    },
+    toggle_size: ToggleSize {
+        small: 9.0,
+        medium: 12.0,
+        large: 14.0,
+    },
+};
+
+// Spacious sizes for touch and customer-facing screens: roomier spacing and larger inputs
+pub const SPACIOUS: Sizes = Sizes {
+    border_radius: BorderRadius {
+        small: 4.0,
+        medium: 6.0,
+        large: 10.0,
+        x_large: 20.0,
+    },
+    spacing: Spacing {
+        x3_small: 2.0,
+        x2_small: 3.0,
+        x_small: 6.0,
+        small: 10.0,
+        medium: 20.0,
+        large: 24.0,
+        x_large: 36.0,
+        x2_large: 44.0,
+        x3_large: 60.0,
+        x4_large: 90.0,
+    },
+    font_size: FontSize {
+        x2_small: 8.0,
+        x_small: 10.0,
+        small: 12.0,
+        medium: 14.0,
+        large: 17.0,
+        x_large: 20.0,
+        x2_large: 30.0,
+        x3_large: 40.0,
+        x4_large: 60.0,
+    },
+    font_weight: FONT_WEIGHT,
+    line_height: LINE_HEIGHT,
+    input_height: InputHeight {
+        small: 28.0,
+        medium: 40.0,
+        large: 48.0,
+    },
+    toggle_size: ToggleSize {
+        small: 12.0,
+        medium: 16.0,
+        large: 20.0,
+    },
+};
+
+/// The built-in size presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// Tighter spacing and smaller controls, see [`COMPACT`]
+    Compact,
+    /// The Shoelace defaults, see [`SIZES`]
+    #[default]
+    Comfortable,
+    /// Roomier spacing and larger controls, see [`SPACIOUS`]
+    Spacious,
+}
+
+impl Density {
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Comfortable, Density::Spacious];
+
+    /// The size tokens of the preset
+    pub const fn sizes(self) -> Sizes {
+        match self {
+            Density::Compact => COMPACT,
+            Density::Comfortable => SIZES,
+            Density::Spacious => SPACIOUS,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Comfortable => "Comfortable",
+            Density::Spacious => "Spacious",
+        }
+    }
+}
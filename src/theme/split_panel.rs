@@ -0,0 +1,38 @@
+use crate::{
+    theme::Theme,
+    widgets::split::{Catalog, Status, Style},
+};
+
+/// Style class for the split panel divider
+///
+/// Shoelace split panel styling reference:
+/// - Divider: neutral-200
+/// - Focus ring: primary-600
+#[derive(Debug, Clone, Copy, Default)]
+pub enum SplitPanelStyleClass {
+    #[default]
+    Default,
+}
+
+impl Catalog for Theme {
+    type Class<'a> = SplitPanelStyleClass;
+
+    fn default<'a>() -> Self::Class<'a> {
+        SplitPanelStyleClass::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let tokens = self.tokens();
+
+        let divider = match status {
+            Status::Active => tokens.neutral.c200,
+            Status::Hovered => tokens.neutral.c300,
+            Status::Dragged => tokens.neutral.c400,
+        };
+
+        Style {
+            divider,
+            focus: tokens.primary.c600,
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! Following the light or dark preference of the operating system
+//!
+//! [`SystemTheme`] picks between a light and a dark theme from the color scheme the
+//! windowing layer reports, and cross-fades the tokens whenever the shown theme
+//! changes. It is driven by [`Event`]s: the application forwards the events of
+//! [`SystemTheme::subscription`] to [`SystemTheme::update`] and returns
+//! [`SystemTheme::theme`] from its `theme` function. Tests can inject the same
+//! events to simulate the system switching modes.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use iced_shoelace::theme::{Theme, system::{self, SystemTheme}};
+//!
+//! #[derive(Default)]
+//! struct App {
+//!     theme: SystemTheme,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Theme(system::Event),
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Theme(event) => self.theme.update(event),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> iced::Subscription<Message> {
+//!         self.theme.subscription().map(Message::Theme)
+//!     }
+//!
+//!     fn theme(&self) -> Theme {
+//!         self.theme.theme()
+//!     }
+//! }
+//! ```
+use std::time::{Duration, Instant};
+
+use iced::{Subscription, Task, theme::Mode, window};
+
+use crate::theme::{
+    CustomTheme, Theme, color,
+    pallete::{ColorValue, ColorVariant},
+    tokens::Tokens,
+};
+
+/// Which theme to show
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Preference {
+    /// The light or dark theme, following the operating system
+    #[default]
+    System,
+    /// A single theme, whatever the operating system prefers
+    Fixed(Theme),
+}
+
+/// An event that changes the shown theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The operating system reported its color scheme
+    ModeChanged(Mode),
+    /// A frame is about to be drawn while the themes cross-fade
+    Frame(Instant),
+}
+
+/// A theme following the light or dark preference of the operating system
+#[derive(Debug, Clone)]
+pub struct SystemTheme {
+    light: Theme,
+    dark: Theme,
+    preference: Preference,
+    mode: Mode,
+    duration: Duration,
+    fade: Option<Fade>,
+}
+
+/// A running cross-fade from the tokens shown when it started
+#[derive(Debug, Clone, Copy)]
+struct Fade {
+    from: Tokens,
+    /// Set by the first frame, so the fade starts when it is first drawn
+    started: Option<Instant>,
+    progress: f32,
+}
+
+impl Default for SystemTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemTheme {
+    /// Follows the system with the built-in light and dark themes
+    pub fn new() -> Self {
+        Self {
+            light: Theme::Light,
+            dark: Theme::Dark,
+            preference: Preference::System,
+            mode: Mode::None,
+            duration: Duration::from_millis(250),
+            fade: None,
+        }
+    }
+
+    /// Sets the theme shown when the system prefers light. Default: [`Theme::Light`]
+    pub fn light(mut self, theme: Theme) -> Self {
+        self.light = theme;
+        self
+    }
+
+    /// Sets the theme shown when the system prefers dark. Default: [`Theme::Dark`]
+    pub fn dark(mut self, theme: Theme) -> Self {
+        self.dark = theme;
+        self
+    }
+
+    /// Sets how long the cross-fade between themes takes. Default: 250ms
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The current preference
+    pub fn preference(&self) -> &Preference {
+        &self.preference
+    }
+
+    /// The color scheme last reported by the system, `Mode::None` until known
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Switches between following the system and a fixed theme, cross-fading
+    pub fn set_preference(&mut self, preference: Preference) {
+        let shown = self.tokens();
+        self.preference = preference;
+        self.fade_from(shown);
+    }
+
+    /// Applies an event from [`SystemTheme::subscription`] or [`SystemTheme::fetch`]
+    pub fn update(&mut self, event: Event) {
+        match event {
+            Event::ModeChanged(mode) => {
+                if mode == self.mode {
+                    return;
+                }
+
+                let known = self.mode != Mode::None;
+                let shown = self.tokens();
+                self.mode = mode;
+
+                // The first report replaces the fallback without a visible fade
+                if known {
+                    self.fade_from(shown);
+                }
+            }
+            Event::Frame(now) => {
+                let Some(fade) = &mut self.fade else {
+                    return;
+                };
+
+                let started = *fade.started.get_or_insert(now);
+                let elapsed = now.saturating_duration_since(started);
+
+                if elapsed >= self.duration {
+                    self.fade = None;
+                } else {
+                    fade.progress = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+                }
+            }
+        }
+    }
+
+    /// Whether the shown theme is still cross-fading
+    pub fn is_fading(&self) -> bool {
+        self.fade.is_some()
+    }
+
+    /// The theme being faded to, or shown once the fade is over
+    pub fn target(&self) -> &Theme {
+        match &self.preference {
+            Preference::Fixed(theme) => theme,
+            // Like `theme::Base::default`, an unknown preference is dark
+            Preference::System if self.mode == Mode::Light => &self.light,
+            Preference::System => &self.dark,
+        }
+    }
+
+    /// The theme to draw with
+    ///
+    /// During a fade this is a custom theme with tokens between the previous and the
+    /// target theme. Sizes and fonts are not faded and always follow the target.
+    pub fn theme(&self) -> Theme {
+        let target = self.target();
+
+        if self.is_fading() {
+            CustomTheme::from_tokens("System", target.is_dark(), self.tokens())
+                .sizes(target.sizes())
+                .fonts(target.fonts())
+                .build()
+        } else {
+            target.clone()
+        }
+    }
+
+    /// Listens for system color scheme changes, and for frames while fading
+    pub fn subscription(&self) -> Subscription<Event> {
+        let changes = iced::system::theme_changes().map(Event::ModeChanged);
+
+        if self.is_fading() {
+            Subscription::batch([changes, window::frames().map(Event::Frame)])
+        } else {
+            changes
+        }
+    }
+
+    /// Asks the system for its current color scheme, usually at startup
+    pub fn fetch() -> Task<Event> {
+        iced::system::theme().map(Event::ModeChanged)
+    }
+
+    /// The tokens currently shown, partway through a fade
+    fn tokens(&self) -> Tokens {
+        let target = self.target().tokens();
+
+        match self.fade {
+            Some(fade) => mix(&fade.from, &target, ease(fade.progress)),
+            None => target,
+        }
+    }
+
+    fn fade_from(&mut self, shown: Tokens) {
+        self.fade = (shown != self.target().tokens() && !self.duration.is_zero()).then_some(Fade {
+            from: shown,
+            started: None,
+            progress: 0.0,
+        });
+    }
+}
+
+/// Ease-in-out cubic
+fn ease(t: f32) -> f32 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+fn mix(from: &Tokens, to: &Tokens, amount: f32) -> Tokens {
+    let mut tokens = *to;
+
+    for variant in ColorVariant::SCALES {
+        for value in ColorValue::ALL {
+            *variant.get_color_mut(&mut tokens, value) = color::mix(
+                variant.get_color(*from, value),
+                variant.get_color(*to, value),
+                amount,
+            );
+        }
+    }
+
+    tokens.neutral_0 = color::mix(from.neutral_0, to.neutral_0, amount);
+    tokens.neutral_1000 = color::mix(from.neutral_1000, to.neutral_1000, amount);
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{sizes::COMPACT, typography::Fonts};
+
+    #[test]
+    fn first_report_switches_without_fading() {
+        let mut theme = SystemTheme::new();
+        assert_eq!(theme.theme(), Theme::Dark);
+
+        theme.update(Event::ModeChanged(Mode::Light));
+
+        assert!(!theme.is_fading());
+        assert_eq!(theme.theme(), Theme::Light);
+    }
+
+    #[test]
+    fn mode_changes_cross_fade() {
+        let light = Theme::Light.tokens();
+        let dark = Theme::Dark.tokens();
+
+        let mut theme = SystemTheme::new().duration(Duration::from_millis(200));
+        theme.update(Event::ModeChanged(Mode::Light));
+        theme.update(Event::ModeChanged(Mode::Dark));
+
+        // Nothing changes until the first frame is drawn
+        assert!(theme.is_fading());
+        assert_eq!(theme.theme().tokens(), light);
+        assert_eq!(theme.target(), &Theme::Dark);
+
+        let start = Instant::now();
+        theme.update(Event::Frame(start));
+        theme.update(Event::Frame(start + Duration::from_millis(100)));
+
+        let halfway = theme.theme().tokens();
+        assert_ne!(halfway, light);
+        assert_ne!(halfway, dark);
+        assert!(theme.theme().is_dark());
+
+        theme.update(Event::Frame(start + Duration::from_millis(200)));
+
+        assert!(!theme.is_fading());
+        assert_eq!(theme.theme(), Theme::Dark);
+    }
+
+    #[test]
+    fn interrupted_fade_continues_from_shown_tokens() {
+        let mut theme = SystemTheme::new().duration(Duration::from_millis(200));
+        theme.update(Event::ModeChanged(Mode::Light));
+        theme.update(Event::ModeChanged(Mode::Dark));
+
+        let start = Instant::now();
+        theme.update(Event::Frame(start));
+        theme.update(Event::Frame(start + Duration::from_millis(100)));
+        let shown = theme.theme().tokens();
+
+        theme.update(Event::ModeChanged(Mode::Light));
+
+        assert_eq!(theme.theme().tokens(), shown);
+        assert_eq!(theme.target(), &Theme::Light);
+    }
+
+    #[test]
+    fn fixed_preference_ignores_the_system() {
+        let mut theme = SystemTheme::new().duration(Duration::ZERO);
+        theme.update(Event::ModeChanged(Mode::Light));
+        theme.set_preference(Preference::Fixed(Theme::HighContrastDark));
+
+        assert!(!theme.is_fading());
+        assert_eq!(theme.theme(), Theme::HighContrastDark);
+
+        theme.update(Event::ModeChanged(Mode::Dark));
+        theme.update(Event::ModeChanged(Mode::Light));
+        assert_eq!(theme.theme(), Theme::HighContrastDark);
+
+        theme.set_preference(Preference::System);
+        assert_eq!(theme.theme(), Theme::Light);
+    }
+
+    #[test]
+    fn fade_keeps_the_target_sizes_and_fonts() {
+        let fonts = Fonts::named("Brand Sans", "Brand Mono");
+        let dark = CustomTheme::new("Brand Dark", &Theme::Dark)
+            .sizes(COMPACT)
+            .fonts(fonts)
+            .build();
+
+        let mut theme = SystemTheme::new()
+            .dark(dark)
+            .duration(Duration::from_millis(200));
+        theme.update(Event::ModeChanged(Mode::Light));
+        theme.update(Event::ModeChanged(Mode::Dark));
+
+        let start = Instant::now();
+        theme.update(Event::Frame(start));
+        theme.update(Event::Frame(start + Duration::from_millis(100)));
+
+        assert!(theme.is_fading());
+        assert_eq!(theme.theme().sizes(), COMPACT);
+        assert_eq!(theme.theme().fonts(), fonts);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    theme::Theme,
+    widgets::tab_indicator::{Catalog, Style},
+};
+
+/// Style class for the tab group navigation strip
+///
+/// Shoelace tab group styling reference:
+/// - Track: neutral-200, 2px
+/// - Active tab indicator: primary-600
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TabGroupStyleClass {
+    #[default]
+    Default,
+}
+
+impl Catalog for Theme {
+    type Class<'a> = TabGroupStyleClass;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TabGroupStyleClass::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>) -> Style {
+        let tokens = self.tokens();
+
+        Style {
+            track: tokens.neutral.c200,
+            indicator: tokens.primary.c600,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use iced::Color;
 
 /// A color scale from 50 (darkest) to 950 (lightest)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ColorScale {
     pub c50: Color,
     pub c100: Color,
@@ -17,7 +17,7 @@ pub struct ColorScale {
 }
 
 /// Complete theme token set
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Tokens {
     // Color scales
     pub gray: ColorScale,
@@ -0,0 +1,155 @@
+//! Font families and the semantic text roles
+//!
+//! Every [`Role`] maps to a font size, weight and line height token, so headings and
+//! body text follow the theme's density. The sans and mono families come from the
+//! theme's [`Fonts`], which also bundle the font files to load at application start.
+//! The built-in themes use Inter and Fira Mono, shipped in `fonts/` under the SIL
+//! Open Font License.
+use iced::{
+    Font, Task,
+    font::{self, Weight},
+};
+
+use crate::theme::sizes::{FontSize, FontWeight, LineHeight};
+
+/// The font families of a theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fonts {
+    /// The family of headings and body text
+    pub sans: Font,
+    /// The family of code
+    pub mono: Font,
+    /// Font files providing the families, usually from `include_bytes!`
+    pub bundled: &'static [&'static [u8]],
+}
+
+/// Inter, as a variable font covering every weight
+pub const INTER: &[u8] = include_bytes!("../../fonts/InterVariable.ttf");
+
+/// Fira Mono, in its medium weight
+pub const FIRA_MONO: &[u8] = include_bytes!("../../fonts/FiraMono-Medium.ttf");
+
+// The fonts of the built-in themes, bundled with the crate
+pub const FONTS: Fonts = Fonts::named("Inter Variable", "Fira Mono").bundled(&[INTER, FIRA_MONO]);
+
+impl Fonts {
+    /// Uses the named families, e.g. `Fonts::named("Inter", "JetBrains Mono")`
+    pub const fn named(sans: &'static str, mono: &'static str) -> Self {
+        Self {
+            sans: Font::with_name(sans),
+            mono: Font::with_name(mono),
+            bundled: &[],
+        }
+    }
+
+    /// Sets the font files to load for the families
+    pub const fn bundled(mut self, bundled: &'static [&'static [u8]]) -> Self {
+        self.bundled = bundled;
+        self
+    }
+
+    /// Loads the bundled font files
+    ///
+    /// Return this from the application's boot function, or pass every file to
+    /// `iced::Application::font` instead.
+    pub fn load<Message: Send + 'static>(&self) -> Task<Message> {
+        Task::batch(
+            self.bundled
+                .iter()
+                .map(|bytes| font::load(*bytes).discard()),
+        )
+    }
+}
+
+/// The semantic role of a piece of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Role {
+    /// Oversized text for hero sections
+    Display,
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+    /// Running text
+    #[default]
+    Body,
+    /// Small supporting text, such as hints and captions
+    Caption,
+    /// Inline or block code, in the mono family
+    Code,
+}
+
+impl Role {
+    /// The font size of the role (in pixels)
+    pub fn size(self, font_size: &FontSize) -> f32 {
+        match self {
+            Role::Display => font_size.x4_large,
+            Role::H1 => font_size.x3_large,
+            Role::H2 => font_size.x2_large,
+            Role::H3 => font_size.x_large,
+            Role::H4 => font_size.large,
+            Role::H5 => font_size.medium,
+            Role::H6 => font_size.small,
+            Role::Body => font_size.medium,
+            Role::Caption => font_size.small,
+            Role::Code => font_size.medium,
+        }
+    }
+
+    /// The font weight of the role, as a CSS weight
+    pub fn weight(self, font_weight: &FontWeight) -> u16 {
+        match self {
+            Role::Display => font_weight.light,
+            Role::H1 | Role::H2 => font_weight.bold,
+            Role::H3 | Role::H4 | Role::H5 | Role::H6 => font_weight.semibold,
+            Role::Body | Role::Caption | Role::Code => font_weight.normal,
+        }
+    }
+
+    /// The line height of the role, as a multiple of its font size
+    pub fn line_height(self, line_height: &LineHeight) -> f32 {
+        match self {
+            Role::Display | Role::H1 | Role::H2 => line_height.denser,
+            Role::H3 | Role::H4 | Role::H5 | Role::H6 => line_height.dense,
+            Role::Body | Role::Caption | Role::Code => line_height.normal,
+        }
+    }
+
+    /// The font of the role, in the sans or mono family
+    pub fn font(self, fonts: &Fonts, font_weight: &FontWeight) -> Font {
+        let family = match self {
+            Role::Code => fonts.mono,
+            _ => fonts.sans,
+        };
+
+        Font {
+            weight: weight(self.weight(font_weight)),
+            ..family
+        }
+    }
+
+    /// Whether the role is a heading
+    pub fn is_heading(self) -> bool {
+        matches!(
+            self,
+            Role::Display | Role::H1 | Role::H2 | Role::H3 | Role::H4 | Role::H5 | Role::H6
+        )
+    }
+}
+
+/// Converts a CSS font weight to the nearest `Weight`
+pub fn weight(value: u16) -> Weight {
+    match value {
+        0..=149 => Weight::Thin,
+        150..=249 => Weight::ExtraLight,
+        250..=349 => Weight::Light,
+        350..=449 => Weight::Normal,
+        450..=549 => Weight::Medium,
+        550..=649 => Weight::Semibold,
+        650..=749 => Weight::Bold,
+        750..=849 => Weight::ExtraBold,
+        _ => Weight::Black,
+    }
+}
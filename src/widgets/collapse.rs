@@ -0,0 +1,292 @@
+//! A container that animates its height between collapsed and expanded.
+//!
+//! The content is always laid out at its natural size; the [`Collapse`] reports
+//! a fraction of that height and clips whatever does not fit. Once collapsing
+//! starts, the content is skipped by operations such as focus traversal; when
+//! fully collapsed, it no longer receives events.
+use std::time::{Duration, Instant};
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::window;
+use iced_core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+/// A wrapper that expands and collapses its content with an animated height.
+pub struct Collapse<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    open: bool,
+    duration: Duration,
+}
+
+impl<'a, Message, Theme, Renderer> Collapse<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Collapse`] that is expanded when `open` is true.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, open: bool) -> Self {
+        Self {
+            content: content.into(),
+            open,
+            duration: Duration::from_millis(250),
+        }
+    }
+
+    /// Sets how long expanding or collapsing takes.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    open: bool,
+    /// The expansion when the current animation started
+    from: f32,
+    started: Option<Instant>,
+}
+
+impl State {
+    /// Returns how far the content is expanded, from 0.0 to 1.0.
+    fn expansion(&self, duration: Duration) -> f32 {
+        let target = if self.open { 1.0 } else { 0.0 };
+
+        let Some(started) = self.started else {
+            return target;
+        };
+
+        if duration.is_zero() {
+            return target;
+        }
+
+        let t = (started.elapsed().as_secs_f32() / duration.as_secs_f32()).clamp(0.0, 1.0);
+
+        // Ease-in-out cubic
+        let eased = if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        };
+
+        self.from + (target - self.from) * eased
+    }
+
+    fn is_animating(&self, duration: Duration) -> bool {
+        self.started
+            .is_some_and(|started| started.elapsed() < duration)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Collapse<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            open: self.open,
+            from: 0.0,
+            started: None,
+        })
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.open != self.open {
+            // Start from wherever a running animation currently is
+            state.from = state.expansion(self.duration);
+            state.open = self.open;
+            state.started = Some(Instant::now());
+        }
+
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.content.as_widget().size().width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let expansion = tree.state.downcast_ref::<State>().expansion(self.duration);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = Size::new(content.size().width, content.size().height * expansion);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        // Keep focus and other operations out of collapsed content
+        if !tree.state.downcast_ref::<State>().open {
+            return;
+        }
+
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && state.started.is_some()
+        {
+            if !state.is_animating(self.duration) {
+                state.started = None;
+            } else {
+                shell.request_redraw();
+            }
+
+            shell.invalidate_layout();
+        }
+
+        if layout.bounds().height <= 0.0 {
+            return;
+        }
+
+        // Hide the cursor from the clipped part of the content
+        let cursor = if cursor.is_over(layout.bounds()) {
+            cursor
+        } else {
+            mouse::Cursor::Unavailable
+        };
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::None;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if bounds.height <= 0.0 {
+            return;
+        }
+
+        let Some(clipped_viewport) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clipped_viewport, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                inherited_style,
+                layout.children().next().unwrap(),
+                cursor,
+                &clipped_viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !tree.state.downcast_ref::<State>().open {
+            return None;
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Collapse<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        collapse: Collapse<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(collapse)
+    }
+}
@@ -0,0 +1,365 @@
+//! Publishes a message once a duration has elapsed, optionally drawing a countdown bar.
+//!
+//! The countdown pauses while the cursor is over the content and resumes when it
+//! leaves. The countdown restarts if the widget is rebuilt with a different
+//! duration; use a keyed container when the widgets around it can be reordered.
+use std::time::{Duration, Instant};
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::window;
+use iced_core::{Clipboard, Color, Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+/// A wrapper that counts down and publishes a message when the time is up.
+pub struct Countdown<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    duration: Duration,
+    on_expire: Option<Message>,
+    show_bar: bool,
+    bar_height: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Countdown<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Countdown`] of the given duration around some content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            duration,
+            on_expire: None,
+            show_bar: false,
+            bar_height: 3.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message published once the countdown reaches zero.
+    pub fn on_expire(mut self, message: Message) -> Self {
+        self.on_expire = Some(message);
+        self
+    }
+
+    /// Sets whether a bar showing the remaining time is drawn along the bottom edge.
+    pub fn show_bar(mut self, show_bar: bool) -> Self {
+        self.show_bar = show_bar;
+        self
+    }
+
+    /// Sets the height of the countdown bar.
+    pub fn bar_height(mut self, bar_height: f32) -> Self {
+        self.bar_height = bar_height;
+        self
+    }
+
+    /// Sets the style class of the [`Countdown`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    duration: Duration,
+    /// Time left when the countdown was last paused or started
+    remaining: Duration,
+    /// When the countdown last resumed; `None` while paused
+    resumed_at: Option<Instant>,
+    is_expired: bool,
+}
+
+impl State {
+    fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            remaining: duration,
+            resumed_at: Some(Instant::now()),
+            is_expired: false,
+        }
+    }
+
+    fn remaining(&self, now: Instant) -> Duration {
+        match self.resumed_at {
+            Some(resumed_at) => self
+                .remaining
+                .saturating_sub(now.saturating_duration_since(resumed_at)),
+            None => self.remaining,
+        }
+    }
+
+    fn pause(&mut self, now: Instant) {
+        if self.resumed_at.is_some() {
+            self.remaining = self.remaining(now);
+            self.resumed_at = None;
+        }
+    }
+
+    /// Resumes a paused countdown, returning whether it was paused.
+    fn resume(&mut self, now: Instant) -> bool {
+        if self.resumed_at.is_none() {
+            self.resumed_at = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Countdown<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::new(self.duration))
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.duration != self.duration {
+            *state = State::new(self.duration);
+        }
+
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_expired {
+            return;
+        }
+
+        let now = Instant::now();
+
+        // Pause while hovered so the user has time to read
+        if cursor.is_over(layout.bounds()) {
+            state.pause(now);
+        } else if state.resume(now) {
+            // Ticking stops while paused, so start it again
+            shell.request_redraw();
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let remaining = state.remaining(*now);
+
+            if remaining.is_zero() {
+                state.is_expired = true;
+
+                if let Some(message) = self.on_expire.clone() {
+                    shell.publish(message);
+                }
+            } else if state.resumed_at.is_some() {
+                if self.show_bar {
+                    shell.request_redraw();
+                } else {
+                    shell.request_redraw_at(*now + remaining);
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if !self.show_bar {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        let fraction = if state.duration.is_zero() {
+            0.0
+        } else {
+            state.remaining(Instant::now()).as_secs_f32() / state.duration.as_secs_f32()
+        };
+
+        let track = Rectangle {
+            y: bounds.y + bounds.height - self.bar_height,
+            height: self.bar_height,
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: track,
+                ..renderer::Quad::default()
+            },
+            style.track,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    width: track.width * fraction,
+                    ..track
+                },
+                ..renderer::Quad::default()
+            },
+            style.bar,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Countdown<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        countdown: Countdown<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(countdown)
+    }
+}
+
+/// The appearance of a [`Countdown`] bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color behind the bar.
+    pub track: Color,
+    /// The color of the remaining time.
+    pub bar: Color,
+}
+
+/// The theme catalog of a [`Countdown`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
@@ -0,0 +1,202 @@
+//! Shows an image, or some fallback content when the image cannot be loaded.
+//!
+//! Whether the image loaded is decided during layout by asking the renderer for
+//! its dimensions, so a missing file or undecodable bytes switch to the fallback
+//! without the application having to load the image itself.
+use iced_core::image;
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size};
+
+/// A wrapper that shows an image element, falling back when the image is invalid.
+pub struct ImageFallback<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer>
+where
+    Renderer: image::Renderer,
+{
+    handle: Renderer::Handle,
+    image: Element<'a, Message, Theme, Renderer>,
+    fallback: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> ImageFallback<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    /// Creates a new [`ImageFallback`] showing `image` when `handle` can be loaded
+    /// and `fallback` otherwise.
+    pub fn new(
+        handle: Renderer::Handle,
+        image: impl Into<Element<'a, Message, Theme, Renderer>>,
+        fallback: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            handle,
+            image: image.into(),
+            fallback: fallback.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    loaded: bool,
+}
+
+impl State {
+    /// Returns the index of the child being shown.
+    fn shown(&self) -> usize {
+        if self.loaded { 0 } else { 1 }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ImageFallback<'_, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.image),
+            widget::Tree::new(&self.fallback),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.image.as_widget(), self.fallback.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.fallback.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let loaded = renderer
+            .measure_image(&self.handle)
+            .is_some_and(|size| size.width > 0 && size.height > 0);
+
+        let state = tree.state.downcast_mut::<State>();
+        state.loaded = loaded;
+
+        let shown = state.shown();
+        let content = if loaded {
+            &mut self.image
+        } else {
+            &mut self.fallback
+        };
+
+        content
+            .as_widget_mut()
+            .layout(&mut tree.children[shown], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let shown = tree.state.downcast_ref::<State>().shown();
+        let content = if shown == 0 {
+            &mut self.image
+        } else {
+            &mut self.fallback
+        };
+
+        content.as_widget_mut().update(
+            &mut tree.children[shown],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let shown = tree.state.downcast_ref::<State>().shown();
+        let content = if shown == 0 {
+            &self.image
+        } else {
+            &self.fallback
+        };
+
+        content.as_widget().mouse_interaction(
+            &tree.children[shown],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let shown = tree.state.downcast_ref::<State>().shown();
+        let content = if shown == 0 {
+            &self.image
+        } else {
+            &self.fallback
+        };
+
+        content.as_widget().draw(
+            &tree.children[shown],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ImageFallback<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: image::Renderer + 'a,
+{
+    fn from(
+        fallback: ImageFallback<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(fallback)
+    }
+}
@@ -0,0 +1,259 @@
+//! A container that gains focus when clicked and reports key presses while focused.
+//!
+//! This is the keyboard counterpart of iced's `mouse_area`. Components built from
+//! other widgets can wrap their content in a [`KeyboardArea`] to support arrow-key
+//! navigation without a full custom widget.
+//!
+//! # Example
+//! ```no_run
+//! use iced::keyboard::{Key, key::Named};
+//! use iced_shoelace::widgets::keyboard_area::KeyboardArea;
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Next,
+//!     Previous,
+//! }
+//!
+//! let area = KeyboardArea::new("Focus me and press the arrow keys").on_key_press(
+//!     |key, _modifiers| match key {
+//!         Key::Named(Named::ArrowRight) => Some(Message::Next),
+//!         Key::Named(Named::ArrowLeft) => Some(Message::Previous),
+//!         _ => None,
+//!     },
+//! );
+//! ```
+use iced_core::keyboard;
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+/// A wrapper that emits messages for key presses while it is focused.
+pub struct KeyboardArea<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_key_press: Option<Box<dyn Fn(keyboard::Key, keyboard::Modifiers) -> Option<Message> + 'a>>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> KeyboardArea<'a, Message, Theme, Renderer> {
+    /// Creates a new [`KeyboardArea`] wrapping the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_key_press: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
+    /// Sets the function called for each key press while focused.
+    ///
+    /// Returning `Some` publishes the message and captures the event.
+    pub fn on_key_press(
+        mut self,
+        f: impl Fn(keyboard::Key, keyboard::Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_key_press = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message produced when the [`KeyboardArea`] gains focus.
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the [`KeyboardArea`] loses focus.
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for KeyboardArea<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                let is_focused = cursor.is_over(layout.bounds());
+
+                if is_focused != state.is_focused {
+                    state.is_focused = is_focused;
+
+                    let message = if is_focused {
+                        self.on_focus.clone()
+                    } else {
+                        self.on_blur.clone()
+                    };
+
+                    if let Some(message) = message {
+                        shell.publish(message);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                if state.is_focused
+                    && !shell.is_event_captured()
+                    && let Some(on_key_press) = &self.on_key_press
+                    && let Some(message) = on_key_press(key.clone(), *modifiers)
+                {
+                    shell.publish(message);
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<KeyboardArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        area: KeyboardArea<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(area)
+    }
+}
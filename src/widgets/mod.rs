@@ -1,2 +1,13 @@
+pub mod collapse;
+pub mod countdown;
+pub mod hidden;
+pub mod image_fallback;
+pub mod keyboard_area;
 pub mod overlay;
+pub mod popup;
+pub mod range;
+pub mod scroll_arrows;
+pub mod split;
+pub mod status_area;
+pub mod tab_indicator;
 pub mod tooltip;
@@ -0,0 +1,536 @@
+//! Popups display interactive content anchored to another element.
+//!
+//! Unlike a [`Tooltip`](super::tooltip::Tooltip), a popup is shown for as long as it
+//! is open, forwards events to its content, and asks to be closed when the user
+//! clicks outside of it or presses Escape.
+//!
+//! # Example
+//! ```no_run
+//! use iced::widget::{button, text};
+//! use iced_shoelace::widgets::popup::{Align, Popup, Side};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Toggle,
+//!     Close,
+//! }
+//!
+//! let popup = Popup::new(
+//!     button("Options").on_press(Message::Toggle),
+//!     text("Popup contents"),
+//! )
+//! .open(true)
+//! .side(Side::Bottom)
+//! .align(Align::Start)
+//! .gap(4.0)
+//! .on_close(Message::Close);
+//! ```
+use iced_core::keyboard::{self, key::Named};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::{Clipboard, Element, Event, Length, Point, Rectangle, Shell, Size, Vector};
+
+/// The side of the anchor a [`Popup`] is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Side {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// Left of the anchor.
+    Left,
+    /// Right of the anchor.
+    Right,
+}
+
+impl Side {
+    /// The side across the anchor, used when the popup does not fit.
+    fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// How a [`Popup`] is aligned along the side of its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// Aligned to the left or top edge of the anchor.
+    Start,
+    /// Centered on the anchor.
+    #[default]
+    Center,
+    /// Aligned to the right or bottom edge of the anchor.
+    End,
+}
+
+/// An element that shows interactive content next to an anchor while open.
+pub struct Popup<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer> {
+    anchor: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    open: bool,
+    side: Side,
+    align: Align,
+    gap: f32,
+    skidding: f32,
+    flip: bool,
+    snap_within_viewport: bool,
+    on_close: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Popup<'a, Message, Theme, Renderer> {
+    /// Creates a new, closed [`Popup`] showing `content` next to `anchor`.
+    pub fn new(
+        anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            anchor: anchor.into(),
+            content: content.into(),
+            open: false,
+            side: Side::default(),
+            align: Align::default(),
+            gap: 0.0,
+            skidding: 0.0,
+            flip: true,
+            snap_within_viewport: true,
+            on_close: None,
+        }
+    }
+
+    /// Sets whether the [`Popup`] is shown.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Sets the side of the anchor the [`Popup`] is placed on.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets how the [`Popup`] is aligned along its side of the anchor.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the gap between the anchor and the [`Popup`].
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the offset of the [`Popup`] along its side of the anchor.
+    pub fn skidding(mut self, skidding: f32) -> Self {
+        self.skidding = skidding;
+        self
+    }
+
+    /// Sets whether the [`Popup`] moves to the opposite side when it does not fit.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Sets whether the [`Popup`] is shifted to stay within the viewport.
+    pub fn snap_within_viewport(mut self, snap: bool) -> Self {
+        self.snap_within_viewport = snap;
+        self
+    }
+
+    /// Sets the message produced when the user clicks outside of the open
+    /// [`Popup`] and its anchor, or presses Escape.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popup<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.anchor),
+            widget::Tree::new(&self.content),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.anchor.as_widget(), self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.anchor.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.anchor.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.anchor
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.anchor
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.anchor.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.anchor.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = tree.children.iter_mut();
+
+        let anchor = self.anchor.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let popup = if self.open {
+            Some(overlay::Element::new(Box::new(Overlay {
+                anchor_bounds: layout.bounds() + translation,
+                content: &mut self.content,
+                state: children.next().unwrap(),
+                side: self.side,
+                align: self.align,
+                gap: self.gap,
+                skidding: self.skidding,
+                flip: self.flip,
+                snap_within_viewport: self.snap_within_viewport,
+                on_close: self.on_close.clone(),
+            })))
+        } else {
+            None
+        };
+
+        if anchor.is_some() || popup.is_some() {
+            Some(overlay::Group::with_children(anchor.into_iter().chain(popup).collect()).overlay())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popup<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(popup: Popup<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(popup)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    anchor_bounds: Rectangle,
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    state: &'b mut widget::Tree,
+    side: Side,
+    align: Align,
+    gap: f32,
+    skidding: f32,
+    flip: bool,
+    snap_within_viewport: bool,
+    on_close: Option<Message>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer> {
+    /// The top left corner of a popup of `size` on `side` of the anchor
+    fn position(&self, side: Side, size: Size) -> Point {
+        let anchor = self.anchor_bounds;
+
+        let along = |start: f32, length: f32, extent: f32| {
+            let offset = match self.align {
+                Align::Start => 0.0,
+                Align::Center => (length - extent) / 2.0,
+                Align::End => length - extent,
+            };
+
+            start + offset + self.skidding
+        };
+
+        match side {
+            Side::Top => Point::new(
+                along(anchor.x, anchor.width, size.width),
+                anchor.y - self.gap - size.height,
+            ),
+            Side::Bottom => Point::new(
+                along(anchor.x, anchor.width, size.width),
+                anchor.y + anchor.height + self.gap,
+            ),
+            Side::Left => Point::new(
+                anchor.x - self.gap - size.width,
+                along(anchor.y, anchor.height, size.height),
+            ),
+            Side::Right => Point::new(
+                anchor.x + anchor.width + self.gap,
+                along(anchor.y, anchor.height, size.height),
+            ),
+        }
+    }
+}
+
+/// Whether a popup at `position` overflows `viewport` on the given `side`
+fn overflows(side: Side, position: Point, size: Size, viewport: Rectangle) -> bool {
+    match side {
+        Side::Top => position.y < viewport.y,
+        Side::Bottom => position.y + size.height > viewport.y + viewport.height,
+        Side::Left => position.x < viewport.x,
+        Side::Right => position.x + size.width > viewport.x + viewport.width,
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+
+        let content = self.content.as_widget_mut().layout(
+            self.state,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                if self.snap_within_viewport {
+                    viewport.size()
+                } else {
+                    Size::INFINITE
+                },
+            ),
+        );
+
+        let size = content.size();
+        let mut position = self.position(self.side, size);
+
+        // Flip to the opposite side only if the popup fits there
+        if self.flip && overflows(self.side, position, size, viewport) {
+            let opposite = self.side.opposite();
+            let flipped = self.position(opposite, size);
+
+            if !overflows(opposite, flipped, size, viewport) {
+                position = flipped;
+            }
+        }
+
+        if self.snap_within_viewport {
+            position.x = position
+                .x
+                .min(viewport.x + viewport.width - size.width)
+                .max(viewport.x);
+            position.y = position
+                .y
+                .min(viewport.y + viewport.height - size.height)
+                .max(viewport.y);
+        }
+
+        layout::Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        self.content.as_widget_mut().update(
+            self.state,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        let Some(on_close) = &self.on_close else {
+            return;
+        };
+
+        match event {
+            // The click still reaches whatever is below, as in Shoelace
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if let Some(position) = cursor.position()
+                    && !layout.bounds().contains(position)
+                    && !self.anchor_bounds.contains(position)
+                {
+                    shell.publish(on_close.clone());
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Escape),
+                ..
+            }) if !shell.is_event_captured() => {
+                shell.publish(on_close.clone());
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            self.state,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.state,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content.as_widget().draw(
+            self.state,
+            renderer,
+            theme,
+            inherited_style,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.state,
+            layout.children().next().unwrap(),
+            renderer,
+            &layout.bounds(),
+            Vector::ZERO,
+        )
+    }
+}
@@ -0,0 +1,567 @@
+//! Range sliders let users pick a value, or an interval, within a span of numbers.
+//!
+//! Unlike iced's built-in slider, this widget can render two thumbs for
+//! selecting an interval, and draws optional tick marks beneath the track.
+//!
+//! # Example
+//! ```no_run
+//! use iced_shoelace::widgets::range::{Range, Value};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Changed(Value),
+//! }
+//!
+//! let slider = Range::new(0.0..=100.0, Value::Interval(20.0, 80.0), Message::Changed)
+//!     .step(5.0)
+//!     .ticks(vec![0.0, 25.0, 50.0, 75.0, 100.0]);
+//! ```
+use std::ops::RangeInclusive;
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size,
+};
+
+/// The value of a [`Range`]: a single point or an interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    /// A single thumb positioned at the value.
+    Single(f32),
+    /// Two thumbs bounding an interval (lower, upper).
+    Interval(f32, f32),
+}
+
+impl Value {
+    /// Returns the lower bound of the value (the value itself for a single thumb).
+    pub fn lower(&self) -> f32 {
+        match *self {
+            Value::Single(value) => value,
+            Value::Interval(lower, _) => lower,
+        }
+    }
+
+    /// Returns the upper bound of the value (the value itself for a single thumb).
+    pub fn upper(&self) -> f32 {
+        match *self {
+            Value::Single(value) => value,
+            Value::Interval(_, upper) => upper,
+        }
+    }
+}
+
+/// A horizontal slider with one or two thumbs.
+pub struct Range<'a, Message, Theme = crate::theme::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: Value,
+    step: f32,
+    ticks: Vec<f32>,
+    on_change: Box<dyn Fn(Value) -> Message + 'a>,
+    on_release: Option<Message>,
+    disabled: bool,
+    width: Length,
+    track_height: f32,
+    thumb_size: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Range<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of the track.
+    const DEFAULT_TRACK_HEIGHT: f32 = 6.0;
+
+    /// The default diameter of a thumb.
+    const DEFAULT_THUMB_SIZE: f32 = 14.0;
+
+    /// Creates a new [`Range`] spanning `range` with the given [`Value`].
+    ///
+    /// `on_change` is called with the new value whenever a thumb moves.
+    pub fn new(
+        range: RangeInclusive<f32>,
+        value: Value,
+        on_change: impl Fn(Value) -> Message + 'a,
+    ) -> Self {
+        Self {
+            range,
+            value,
+            step: 1.0,
+            ticks: Vec::new(),
+            on_change: Box::new(on_change),
+            on_release: None,
+            disabled: false,
+            width: Length::Fill,
+            track_height: Self::DEFAULT_TRACK_HEIGHT,
+            thumb_size: Self::DEFAULT_THUMB_SIZE,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the step size of the [`Range`].
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the positions of the tick marks drawn beneath the track.
+    pub fn ticks(mut self, ticks: Vec<f32>) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the message produced when a thumb is released.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets whether the [`Range`] is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the width of the [`Range`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the track.
+    pub fn track_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.track_height = height.into().0;
+        self
+    }
+
+    /// Sets the diameter of the thumbs.
+    pub fn thumb_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.thumb_size = size.into().0;
+        self
+    }
+
+    /// Sets the style class of the [`Range`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the area the thumbs travel along, inset by half a thumb on each side.
+    fn track_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let radius = self.thumb_size / 2.0;
+
+        Rectangle {
+            x: bounds.x + radius,
+            y: bounds.y,
+            width: (bounds.width - self.thumb_size).max(0.0),
+            height: bounds.height,
+        }
+    }
+
+    /// Maps a value to its horizontal position along the track.
+    fn position_of(&self, track: Rectangle, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if end <= start {
+            return track.x;
+        }
+
+        track.x + track.width * ((value - start) / (end - start)).clamp(0.0, 1.0)
+    }
+
+    /// Maps a cursor position to a stepped value within the range.
+    fn value_at(&self, track: Rectangle, cursor: Point) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if track.width <= 0.0 || end <= start {
+            return start;
+        }
+
+        let percent = ((cursor.x - track.x) / track.width).clamp(0.0, 1.0);
+        let raw = start + percent * (end - start);
+
+        let stepped = if self.step > 0.0 {
+            start + ((raw - start) / self.step).round() * self.step
+        } else {
+            raw
+        };
+
+        stepped.clamp(start, end)
+    }
+
+    /// Returns the value with the dragged thumb moved to `new`.
+    fn moved(&self, thumb: Thumb, new: f32) -> Value {
+        match (self.value, thumb) {
+            (Value::Single(_), _) => Value::Single(new),
+            (Value::Interval(_, upper), Thumb::Lower) => Value::Interval(new.min(upper), upper),
+            (Value::Interval(lower, _), Thumb::Upper) => Value::Interval(lower, new.max(lower)),
+        }
+    }
+
+    /// Picks the thumb closest to the given value.
+    fn closest_thumb(&self, value: f32) -> Thumb {
+        match self.value {
+            Value::Single(_) => Thumb::Lower,
+            Value::Interval(lower, upper) => {
+                if (value - lower).abs() <= (value - upper).abs() && value <= upper {
+                    Thumb::Lower
+                } else {
+                    Thumb::Upper
+                }
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Range<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut widget::Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let tick_space = if self.ticks.is_empty() {
+            0.0
+        } else {
+            self.track_height
+        };
+
+        layout::atomic(
+            limits,
+            self.width,
+            self.thumb_size.max(self.track_height) + tick_space,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let track = self.track_bounds(bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let new = self.value_at(track, position);
+                    let thumb = self.closest_thumb(new);
+                    let value = self.moved(thumb, new);
+
+                    // Always publish on press so listeners know a drag has started
+                    state.dragging = Some(thumb);
+                    state.is_stacked = matches!(
+                        self.value,
+                        Value::Interval(lower, upper) if lower == upper && new == lower
+                    );
+                    self.value = value;
+                    shell.publish((self.on_change)(value));
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(mut thumb) = state.dragging
+                    && let Some(position) = cursor.position()
+                {
+                    let new = self.value_at(track, position);
+                    let stacked_at = self.value.lower();
+
+                    // Stacked thumbs split towards wherever the first drag goes
+                    if state.is_stacked && new != stacked_at {
+                        thumb = if new < stacked_at {
+                            Thumb::Lower
+                        } else {
+                            Thumb::Upper
+                        };
+                        state.dragging = Some(thumb);
+                        state.is_stacked = false;
+                    }
+
+                    let value = self.moved(thumb, new);
+
+                    if value != self.value {
+                        self.value = value;
+                        shell.publish((self.on_change)(value));
+                        shell.request_redraw();
+                    }
+
+                    shell.capture_event();
+                } else {
+                    let is_hovered = cursor.is_over(bounds);
+
+                    if is_hovered != state.is_hovered {
+                        state.is_hovered = is_hovered;
+                        shell.request_redraw();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.dragging.take().is_some() {
+                    if let Some(message) = self.on_release.clone() {
+                        shell.publish(message);
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.disabled {
+            if cursor.is_over(layout.bounds()) {
+                mouse::Interaction::NotAllowed
+            } else {
+                mouse::Interaction::default()
+            }
+        } else if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let track = self.track_bounds(bounds);
+
+        let status = if self.disabled {
+            Status::Disabled
+        } else if state.dragging.is_some() {
+            Status::Dragged
+        } else if state.is_hovered {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        let thumb_radius = self.thumb_size / 2.0;
+        let center_y = bounds.y + self.thumb_size.max(self.track_height) / 2.0;
+        let rail_y = center_y - self.track_height / 2.0;
+        let rail_radius = self.track_height / 2.0;
+
+        // Full rail, spanning the thumb travel area plus the thumb radius on each side
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: rail_y,
+                    width: bounds.width,
+                    height: self.track_height,
+                },
+                border: Border {
+                    radius: rail_radius.into(),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            style.rail,
+        );
+
+        // Active segment between the start (or lower thumb) and the value
+        let (active_start, active_end) = match self.value {
+            Value::Single(value) => (bounds.x, self.position_of(track, value)),
+            Value::Interval(lower, upper) => (
+                self.position_of(track, lower),
+                self.position_of(track, upper),
+            ),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: active_start,
+                    y: rail_y,
+                    width: (active_end - active_start).max(0.0),
+                    height: self.track_height,
+                },
+                border: Border {
+                    radius: rail_radius.into(),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            style.rail_active,
+        );
+
+        // Tick marks beneath the rail
+        let tick_y = center_y + self.thumb_size.max(self.track_height) / 2.0;
+
+        for tick in &self.ticks {
+            let x = self.position_of(track, *tick);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - 0.5,
+                        y: tick_y,
+                        width: 1.0,
+                        height: self.track_height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.tick,
+            );
+        }
+
+        // Thumbs
+        let thumbs = match self.value {
+            Value::Single(value) => vec![value],
+            Value::Interval(lower, upper) => vec![lower, upper],
+        };
+
+        for value in thumbs {
+            let x = self.position_of(track, value);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - thumb_radius,
+                        y: center_y - thumb_radius,
+                        width: self.thumb_size,
+                        height: self.thumb_size,
+                    },
+                    border: Border {
+                        color: style.thumb_border,
+                        width: style.thumb_border_width,
+                        radius: thumb_radius.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.thumb,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Range<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(range: Range<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(range)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    /// Whether the drag started on two thumbs at the same value and has not moved yet
+    is_stacked: bool,
+    is_hovered: bool,
+}
+
+/// The possible status of a [`Range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Range`] can be interacted with.
+    Active,
+    /// The [`Range`] is being hovered.
+    Hovered,
+    /// A thumb of the [`Range`] is being dragged.
+    Dragged,
+    /// The [`Range`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of a [`Range`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color of the rail.
+    pub rail: Color,
+    /// The color of the selected portion of the rail.
+    pub rail_active: Color,
+    /// The fill color of the thumbs.
+    pub thumb: Color,
+    /// The border color of the thumbs.
+    pub thumb_border: Color,
+    /// The border width of the thumbs.
+    pub thumb_border_width: f32,
+    /// The color of the tick marks.
+    pub tick: Color,
+}
+
+/// The theme catalog of a [`Range`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
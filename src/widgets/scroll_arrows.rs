@@ -0,0 +1,351 @@
+//! Adds previous and next arrows to a horizontal scrollable whose content overflows.
+//!
+//! The arrows only take up space when the scrollable's content is wider than the
+//! scrollable itself. Pressing an arrow scrolls the wrapped scrollable directly
+//! with a widget operation, so the parent does not need to run a `Task`.
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::operation::scrollable::{AbsoluteOffset, scroll_by};
+use iced_core::widget::{self, Widget};
+use iced_core::{Clipboard, Element, Event, Length, Point, Rectangle, Shell, Size, Vector};
+
+/// A wrapper that shows scroll arrows beside an overflowing horizontal scrollable.
+pub struct ScrollArrows<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    previous: Element<'a, Message, Theme, Renderer>,
+    next: Element<'a, Message, Theme, Renderer>,
+    id: widget::Id,
+    step: f32,
+}
+
+impl<'a, Message, Theme, Renderer> ScrollArrows<'a, Message, Theme, Renderer> {
+    /// Creates a new [`ScrollArrows`] around the scrollable with the given `id`.
+    ///
+    /// `previous` and `next` are drawn as the arrows; presses on them are handled
+    /// by the [`ScrollArrows`] and never reach them.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        id: widget::Id,
+        previous: impl Into<Element<'a, Message, Theme, Renderer>>,
+        next: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            previous: previous.into(),
+            next: next.into(),
+            id,
+            step: 100.0,
+        }
+    }
+
+    /// Sets how far a single press of an arrow scrolls, in pixels.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_overflowing: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ScrollArrows<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.content),
+            widget::Tree::new(&self.previous),
+            widget::Tree::new(&self.next),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[
+            self.content.as_widget(),
+            self.previous.as_widget(),
+            self.next.as_widget(),
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        // A scrollable's layout has a single child: its (unclipped) content
+        let is_overflowing = content
+            .children()
+            .first()
+            .is_some_and(|inner| inner.size().width > content.size().width + 0.5);
+
+        tree.state.downcast_mut::<State>().is_overflowing = is_overflowing;
+
+        if !is_overflowing {
+            return layout::Node::with_children(
+                content.size(),
+                vec![
+                    content,
+                    layout::Node::new(Size::ZERO),
+                    layout::Node::new(Size::ZERO),
+                ],
+            );
+        }
+
+        let previous =
+            self.previous
+                .as_widget_mut()
+                .layout(&mut tree.children[1], renderer, &limits.loose());
+        let next =
+            self.next
+                .as_widget_mut()
+                .layout(&mut tree.children[2], renderer, &limits.loose());
+
+        let arrows_width = previous.size().width + next.size().width;
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(arrows_width, 0.0)),
+        );
+
+        let height = content
+            .size()
+            .height
+            .max(previous.size().height)
+            .max(next.size().height);
+
+        let previous_width = previous.size().width;
+        let previous_height = previous.size().height;
+        let next_height = next.size().height;
+        let content_width = content.size().width;
+
+        let previous = previous.move_to(Point::new(0.0, (height - previous_height) / 2.0));
+        let content = content.move_to(Point::new(previous_width, 0.0));
+        let next = next.move_to(Point::new(
+            previous_width + content_width,
+            (height - next_height) / 2.0,
+        ));
+
+        layout::Node::with_children(
+            Size::new(content_width + arrows_width, height),
+            vec![content, previous, next],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        // Lets `scroll_to` and `snap_to` reach the wrapped scrollable
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_overflowing = tree.state.downcast_ref::<State>().is_overflowing;
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let previous_layout = children.next().unwrap();
+        let next_layout = children.next().unwrap();
+
+        if is_overflowing
+            && let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+        {
+            let delta = if cursor.is_over(previous_layout.bounds()) {
+                Some(-self.step)
+            } else if cursor.is_over(next_layout.bounds()) {
+                Some(self.step)
+            } else {
+                None
+            };
+
+            if let Some(delta) = delta {
+                let mut operation = scroll_by(self.id.clone(), AbsoluteOffset { x: delta, y: 0.0 });
+
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    content_layout,
+                    renderer,
+                    &mut operation,
+                );
+
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if is_overflowing {
+            for (index, (arrow, arrow_layout)) in [&mut self.previous, &mut self.next]
+                .into_iter()
+                .zip([previous_layout, next_layout])
+                .enumerate()
+            {
+                arrow.as_widget_mut().update(
+                    &mut tree.children[index + 1],
+                    event,
+                    arrow_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_overflowing = tree.state.downcast_ref::<State>().is_overflowing;
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+
+        if is_overflowing && children.any(|arrow| cursor.is_over(arrow.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let is_overflowing = tree.state.downcast_ref::<State>().is_overflowing;
+        let mut children = layout.children();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            children.next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        if is_overflowing {
+            for (index, (arrow, arrow_layout)) in [&self.previous, &self.next]
+                .into_iter()
+                .zip(children)
+                .enumerate()
+            {
+                arrow.as_widget().draw(
+                    &tree.children[index + 1],
+                    renderer,
+                    theme,
+                    inherited_style,
+                    arrow_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ScrollArrows<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        arrows: ScrollArrows<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(arrows)
+    }
+}
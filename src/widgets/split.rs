@@ -0,0 +1,665 @@
+//! Two panes separated by a divider that can be dragged to resize them.
+//!
+//! The position is controlled by the application: the widget publishes the new
+//! position while the divider is dragged, or moved with the arrow keys once it has
+//! been clicked or focused with `focus_next`, and expects to be rebuilt with it.
+//!
+//! # Example
+//! ```no_run
+//! use iced_shoelace::widgets::split::{Position, Split};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     Repositioned(Position),
+//! }
+//!
+//! let split = Split::new("Start", "End", Position::Percent(30.0))
+//!     .min(120.0)
+//!     .on_reposition(Message::Repositioned);
+//! ```
+use iced_core::keyboard::{self, Key, key::Named};
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shell, Size, Vector,
+};
+
+/// How far outside the divider a press still grabs it, on each side.
+const HIT_SLOP: f32 = 4.0;
+
+/// How the panes are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Side by side, with a vertical divider.
+    #[default]
+    Horizontal,
+    /// One above the other, with a horizontal divider.
+    Vertical,
+}
+
+/// The size of the primary pane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    /// An absolute size, kept when the split is resized.
+    Pixels(f32),
+    /// A share of the space available to both panes, from 0 to 100.
+    Percent(f32),
+}
+
+impl Position {
+    /// Resolves the position to pixels within the given space.
+    fn to_pixels(self, total: f32) -> f32 {
+        match self {
+            Position::Pixels(pixels) => pixels,
+            Position::Percent(percent) => total * percent / 100.0,
+        }
+    }
+}
+
+/// One of the two panes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pane {
+    /// The left or top pane.
+    #[default]
+    Start,
+    /// The right or bottom pane.
+    End,
+}
+
+/// Two panes with a draggable divider between them.
+pub struct Split<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    panes: [Element<'a, Message, Theme, Renderer>; 2],
+    orientation: Orientation,
+    position: Position,
+    primary: Pane,
+    min: f32,
+    max: f32,
+    snaps: Vec<Position>,
+    snap_threshold: f32,
+    divider_width: f32,
+    on_reposition: Option<Box<dyn Fn(Position) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Split<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Split`] with the primary pane at the given position.
+    pub fn new(
+        start: impl Into<Element<'a, Message, Theme, Renderer>>,
+        end: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Self {
+            panes: [start.into(), end.into()],
+            orientation: Orientation::Horizontal,
+            position,
+            primary: Pane::Start,
+            min: 0.0,
+            max: f32::INFINITY,
+            snaps: Vec::new(),
+            snap_threshold: 12.0,
+            divider_width: 4.0,
+            on_reposition: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets how the panes are arranged.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets which pane the position measures; that pane keeps a pixel position
+    /// when the split is resized.
+    pub fn primary(mut self, primary: Pane) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Sets the minimum size of the primary pane in pixels.
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum size of the primary pane in pixels.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the positions the divider snaps to while dragged.
+    pub fn snaps(mut self, snaps: impl IntoIterator<Item = Position>) -> Self {
+        self.snaps = snaps.into_iter().collect();
+        self
+    }
+
+    /// Sets how close to a snap position, in pixels, the divider has to be to snap.
+    pub fn snap_threshold(mut self, threshold: f32) -> Self {
+        self.snap_threshold = threshold;
+        self
+    }
+
+    /// Sets the thickness of the divider.
+    pub fn divider_width(mut self, width: f32) -> Self {
+        self.divider_width = width;
+        self
+    }
+
+    /// Sets the function producing a message when the divider is moved.
+    pub fn on_reposition(mut self, f: impl Fn(Position) -> Message + 'a) -> Self {
+        self.on_reposition = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style class of the [`Split`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the length of a size along the axis the divider moves on.
+    fn main(&self, size: Size) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => size.width,
+            Orientation::Vertical => size.height,
+        }
+    }
+
+    /// Returns the space shared by both panes.
+    fn total(&self, bounds: Rectangle) -> f32 {
+        (self.main(bounds.size()) - self.divider_width).max(0.0)
+    }
+
+    /// Clamps a primary pane size to the limits and the available space.
+    fn clamp(&self, primary: f32, total: f32) -> f32 {
+        primary.min(self.max).max(self.min).clamp(0.0, total)
+    }
+
+    /// Returns the size of the start pane.
+    fn start(&self, total: f32) -> f32 {
+        let primary = self.clamp(self.position.to_pixels(total), total);
+
+        match self.primary {
+            Pane::Start => primary,
+            Pane::End => total - primary,
+        }
+    }
+
+    fn divider_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let start = self.start(self.total(bounds));
+
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + start,
+                width: self.divider_width,
+                ..bounds
+            },
+            Orientation::Vertical => Rectangle {
+                y: bounds.y + start,
+                height: self.divider_width,
+                ..bounds
+            },
+        }
+    }
+
+    fn hit_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let divider = self.divider_bounds(bounds);
+
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: divider.x - HIT_SLOP,
+                width: divider.width + HIT_SLOP * 2.0,
+                ..divider
+            },
+            Orientation::Vertical => Rectangle {
+                y: divider.y - HIT_SLOP,
+                height: divider.height + HIT_SLOP * 2.0,
+                ..divider
+            },
+        }
+    }
+
+    /// Moves the divider so the start pane has the given size, publishing the
+    /// new position in the same unit as the current one.
+    fn reposition(&mut self, start: f32, total: f32, snap: bool, shell: &mut Shell<'_, Message>) {
+        let mut primary = match self.primary {
+            Pane::Start => start,
+            Pane::End => total - start,
+        };
+
+        if snap
+            && let Some(snapped) = self
+                .snaps
+                .iter()
+                .map(|snap| snap.to_pixels(total))
+                .filter(|snap| (snap - primary).abs() <= self.snap_threshold)
+                .min_by(|a, b| (a - primary).abs().total_cmp(&(b - primary).abs()))
+        {
+            primary = snapped;
+        }
+
+        let primary = self.clamp(primary, total);
+
+        let position = match self.position {
+            Position::Pixels(_) => Position::Pixels(primary),
+            Position::Percent(_) if total > 0.0 => Position::Percent(primary / total * 100.0),
+            Position::Percent(_) => Position::Percent(0.0),
+        };
+
+        if position != self.position {
+            self.position = position;
+
+            if let Some(on_reposition) = &self.on_reposition {
+                shell.publish(on_reposition(position));
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_dragging: bool,
+    is_hovered: bool,
+    is_focused: bool,
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Split<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.panes.iter().map(widget::Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&self.panes);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.width(Length::Fill).height(Length::Fill).max();
+        let bounds = Rectangle::new(Point::ORIGIN, size);
+
+        let total = self.total(bounds);
+        let start = self.start(total);
+        let end = total - start;
+
+        let (start_size, end_size, end_offset) = match self.orientation {
+            Orientation::Horizontal => (
+                Size::new(start, size.height),
+                Size::new(end, size.height),
+                Vector::new(start + self.divider_width, 0.0),
+            ),
+            Orientation::Vertical => (
+                Size::new(size.width, start),
+                Size::new(size.width, end),
+                Vector::new(0.0, start + self.divider_width),
+            ),
+        };
+
+        let [first, second] = &mut self.panes;
+        let (first_tree, second_tree) = tree.children.split_at_mut(1);
+
+        let first = first.as_widget_mut().layout(
+            &mut first_tree[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, start_size),
+        );
+
+        let second = second
+            .as_widget_mut()
+            .layout(
+                &mut second_tree[0],
+                renderer,
+                &layout::Limits::new(Size::ZERO, end_size),
+            )
+            .move_to(Point::ORIGIN + end_offset);
+
+        layout::Node::with_children(size, vec![first, second])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let bounds = layout.bounds();
+        let divider = self.divider_bounds(bounds);
+        let state = tree.state.downcast_mut::<State>();
+        let [first, second] = &mut self.panes;
+        let [first_tree, second_tree] = &mut tree.children[..] else {
+            return;
+        };
+        let mut children = layout.children();
+        let (first_layout, second_layout) = (children.next(), children.next());
+
+        operation.container(None, bounds);
+        operation.traverse(&mut |operation| {
+            // The divider sits between the panes in the focus order
+            if let Some(layout) = first_layout {
+                first
+                    .as_widget_mut()
+                    .operate(first_tree, layout, renderer, operation);
+            }
+
+            operation.focusable(None, divider, &mut *state);
+
+            if let Some(layout) = second_layout {
+                second
+                    .as_widget_mut()
+                    .operate(second_tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let total = self.total(bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_over = cursor.is_over(self.hit_bounds(bounds));
+
+                if is_over != state.is_focused {
+                    state.is_focused = is_over;
+                    shell.request_redraw();
+                }
+
+                if is_over {
+                    state.is_dragging = true;
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if state.is_dragging {
+                    if let Some(position) = cursor.position() {
+                        // Keep the cursor in the middle of the divider
+                        let offset = match self.orientation {
+                            Orientation::Horizontal => position.x - bounds.x,
+                            Orientation::Vertical => position.y - bounds.y,
+                        };
+
+                        self.reposition(offset - self.divider_width / 2.0, total, true, shell);
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+
+                let is_hovered = cursor.is_over(self.hit_bounds(bounds));
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if state.is_dragging {
+                    state.is_dragging = false;
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.is_focused =>
+            {
+                // Shoelace moves the divider by 1% of the space, or 10% with Shift
+                let step = total * if modifiers.shift() { 0.1 } else { 0.01 };
+                let start = self.start(total);
+
+                let (backward, forward) = match self.orientation {
+                    Orientation::Horizontal => (Named::ArrowLeft, Named::ArrowRight),
+                    Orientation::Vertical => (Named::ArrowUp, Named::ArrowDown),
+                };
+
+                let target = match key {
+                    Key::Named(named) if *named == backward => Some(start - step),
+                    Key::Named(named) if *named == forward => Some(start + step),
+                    Key::Named(Named::Home) => Some(0.0),
+                    Key::Named(Named::End) => Some(total),
+                    _ => None,
+                };
+
+                if let Some(target) = target {
+                    self.reposition(target.clamp(0.0, total), total, false, shell);
+                    shell.capture_event();
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        for ((pane, tree), layout) in self
+            .panes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            pane.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging || cursor.is_over(self.hit_bounds(layout.bounds())) {
+            return match self.orientation {
+                Orientation::Horizontal => mouse::Interaction::ResizingHorizontally,
+                Orientation::Vertical => mouse::Interaction::ResizingVertically,
+            };
+        }
+
+        self.panes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((pane, tree), layout)| {
+                pane.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        // Panes are clipped so content never spills under the divider
+        for ((pane, tree), layout) in self.panes.iter().zip(&tree.children).zip(layout.children()) {
+            let pane_bounds = Rectangle {
+                width: layout.bounds().width.min(bounds.width),
+                height: layout.bounds().height.min(bounds.height),
+                ..layout.bounds()
+            };
+
+            if let Some(clip) = pane_bounds.intersection(viewport) {
+                renderer.with_layer(clip, |renderer| {
+                    pane.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        inherited_style,
+                        layout,
+                        cursor,
+                        &clip,
+                    );
+                });
+            }
+        }
+
+        let status = if state.is_dragging {
+            Status::Dragged
+        } else if state.is_hovered {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+        let divider = self.divider_bounds(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: divider,
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        if state.is_focused {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: divider.expand(1.0),
+                    border: Border {
+                        color: style.focus,
+                        width: 2.0,
+                        radius: 2.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.panes,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Split<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(split: Split<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(split)
+    }
+}
+
+/// The status of a [`Split`] divider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The divider can be dragged.
+    Active,
+    /// The cursor is over the divider.
+    Hovered,
+    /// The divider is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Split`] divider.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color of the divider.
+    pub divider: Color,
+    /// The color of the ring drawn around the focused divider.
+    pub focus: Color,
+}
+
+/// The theme catalog of a [`Split`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
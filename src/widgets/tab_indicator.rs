@@ -0,0 +1,384 @@
+//! Draws a track along one edge of a strip of tabs and an indicator under the active tab.
+//!
+//! The indicator slides from the previously active tab to the new one whenever
+//! the active index changes. Each direct child of the wrapped content's layout
+//! (for example, each element of a `Row`) is treated as a tab.
+use std::time::{Duration, Instant};
+
+use iced_core::layout::{self, Layout};
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{self, Widget};
+use iced_core::window;
+use iced_core::{Clipboard, Color, Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+/// The edge of the tab strip the track and indicator are drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edge {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// A wrapper that draws an animated active-tab indicator over its content.
+pub struct TabIndicator<'a, Message, Theme = crate::theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    active: Option<usize>,
+    edge: Edge,
+    thickness: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TabIndicator<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`TabIndicator`] highlighting the child at `active`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        active: Option<usize>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            active,
+            edge: Edge::Bottom,
+            thickness: 2.0,
+            duration: Duration::from_millis(150),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the edge the track and indicator are drawn on.
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets the thickness of the track and indicator.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets how long the indicator takes to slide between tabs.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style class of the [`TabIndicator`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the strip of `bounds` along the configured edge.
+    fn edge_of(&self, bounds: Rectangle) -> Rectangle {
+        match self.edge {
+            Edge::Top => Rectangle {
+                height: self.thickness,
+                ..bounds
+            },
+            Edge::Bottom => Rectangle {
+                y: bounds.y + bounds.height - self.thickness,
+                height: self.thickness,
+                ..bounds
+            },
+            Edge::Left => Rectangle {
+                width: self.thickness,
+                ..bounds
+            },
+            Edge::Right => Rectangle {
+                x: bounds.x + bounds.width - self.thickness,
+                width: self.thickness,
+                ..bounds
+            },
+        }
+    }
+
+    /// Returns the indicator under `tab`, aligned to the track of `strip`.
+    fn indicator_of(&self, strip: Rectangle, tab: Rectangle) -> Rectangle {
+        let track = self.edge_of(strip);
+
+        match self.edge {
+            Edge::Top | Edge::Bottom => Rectangle {
+                x: tab.x,
+                width: tab.width,
+                ..track
+            },
+            Edge::Left | Edge::Right => Rectangle {
+                y: tab.y,
+                height: tab.height,
+                ..track
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    active: Option<usize>,
+    previous: Option<usize>,
+    started: Option<Instant>,
+}
+
+impl State {
+    /// Returns the eased progress of the current slide, from 0.0 to 1.0.
+    fn progress(&self, duration: Duration) -> f32 {
+        let Some(started) = self.started else {
+            return 1.0;
+        };
+
+        if duration.is_zero() {
+            return 1.0;
+        }
+
+        let t = (started.elapsed().as_secs_f32() / duration.as_secs_f32()).clamp(0.0, 1.0);
+
+        // Ease-out cubic
+        1.0 - (1.0 - t).powi(3)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabIndicator<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            active: self.active,
+            previous: None,
+            started: None,
+        })
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.active != self.active {
+            state.previous = state.active;
+            state.active = self.active;
+            state.started = Some(Instant::now());
+        }
+
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        // Lets the tabs and their scrollable be focused and scrolled
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.started.is_some() {
+                if state.progress(self.duration) < 1.0 {
+                    shell.request_redraw();
+                } else {
+                    state.started = None;
+                }
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        // The track spans the whole strip, beneath the tabs
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.edge_of(bounds),
+                ..renderer::Quad::default()
+            },
+            style.track,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            inherited_style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let tab_bounds = |index: Option<usize>| {
+            index.and_then(|index| layout.children().nth(index).map(|tab| tab.bounds()))
+        };
+
+        let Some(target) = tab_bounds(state.active) else {
+            return;
+        };
+
+        // Slide from the previous tab, if there was one and the slide is still running
+        let current = match tab_bounds(state.previous) {
+            Some(from) if state.started.is_some() => {
+                let t = state.progress(self.duration);
+
+                Rectangle {
+                    x: from.x + (target.x - from.x) * t,
+                    y: from.y + (target.y - from.y) * t,
+                    width: from.width + (target.width - from.width) * t,
+                    height: from.height + (target.height - from.height) * t,
+                }
+            }
+            _ => target,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.indicator_of(bounds, current),
+                ..renderer::Quad::default()
+            },
+            style.indicator,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TabIndicator<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        indicator: TabIndicator<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(indicator)
+    }
+}
+
+/// The appearance of a [`TabIndicator`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color of the track spanning all tabs.
+    pub track: Color,
+    /// The color of the indicator under the active tab.
+    pub indicator: Color,
+}
+
+/// The theme catalog of a [`TabIndicator`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
@@ -35,6 +35,11 @@ use iced_core::{
     Clipboard, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector,
 };
 
+/// The source of the current time a [`Tooltip`] measures its hover delay with
+///
+/// Tests can replace [`Instant::now`] with a clock they advance by hand.
+pub type Clock = fn() -> Instant;
+
 /// An element to display a widget over another.
 ///
 /// # Example
@@ -71,6 +76,8 @@ where
     snap_within_viewport: bool,
     class: Theme::Class<'a>,
     duration: Duration,
+    open: Option<bool>,
+    clock: Clock,
 }
 
 impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
@@ -98,6 +105,8 @@ where
             snap_within_viewport: true,
             class: Theme::default(),
             duration: Duration::from_millis(500),
+            open: None,
+            clock: Instant::now,
         }
     }
 
@@ -119,6 +128,20 @@ where
         self
     }
 
+    /// Sets the clock the hover delay is measured with. Defaults to [`Instant::now`].
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Forces the [`Tooltip`] open or closed, ignoring hover state.
+    ///
+    /// Passing `None` restores the default hover behavior.
+    pub fn open(mut self, open: impl Into<Option<bool>>) -> Self {
+        self.open = open.into();
+        self
+    }
+
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
@@ -204,7 +227,7 @@ where
             let hover_time = if let State::Hovered { time, .. } = *state {
                 time
             } else {
-                Instant::now()
+                (self.clock)()
             };
 
             *state = cursor
@@ -243,6 +266,18 @@ where
         );
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
     fn mouse_interaction(
         &self,
         tree: &widget::Tree,
@@ -301,12 +336,19 @@ where
             translation,
         );
 
-        let tooltip = if let State::Hovered {
-            cursor_position,
-            time,
-        } = *state
-            && time.elapsed() >= self.duration
-        {
+        let (visible, cursor_position) = match *state {
+            State::Hovered {
+                cursor_position,
+                time,
+            } => (
+                self.open
+                    .unwrap_or((self.clock)().saturating_duration_since(time) >= self.duration),
+                cursor_position,
+            ),
+            State::Idle => (self.open.unwrap_or(false), layout.bounds().center()),
+        };
+
+        let tooltip = if visible {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &mut self.tooltip,
@@ -471,6 +513,58 @@ where
         .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y))
     }
 
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        // Forward events so interactive contents (e.g. popup panels) can be used
+        self.tooltip.as_widget_mut().update(
+            self.state,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        // Lets operations such as searches and focus reach the tooltip's contents
+        self.tooltip.as_widget_mut().operate(
+            self.state,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.tooltip.as_widget().mouse_interaction(
+            self.state,
+            layout.children().next().unwrap(),
+            cursor,
+            &Rectangle::with_size(Size::INFINITE),
+            renderer,
+        )
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -490,3 +584,116 @@ where
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use iced::widget::text;
+    use iced_test::{Simulator, simulator};
+
+    use super::*;
+    use crate::theme::Theme;
+
+    thread_local! {
+        static NOW: Cell<Instant> = Cell::new(Instant::now());
+    }
+
+    /// A clock that only moves when the test advances it
+    fn now() -> Instant {
+        NOW.get()
+    }
+
+    fn advance(millis: u64) {
+        NOW.set(NOW.get() + Duration::from_millis(millis));
+    }
+
+    fn hint<'a>(
+        tooltip: Tooltip<'a, (), Theme, iced::Renderer>,
+    ) -> Simulator<'a, (), Theme, iced::Renderer> {
+        simulator(Element::from(tooltip.clock(now)))
+    }
+
+    fn tooltip<'a>() -> Tooltip<'a, (), Theme, iced::Renderer> {
+        Tooltip::new(text("Content"), text("Hint"), Position::Bottom)
+    }
+
+    fn move_to(simulator: &mut Simulator<'_, (), Theme, iced::Renderer>, x: f32, y: f32) {
+        let position = Point::new(x, y);
+
+        simulator.point_at(position);
+        simulator.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    fn is_visible(simulator: &mut Simulator<'_, (), Theme, iced::Renderer>) -> bool {
+        simulator.find("Hint").is_ok()
+    }
+
+    #[test]
+    fn appears_after_the_delay() {
+        let mut simulator = hint(tooltip());
+
+        move_to(&mut simulator, 5.0, 5.0);
+        assert!(!is_visible(&mut simulator));
+
+        advance(499);
+        assert!(!is_visible(&mut simulator));
+
+        advance(1);
+        assert!(is_visible(&mut simulator));
+    }
+
+    #[test]
+    fn moving_within_the_content_keeps_the_timer() {
+        let mut simulator = hint(tooltip());
+
+        move_to(&mut simulator, 5.0, 5.0);
+        advance(300);
+        move_to(&mut simulator, 10.0, 8.0);
+        advance(200);
+
+        assert!(is_visible(&mut simulator));
+    }
+
+    #[test]
+    fn leaving_resets_the_timer() {
+        let mut simulator = hint(tooltip());
+
+        move_to(&mut simulator, 5.0, 5.0);
+        advance(600);
+        assert!(is_visible(&mut simulator));
+
+        move_to(&mut simulator, 500.0, 500.0);
+        assert!(!is_visible(&mut simulator));
+
+        advance(100);
+        move_to(&mut simulator, 5.0, 5.0);
+        assert!(!is_visible(&mut simulator));
+
+        advance(500);
+        assert!(is_visible(&mut simulator));
+    }
+
+    #[test]
+    fn custom_duration() {
+        let mut simulator = hint(tooltip().duration(Duration::from_millis(100)));
+
+        move_to(&mut simulator, 5.0, 5.0);
+        advance(99);
+        assert!(!is_visible(&mut simulator));
+
+        advance(1);
+        assert!(is_visible(&mut simulator));
+    }
+
+    #[test]
+    fn open_ignores_hover() {
+        let mut simulator = hint(tooltip().open(true));
+        assert!(is_visible(&mut simulator));
+
+        let mut simulator = hint(tooltip().open(false));
+        move_to(&mut simulator, 5.0, 5.0);
+        advance(1000);
+        assert!(!is_visible(&mut simulator));
+    }
+}